@@ -0,0 +1,79 @@
+use axum::{
+    Json,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::entities::{AccountExport, ExportStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, Copy)]
+pub enum IExportStatus {
+    Pending,
+    Ready,
+    Failed,
+}
+
+impl From<ExportStatus> for IExportStatus {
+    fn from(value: ExportStatus) -> Self {
+        match value {
+            ExportStatus::Pending => IExportStatus::Pending,
+            ExportStatus::Ready => IExportStatus::Ready,
+            ExportStatus::Failed => IExportStatus::Failed,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AccountExportStatusResponse {
+    pub id: Uuid,
+    pub status: IExportStatus,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<AccountExport> for AccountExportStatusResponse {
+    fn from(value: AccountExport) -> Self {
+        Self {
+            id: value.id,
+            status: value.status.into(),
+            error: value.error,
+            created_at: value.created_at,
+            completed_at: value.completed_at,
+        }
+    }
+}
+
+/// A finished export, served as a downloadable JSON file.
+pub struct AccountExportDownload {
+    pub user_id: Uuid,
+    pub archive: Value,
+}
+
+// IntoResponse implementations
+impl IntoResponse for AccountExportStatusResponse {
+    fn into_response(self) -> Response {
+        let status = match self.status {
+            IExportStatus::Pending => StatusCode::ACCEPTED,
+            IExportStatus::Ready | IExportStatus::Failed => StatusCode::OK,
+        };
+
+        (status, Json(self)).into_response()
+    }
+}
+
+impl IntoResponse for AccountExportDownload {
+    fn into_response(self) -> Response {
+        let disposition = format!("attachment; filename=\"jos-account-{}.json\"", self.user_id);
+
+        (
+            [(header::CONTENT_DISPOSITION, disposition)],
+            Json(self.archive),
+        )
+            .into_response()
+    }
+}
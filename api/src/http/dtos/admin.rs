@@ -0,0 +1,103 @@
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::entities::{GetUserCommand, User, UserRole};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, Copy, Default)]
+pub enum IUserRole {
+    #[default]
+    User,
+    Admin,
+}
+
+impl From<UserRole> for IUserRole {
+    fn from(value: UserRole) -> Self {
+        match value {
+            UserRole::User => IUserRole::User,
+            UserRole::Admin => IUserRole::Admin,
+        }
+    }
+}
+
+impl From<IUserRole> for UserRole {
+    fn from(value: IUserRole) -> Self {
+        match value {
+            IUserRole::User => UserRole::User,
+            IUserRole::Admin => UserRole::Admin,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct AdminUsersQuery {
+    pub role: Option<IUserRole>,
+    pub suspended: Option<bool>,
+}
+
+impl From<AdminUsersQuery> for GetUserCommand {
+    fn from(value: AdminUsersQuery) -> Self {
+        Self {
+            role: value.role.map(UserRole::from),
+            suspended: value.suspended,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AdminUserItem {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub role: IUserRole,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl From<User> for AdminUserItem {
+    fn from(value: User) -> Self {
+        Self {
+            id: value.id,
+            username: value.username,
+            email: value.email,
+            role: value.role.into(),
+            suspended_at: value.suspended_at,
+            joined_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct UpdateUserRoleRequest {
+    pub role: IUserRole,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct UpdateGameSystemRequest {
+    #[validate(length(min = 1, max = 80))]
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AdminDeleteResponse {
+    pub message: String,
+}
+
+// IntoResponse implementations
+impl IntoResponse for AdminUserItem {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+impl IntoResponse for AdminDeleteResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -0,0 +1,130 @@
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::entities::{
+    Announcement, AnnouncementComment, AnnouncementListing, AnnouncementReadReceipt,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct CreateAnnouncementRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub title: String,
+    #[validate(length(min = 1, max = 5000))]
+    pub body: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct UpdateAnnouncementRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub title: Option<String>,
+    #[validate(length(min = 1, max = 5000))]
+    pub body: Option<String>,
+    pub pinned: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AnnouncementResponse {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Announcement> for AnnouncementResponse {
+    fn from(value: Announcement) -> Self {
+        Self {
+            id: value.id,
+            table_id: value.table_id,
+            author_id: value.author_id,
+            title: value.title,
+            body: value.body,
+            pinned: value.pinned,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AnnouncementListingResponse {
+    #[serde(flatten)]
+    pub announcement: AnnouncementResponse,
+    pub comment_count: u32,
+    pub read_count: u32,
+    /// Whether the requesting user has marked the announcement read.
+    pub read: bool,
+}
+
+impl From<AnnouncementListing> for AnnouncementListingResponse {
+    fn from(value: AnnouncementListing) -> Self {
+        Self {
+            announcement: value.announcement.into(),
+            comment_count: value.comment_count,
+            read_count: value.read_count,
+            read: value.read,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AnnouncementReadReceiptResponse {
+    pub user_id: Uuid,
+    pub username: String,
+    pub read_at: DateTime<Utc>,
+}
+
+impl From<AnnouncementReadReceipt> for AnnouncementReadReceiptResponse {
+    fn from(value: AnnouncementReadReceipt) -> Self {
+        Self {
+            user_id: value.user_id,
+            username: value.username,
+            read_at: value.read_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct CreateAnnouncementCommentRequest {
+    #[validate(length(min = 1, max = 2000))]
+    pub body: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AnnouncementCommentResponse {
+    pub id: Uuid,
+    pub announcement_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AnnouncementComment> for AnnouncementCommentResponse {
+    fn from(value: AnnouncementComment) -> Self {
+        Self {
+            id: value.id,
+            announcement_id: value.announcement_id,
+            author_id: value.author_id,
+            body: value.body,
+            created_at: value.created_at,
+        }
+    }
+}
+
+// IntoResponse implementations
+impl IntoResponse for AnnouncementResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
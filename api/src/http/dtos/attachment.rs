@@ -0,0 +1,116 @@
+use application::attachment_service::AttachmentFile;
+use axum::{
+    Json,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::entities::{Attachment, AttachmentUsage};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentResponse {
+    fn from(value: Attachment) -> Self {
+        Self {
+            id: value.id,
+            table_id: value.table_id,
+            session_id: value.session_id,
+            uploaded_by: value.uploaded_by,
+            file_name: value.file_name,
+            content_type: value.content_type,
+            size_bytes: value.size_bytes,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AttachmentUsageResponse {
+    pub used_bytes: u64,
+    pub quota_bytes: u64,
+}
+
+impl From<AttachmentUsage> for AttachmentUsageResponse {
+    fn from(value: AttachmentUsage) -> Self {
+        Self {
+            used_bytes: value.used_bytes,
+            quota_bytes: value.quota_bytes,
+        }
+    }
+}
+
+/// The stored file, always served as a download so uploaded HTML or SVG is
+/// never rendered on the API origin.
+pub struct AttachmentDownload(pub AttachmentFile);
+
+/// Builds a `Content-Disposition` value with an ASCII fallback name and the
+/// exact UTF-8 name per RFC 6266.
+fn content_disposition(file_name: &str) -> String {
+    let fallback: String = file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_graphic() || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .filter(|c| *c != '"' && *c != '\\')
+        .collect();
+
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect();
+
+    format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        fallback, encoded
+    )
+}
+
+// IntoResponse implementations
+impl IntoResponse for AttachmentUsageResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+impl IntoResponse for AttachmentDownload {
+    fn into_response(self) -> Response {
+        let AttachmentFile { attachment, bytes } = self.0;
+
+        (
+            [
+                (header::CONTENT_TYPE, attachment.content_type),
+                (
+                    header::CONTENT_DISPOSITION,
+                    content_disposition(&attachment.file_name),
+                ),
+                (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+                (header::CACHE_CONTROL, "private, max-age=300".to_string()),
+            ],
+            bytes,
+        )
+            .into_response()
+    }
+}
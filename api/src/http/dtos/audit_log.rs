@@ -0,0 +1,180 @@
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::entities::{AuditAction, AuditEntityType, AuditLog, GetAuditLogCommand};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, Copy)]
+pub enum IAuditAction {
+    UserRegistered,
+    LoginSucceeded,
+    LoginFailed,
+    Logout,
+    PasswordChanged,
+    UserSuspended,
+    UserUnsuspended,
+    UserRoleChanged,
+    TableUpdated,
+    TableDeleted,
+    SessionUpdated,
+    SessionDeleted,
+    RequestApproved,
+    RequestRejected,
+    AccountDeleted,
+    AccountRestored,
+    TableRestored,
+    SessionRestored,
+    MagicLinkRequested,
+}
+
+impl From<AuditAction> for IAuditAction {
+    fn from(value: AuditAction) -> Self {
+        match value {
+            AuditAction::UserRegistered => IAuditAction::UserRegistered,
+            AuditAction::LoginSucceeded => IAuditAction::LoginSucceeded,
+            AuditAction::LoginFailed => IAuditAction::LoginFailed,
+            AuditAction::Logout => IAuditAction::Logout,
+            AuditAction::PasswordChanged => IAuditAction::PasswordChanged,
+            AuditAction::UserSuspended => IAuditAction::UserSuspended,
+            AuditAction::UserUnsuspended => IAuditAction::UserUnsuspended,
+            AuditAction::UserRoleChanged => IAuditAction::UserRoleChanged,
+            AuditAction::TableUpdated => IAuditAction::TableUpdated,
+            AuditAction::TableDeleted => IAuditAction::TableDeleted,
+            AuditAction::SessionUpdated => IAuditAction::SessionUpdated,
+            AuditAction::SessionDeleted => IAuditAction::SessionDeleted,
+            AuditAction::RequestApproved => IAuditAction::RequestApproved,
+            AuditAction::RequestRejected => IAuditAction::RequestRejected,
+            AuditAction::AccountDeleted => IAuditAction::AccountDeleted,
+            AuditAction::AccountRestored => IAuditAction::AccountRestored,
+            AuditAction::TableRestored => IAuditAction::TableRestored,
+            AuditAction::SessionRestored => IAuditAction::SessionRestored,
+            AuditAction::MagicLinkRequested => IAuditAction::MagicLinkRequested,
+        }
+    }
+}
+
+impl From<IAuditAction> for AuditAction {
+    fn from(value: IAuditAction) -> Self {
+        match value {
+            IAuditAction::UserRegistered => AuditAction::UserRegistered,
+            IAuditAction::LoginSucceeded => AuditAction::LoginSucceeded,
+            IAuditAction::LoginFailed => AuditAction::LoginFailed,
+            IAuditAction::Logout => AuditAction::Logout,
+            IAuditAction::PasswordChanged => AuditAction::PasswordChanged,
+            IAuditAction::UserSuspended => AuditAction::UserSuspended,
+            IAuditAction::UserUnsuspended => AuditAction::UserUnsuspended,
+            IAuditAction::UserRoleChanged => AuditAction::UserRoleChanged,
+            IAuditAction::TableUpdated => AuditAction::TableUpdated,
+            IAuditAction::TableDeleted => AuditAction::TableDeleted,
+            IAuditAction::SessionUpdated => AuditAction::SessionUpdated,
+            IAuditAction::SessionDeleted => AuditAction::SessionDeleted,
+            IAuditAction::RequestApproved => AuditAction::RequestApproved,
+            IAuditAction::RequestRejected => AuditAction::RequestRejected,
+            IAuditAction::AccountDeleted => AuditAction::AccountDeleted,
+            IAuditAction::AccountRestored => AuditAction::AccountRestored,
+            IAuditAction::TableRestored => AuditAction::TableRestored,
+            IAuditAction::SessionRestored => AuditAction::SessionRestored,
+            IAuditAction::MagicLinkRequested => AuditAction::MagicLinkRequested,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, Copy)]
+pub enum IAuditEntityType {
+    User,
+    Table,
+    Session,
+    TableRequest,
+}
+
+impl From<AuditEntityType> for IAuditEntityType {
+    fn from(value: AuditEntityType) -> Self {
+        match value {
+            AuditEntityType::User => IAuditEntityType::User,
+            AuditEntityType::Table => IAuditEntityType::Table,
+            AuditEntityType::Session => IAuditEntityType::Session,
+            AuditEntityType::TableRequest => IAuditEntityType::TableRequest,
+        }
+    }
+}
+
+impl From<IAuditEntityType> for AuditEntityType {
+    fn from(value: IAuditEntityType) -> Self {
+        match value {
+            IAuditEntityType::User => AuditEntityType::User,
+            IAuditEntityType::Table => AuditEntityType::Table,
+            IAuditEntityType::Session => AuditEntityType::Session,
+            IAuditEntityType::TableRequest => AuditEntityType::TableRequest,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct AuditLogQuery {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<IAuditAction>,
+    pub entity_type: Option<IAuditEntityType>,
+    pub entity_id: Option<Uuid>,
+    pub table_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+impl From<AuditLogQuery> for GetAuditLogCommand {
+    fn from(value: AuditLogQuery) -> Self {
+        Self {
+            actor_id: value.actor_id,
+            action: value.action.map(AuditAction::from),
+            entity_type: value.entity_type.map(AuditEntityType::from),
+            entity_id: value.entity_id,
+            table_id: value.table_id,
+            since: value.since,
+            until: value.until,
+            limit: value.limit,
+            offset: value.offset,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: IAuditAction,
+    pub entity_type: IAuditEntityType,
+    pub entity_id: Uuid,
+    pub table_id: Option<Uuid>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditLog> for AuditLogResponse {
+    fn from(value: AuditLog) -> Self {
+        Self {
+            id: value.id,
+            actor_id: value.actor_id,
+            action: value.action.into(),
+            entity_type: value.entity_type.into(),
+            entity_id: value.entity_id,
+            table_id: value.table_id,
+            before: value.before,
+            after: value.after,
+            created_at: value.created_at,
+        }
+    }
+}
+
+// IntoResponse implementations
+impl IntoResponse for AuditLogResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -27,6 +27,23 @@ pub struct RegisterRequest {
     pub password: String,
 }
 
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct MagicLinkRequest {
+    #[validate(email)]
+    pub email: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct MagicLinkLoginRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct MagicLinkResponse {
+    pub message: String,
+}
+
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct RefreshTokenRequest {
     pub refresh_token: String,
@@ -0,0 +1,85 @@
+use application::chat_service::ChatHistoryPage;
+use chrono::{DateTime, Utc};
+use domain::chat::ChatEvent;
+use domain::entities::ChatMessage;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct ChatHistoryQuery {
+    /// Cursor from a previous page; only older messages are returned.
+    pub before: Option<Uuid>,
+    /// Page size, 50 by default and at most 100.
+    pub limit: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ChatMessageResponse {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ChatMessage> for ChatMessageResponse {
+    fn from(value: ChatMessage) -> Self {
+        Self {
+            id: value.id,
+            table_id: value.table_id,
+            author_id: value.author_id,
+            body: value.body,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ChatHistoryResponse {
+    /// Newest first.
+    pub messages: Vec<ChatMessageResponse>,
+    /// Pass as `before` to load older messages; absent on the last page.
+    pub next_cursor: Option<Uuid>,
+}
+
+impl From<ChatHistoryPage> for ChatHistoryResponse {
+    fn from(value: ChatHistoryPage) -> Self {
+        Self {
+            messages: value
+                .messages
+                .into_iter()
+                .map(ChatMessageResponse::from)
+                .collect(),
+            next_cursor: value.next_cursor,
+        }
+    }
+}
+
+/// A frame sent by the client over the chat WebSocket.
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatClientFrame {
+    Message { body: String },
+    Typing,
+}
+
+/// A frame sent by the server over the chat WebSocket.
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatServerFrame {
+    Message(ChatMessageResponse),
+    Typing { table_id: Uuid, user_id: Uuid },
+    Error { message: String },
+}
+
+impl From<ChatEvent> for ChatServerFrame {
+    fn from(value: ChatEvent) -> Self {
+        match value {
+            ChatEvent::Message(message) => ChatServerFrame::Message(message.into()),
+            ChatEvent::Typing { table_id, user_id } => {
+                ChatServerFrame::Typing { table_id, user_id }
+            }
+        }
+    }
+}
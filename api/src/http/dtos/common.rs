@@ -1,35 +1,208 @@
-use domain::entities::GetTableCommand;
-use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use crate::http::dtos::{IAgeRating, ITableFormat, ITableStatus};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use domain::entities::{GeoPoint, GetTableCommand, TableSortField};
+use domain::repositories::{Page, PaginationParams, SortOrder, SortParams};
+use serde::{Deserialize, Deserializer, Serialize};
+use shared::error::{ApplicationError, Error};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-#[derive(Deserialize, Serialize, ToSchema, Default)]
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum ISortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl From<ISortOrder> for SortOrder {
+    fn from(value: ISortOrder) -> Self {
+        match value {
+            ISortOrder::Asc => SortOrder::Asc,
+            ISortOrder::Desc => SortOrder::Desc,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum ITableSortField {
+    #[default]
+    CreatedAt,
+    Title,
+    OpenSlots,
+    Distance,
+}
+
+impl From<ITableSortField> for TableSortField {
+    fn from(value: ITableSortField) -> Self {
+        match value {
+            ITableSortField::CreatedAt => TableSortField::CreatedAt,
+            ITableSortField::Title => TableSortField::Title,
+            ITableSortField::OpenSlots => TableSortField::OpenSlots,
+            ITableSortField::Distance => TableSortField::Distance,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
 pub struct SearchTablesQuery {
     #[serde(rename = "gmId")]
     pub gm_id: Option<Uuid>,
     #[serde(rename = "gameSystemId")]
     pub game_system_id: Option<Uuid>,
+    pub status: Option<ITableStatus>,
+    /// Comma-separated; a table must carry every tag to match.
+    pub tags: Option<String>,
+    pub language: Option<String>,
+    pub format: Option<ITableFormat>,
+    #[serde(rename = "beginnerFriendly")]
+    pub beginner_friendly: Option<bool>,
+    #[serde(rename = "ageRating")]
+    pub age_rating: Option<IAgeRating>,
+    #[serde(rename = "minOpenSlots")]
+    pub min_open_slots: Option<u32>,
+    /// Latitude of the point to search around; requires `lng` and vice versa.
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    #[serde(rename = "radiusKm")]
+    pub radius_km: Option<f64>,
+    /// Defaults to `Distance` when searching around a point.
+    #[serde(rename = "sortBy")]
+    pub sort_by: Option<ITableSortField>,
+    pub order: Option<ISortOrder>,
 }
 
-impl From<SearchTablesQuery> for GetTableCommand {
-    fn from(value: SearchTablesQuery) -> Self {
-        Self {
+impl TryFrom<SearchTablesQuery> for GetTableCommand {
+    type Error = Error;
+
+    fn try_from(value: SearchTablesQuery) -> Result<Self, Self::Error> {
+        let near = match (value.lat, value.lng) {
+            (Some(latitude), Some(longitude)) => Some(GeoPoint {
+                latitude,
+                longitude,
+            }),
+            (None, None) => None,
+            _ => {
+                return Err(Error::Application(ApplicationError::InvalidInput {
+                    message: "Searching around a point requires both lat and lng".to_string(),
+                }));
+            }
+        };
+
+        let sort_by = match value.sort_by {
+            Some(sort_by) => sort_by,
+            None if near.is_some() => ITableSortField::Distance,
+            None => ITableSortField::default(),
+        };
+
+        // Nearest first reads more naturally than the newest-first default.
+        let sort_order = match value.order {
+            Some(order) => order,
+            None if matches!(sort_by, ITableSortField::Distance) => ISortOrder::Asc,
+            None => ISortOrder::default(),
+        };
+
+        Ok(Self {
             gm_id: value.gm_id,
             game_system_id: value.game_system_id,
+            status: value.status.map(Into::into),
+            tags: value
+                .tags
+                .map(|tags| tags.split(',').map(str::to_string).collect()),
+            language: value.language,
+            format: value.format.map(Into::into),
+            beginner_friendly: value.beginner_friendly,
+            age_rating: value.age_rating.map(Into::into),
+            min_open_slots: value.min_open_slots,
+            near,
+            radius_km: value.radius_km,
+            sort: SortParams {
+                field: sort_by.into(),
+                order: sort_order.into(),
+            },
             ..Default::default()
-        }
+        })
     }
 }
 
-#[derive(Deserialize, Serialize, ToSchema)]
-pub struct ValidationError {
-    pub field: String,
-    pub message: String,
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct PaginationQuery {
+    /// Page size, 50 by default and at most 100.
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    /// `next_cursor` of a previous page; takes precedence over `offset`.
+    pub cursor: Option<String>,
 }
 
+impl TryFrom<PaginationQuery> for PaginationParams {
+    type Error = Error;
+
+    fn try_from(value: PaginationQuery) -> Result<Self, Self::Error> {
+        let cursor = value.cursor.as_deref().map(decode_cursor).transpose()?;
+
+        Ok(Self {
+            limit: value.limit,
+            offset: value.offset,
+            cursor,
+        })
+    }
+}
+
+/// Common envelope of paginated listings.
 #[derive(Deserialize, Serialize, ToSchema)]
-pub struct ErrorResponse {
-    pub message: String,
-    pub errors: Option<Vec<ValidationError>>,
-    pub code: Option<String>,
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    /// Number of matching items across all pages.
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    /// Pass as `cursor` to fetch the next page; absent on the last page and
+    /// for listings not sorted by creation time.
+    pub next_cursor: Option<String>,
+}
+
+impl<T, U: Into<T>> From<Page<U>> for PaginatedResponse<T> {
+    fn from(value: Page<U>) -> Self {
+        Self {
+            items: value.items.into_iter().map(Into::into).collect(),
+            total: value.total,
+            limit: value.limit,
+            offset: value.offset,
+            next_cursor: value.next_cursor.map(encode_cursor),
+        }
+    }
+}
+
+/// Cursors are opaque to clients so their encoding can change later.
+fn encode_cursor(id: Uuid) -> String {
+    URL_SAFE_NO_PAD.encode(id.as_bytes())
+}
+
+fn decode_cursor(cursor: &str) -> Result<Uuid, Error> {
+    URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| Uuid::from_slice(&bytes).ok())
+        .ok_or_else(|| {
+            Error::Application(ApplicationError::InvalidInput {
+                message: "Invalid pagination cursor".to_string(),
+            })
+        })
+}
+
+/// Documents multipart upload bodies; handlers read the `file` part directly.
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct FileUploadForm {
+    #[schema(format = Binary)]
+    pub file: String,
+}
+
+/// Distinguishes an explicit `null` from a missing field.
+pub fn deserialize_nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
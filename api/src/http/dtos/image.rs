@@ -0,0 +1,88 @@
+use application::image_service::ImageFile;
+use axum::{
+    Json,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::entities::{Image, ImageVariant};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum IImageVariant {
+    #[default]
+    Original,
+    Thumbnail,
+}
+
+impl From<IImageVariant> for ImageVariant {
+    fn from(value: IImageVariant) -> Self {
+        match value {
+            IImageVariant::Original => ImageVariant::Original,
+            IImageVariant::Thumbnail => ImageVariant::Thumbnail,
+        }
+    }
+}
+
+impl From<ImageVariant> for IImageVariant {
+    fn from(value: ImageVariant) -> Self {
+        match value {
+            ImageVariant::Original => IImageVariant::Original,
+            ImageVariant::Thumbnail => IImageVariant::Thumbnail,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct ImageQuery {
+    #[serde(default)]
+    pub size: IImageVariant,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct ImageResponse {
+    pub id: Uuid,
+    pub content_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub size_bytes: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Image> for ImageResponse {
+    fn from(value: Image) -> Self {
+        Self {
+            id: value.id,
+            content_type: value.content_type,
+            width: value.width,
+            height: value.height,
+            size_bytes: value.size_bytes,
+            created_at: value.created_at,
+        }
+    }
+}
+
+/// Raw image bytes served with their stored content type.
+pub struct ImageFileResponse(pub ImageFile);
+
+// IntoResponse implementations
+impl IntoResponse for ImageResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+impl IntoResponse for ImageFileResponse {
+    fn into_response(self) -> Response {
+        (
+            [
+                (header::CONTENT_TYPE, self.0.content_type),
+                (header::CACHE_CONTROL, "private, max-age=300".to_string()),
+            ],
+            self.0.bytes,
+        )
+            .into_response()
+    }
+}
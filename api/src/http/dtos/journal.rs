@@ -0,0 +1,103 @@
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::entities::{JournalEntry, JournalEntryRevision};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::http::dtos::deserialize_nullable;
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct CreateJournalEntryRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub title: String,
+    /// Markdown.
+    #[validate(length(min = 1, max = 50000))]
+    pub body: String,
+    pub session_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct UpdateJournalEntryRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub title: Option<String>,
+    #[validate(length(min = 1, max = 50000))]
+    pub body: Option<String>,
+    /// `null` unlinks the entry from its session, omitting the field keeps it.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[schema(value_type = Option<Uuid>)]
+    pub session_id: Option<Option<Uuid>>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct JournalQuery {
+    /// Only entries linked to this session.
+    #[serde(rename = "sessionId")]
+    pub session_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct JournalEntryResponse {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    /// Sanitized HTML rendering of `body`.
+    pub body_html: String,
+    pub revision: u32,
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<JournalEntry> for JournalEntryResponse {
+    fn from(value: JournalEntry) -> Self {
+        Self {
+            id: value.id,
+            table_id: value.table_id,
+            session_id: value.session_id,
+            author_id: value.author_id,
+            title: value.title,
+            body: value.body,
+            body_html: value.body_html,
+            revision: value.revision,
+            occurred_at: value.occurred_at,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct JournalEntryRevisionResponse {
+    pub revision: u32,
+    pub title: String,
+    pub body: String,
+    pub edited_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<JournalEntryRevision> for JournalEntryRevisionResponse {
+    fn from(value: JournalEntryRevision) -> Self {
+        Self {
+            revision: value.revision,
+            title: value.title,
+            body: value.body,
+            edited_by: value.edited_by,
+            created_at: value.created_at,
+        }
+    }
+}
+
+// IntoResponse implementations
+impl IntoResponse for JournalEntryResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
@@ -1,19 +1,39 @@
+pub mod account_export;
+pub mod admin;
+pub mod announcement;
+pub mod attachment;
+pub mod audit_log;
 pub mod auth;
+pub mod chat;
 pub mod common;
 pub mod game_system;
+pub mod image;
+pub mod journal;
 pub mod request;
 pub mod session;
 pub mod session_management;
 pub mod table;
 pub mod table_member;
+pub mod table_question;
 pub mod user;
+pub mod user_event;
 
+pub use account_export::*;
+pub use admin::*;
+pub use announcement::*;
+pub use attachment::*;
+pub use audit_log::*;
 pub use auth::*;
+pub use chat::*;
 pub use common::*;
 pub use game_system::*;
+pub use image::*;
+pub use journal::*;
 pub use request::*;
 pub use session::*;
 pub use session_management::*;
 pub use table::*;
 pub use table_member::*;
+pub use table_question::*;
 pub use user::*;
+pub use user_event::*;
@@ -1,11 +1,15 @@
+use crate::http::dtos::ISortOrder;
 use axum::{
     Json,
     response::{IntoResponse, Response},
 };
 use chrono::{DateTime, Utc};
-use domain::entities::{TableRequest, TableRequestStatus};
+use domain::entities::{
+    ApplicationAnswer, TableRequest, TableRequestSortField, TableRequestStatus,
+};
+use domain::repositories::SortParams;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -13,6 +17,44 @@ use validator::Validate;
 pub struct CreateTableRequestRequest {
     #[validate(length(max = 500))]
     pub message: Option<String>,
+    /// Answers to the table's questionnaire, if it has one.
+    #[serde(default)]
+    pub answers: Vec<ApplicationAnswerRequest>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
+pub struct ApplicationAnswerRequest {
+    pub question_id: Uuid,
+    /// One entry for text and single-choice questions.
+    pub values: Vec<String>,
+}
+
+impl From<ApplicationAnswerRequest> for ApplicationAnswer {
+    fn from(value: ApplicationAnswerRequest) -> Self {
+        Self {
+            question_id: value.question_id,
+            // Filled in from the questionnaire once the answer is validated.
+            prompt: String::new(),
+            values: value.values,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
+pub struct ApplicationAnswerResponse {
+    pub question_id: Uuid,
+    pub prompt: String,
+    pub values: Vec<String>,
+}
+
+impl From<ApplicationAnswer> for ApplicationAnswerResponse {
+    fn from(value: ApplicationAnswer) -> Self {
+        Self {
+            question_id: value.question_id,
+            prompt: value.prompt,
+            values: value.values,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
@@ -20,6 +62,38 @@ pub struct CreateTableRequestResponse {
     pub id: Uuid,
 }
 
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum ITableRequestSortField {
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl From<ITableRequestSortField> for TableRequestSortField {
+    fn from(value: ITableRequestSortField) -> Self {
+        match value {
+            ITableRequestSortField::CreatedAt => TableRequestSortField::CreatedAt,
+            ITableRequestSortField::UpdatedAt => TableRequestSortField::UpdatedAt,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct TableRequestListQuery {
+    #[serde(rename = "sortBy")]
+    pub sort_by: Option<ITableRequestSortField>,
+    pub order: Option<ISortOrder>,
+}
+
+impl From<TableRequestListQuery> for SortParams<TableRequestSortField> {
+    fn from(value: TableRequestListQuery) -> Self {
+        Self {
+            field: value.sort_by.unwrap_or_default().into(),
+            order: value.order.unwrap_or_default().into(),
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct SentRequestItem {
     pub id: Uuid,
@@ -64,6 +138,7 @@ pub struct ReceivedRequestItem {
     pub table_id: Uuid,
     pub request_date: DateTime<Utc>,
     pub message: Option<String>,
+    pub answers: Vec<ApplicationAnswerResponse>,
 }
 
 impl From<TableRequest> for ReceivedRequestItem {
@@ -74,6 +149,11 @@ impl From<TableRequest> for ReceivedRequestItem {
             table_id: request.table_id,
             request_date: request.created_at,
             message: request.message,
+            answers: request
+                .answers
+                .into_iter()
+                .map(ApplicationAnswerResponse::from)
+                .collect(),
         }
     }
 }
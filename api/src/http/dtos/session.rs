@@ -1,11 +1,15 @@
+use crate::http::dtos::ISortOrder;
 use axum::{
     Json,
     response::{IntoResponse, Response},
 };
 use chrono::{DateTime, Utc};
-use domain::entities::{Session, SessionStatus, session_checkin::SessionCheckinData};
+use domain::entities::{
+    Session, SessionSortField, SessionStatus, session_checkin::SessionCheckinData,
+};
+use domain::repositories::SortParams;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -110,6 +114,40 @@ impl From<Session> for GetSessionsResponse {
     }
 }
 
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum ISessionSortField {
+    #[default]
+    CreatedAt,
+    ScheduledFor,
+    Title,
+}
+
+impl From<ISessionSortField> for SessionSortField {
+    fn from(value: ISessionSortField) -> Self {
+        match value {
+            ISessionSortField::CreatedAt => SessionSortField::CreatedAt,
+            ISessionSortField::ScheduledFor => SessionSortField::ScheduledFor,
+            ISessionSortField::Title => SessionSortField::Title,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct SessionListQuery {
+    #[serde(rename = "sortBy")]
+    pub sort_by: Option<ISessionSortField>,
+    pub order: Option<ISortOrder>,
+}
+
+impl From<SessionListQuery> for SortParams<SessionSortField> {
+    fn from(value: SessionListQuery) -> Self {
+        Self {
+            field: value.sort_by.unwrap_or_default().into(),
+            order: value.order.unwrap_or_default().into(),
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct SessionListItem {
     pub id: Uuid,
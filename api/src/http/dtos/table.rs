@@ -3,13 +3,16 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use chrono::{DateTime, Utc};
-use domain::entities::{Session, Table, TableDetails, TableStatus, User};
+use domain::entities::{
+    AgeRating, Session, Table, TableDetails, TableFormat, TableListing, TableLocation, TableStatus,
+    TableVisibility, User,
+};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::http::dtos::ISessionStatus;
+use crate::http::dtos::{ISessionStatus, deserialize_nullable};
 
 #[derive(Deserialize, Serialize, ToSchema, Validate)]
 pub struct CreateTableRequest {
@@ -20,20 +23,70 @@ pub struct CreateTableRequest {
     pub description: String,
     #[validate(range(min = 1, max = 20))]
     pub max_players: i32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[validate(length(min = 2, max = 10))]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub format: ITableFormat,
+    #[serde(default)]
+    pub beginner_friendly: bool,
+    #[serde(default)]
+    pub age_rating: IAgeRating,
+    pub location: Option<ITableLocation>,
+    #[serde(default)]
+    pub visibility: ITableVisibility,
 }
 
 #[derive(Deserialize, Serialize, ToSchema, Validate)]
 pub struct UpdateTableRequest {
     #[validate(length(min = 1, max = 100))]
     pub title: Option<String>,
-    #[validate(length(min = 1, max = 50))]
-    pub system: Option<String>,
+    pub system_id: Option<Uuid>,
     #[validate(length(max = 1000))]
     pub description: Option<String>,
     #[validate(range(min = 1, max = 20))]
     pub max_players: Option<i32>,
-    pub visibility: Option<String>,
-    pub status: Option<String>,
+    pub visibility: Option<ITableVisibility>,
+    pub status: Option<ITableStatus>,
+    pub tags: Option<Vec<String>>,
+    /// An empty string clears the language.
+    #[validate(length(max = 10))]
+    pub language: Option<String>,
+    pub format: Option<ITableFormat>,
+    pub beginner_friendly: Option<bool>,
+    pub age_rating: Option<IAgeRating>,
+    /// `null` removes the location, omitting the field keeps it.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[schema(value_type = Option<ITableLocation>)]
+    pub location: Option<Option<ITableLocation>>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone)]
+pub struct ITableLocation {
+    pub address: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl From<ITableLocation> for TableLocation {
+    fn from(value: ITableLocation) -> Self {
+        Self {
+            address: value.address,
+            latitude: value.latitude,
+            longitude: value.longitude,
+        }
+    }
+}
+
+impl From<TableLocation> for ITableLocation {
+    fn from(value: TableLocation) -> Self {
+        Self {
+            address: value.address,
+            latitude: value.latitude,
+            longitude: value.longitude,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, ToSchema)]
@@ -83,6 +136,17 @@ pub struct TableListItem {
     pub player_slots: u32,
     pub status: TableStatus,
     pub game_system_id: Uuid,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: ITableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: IAgeRating,
+    pub location: Option<ITableLocation>,
+    pub visibility: ITableVisibility,
+    /// Only known when the item comes from the table listing.
+    pub open_slots: Option<u32>,
+    /// Set when the listing was searched around a point.
+    pub distance_km: Option<f64>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -97,12 +161,31 @@ impl From<Table> for TableListItem {
             player_slots: value.player_slots,
             status: value.status,
             game_system_id: value.game_system_id,
+            tags: value.tags,
+            language: value.language,
+            format: value.format.into(),
+            beginner_friendly: value.beginner_friendly,
+            age_rating: value.age_rating.into(),
+            location: value.location.map(ITableLocation::from),
+            visibility: value.visibility.into(),
+            open_slots: None,
+            distance_km: None,
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
     }
 }
 
+impl From<TableListing> for TableListItem {
+    fn from(value: TableListing) -> Self {
+        Self {
+            open_slots: Some(value.open_slots),
+            distance_km: value.distance_km,
+            ..value.table.into()
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, ToSchema, Debug, Default)]
 pub enum ITableStatus {
     #[default]
@@ -110,6 +193,15 @@ pub enum ITableStatus {
     Inactive,
 }
 
+impl From<ITableStatus> for TableStatus {
+    fn from(value: ITableStatus) -> Self {
+        match value {
+            ITableStatus::Active => TableStatus::Active,
+            ITableStatus::Inactive => TableStatus::Inactive,
+        }
+    }
+}
+
 impl From<TableStatus> for ITableStatus {
     fn from(value: TableStatus) -> Self {
         match value {
@@ -119,6 +211,90 @@ impl From<TableStatus> for ITableStatus {
     }
 }
 
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum ITableFormat {
+    #[default]
+    Online,
+    InPerson,
+    Hybrid,
+}
+
+impl From<ITableFormat> for TableFormat {
+    fn from(value: ITableFormat) -> Self {
+        match value {
+            ITableFormat::Online => TableFormat::Online,
+            ITableFormat::InPerson => TableFormat::InPerson,
+            ITableFormat::Hybrid => TableFormat::Hybrid,
+        }
+    }
+}
+
+impl From<TableFormat> for ITableFormat {
+    fn from(value: TableFormat) -> Self {
+        match value {
+            TableFormat::Online => ITableFormat::Online,
+            TableFormat::InPerson => ITableFormat::InPerson,
+            TableFormat::Hybrid => ITableFormat::Hybrid,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum IAgeRating {
+    #[default]
+    AllAges,
+    Teen,
+    Mature,
+}
+
+impl From<IAgeRating> for AgeRating {
+    fn from(value: IAgeRating) -> Self {
+        match value {
+            IAgeRating::AllAges => AgeRating::AllAges,
+            IAgeRating::Teen => AgeRating::Teen,
+            IAgeRating::Mature => AgeRating::Mature,
+        }
+    }
+}
+
+impl From<AgeRating> for IAgeRating {
+    fn from(value: AgeRating) -> Self {
+        match value {
+            AgeRating::AllAges => IAgeRating::AllAges,
+            AgeRating::Teen => IAgeRating::Teen,
+            AgeRating::Mature => IAgeRating::Mature,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Default, Clone, Copy)]
+pub enum ITableVisibility {
+    #[default]
+    Public,
+    Unlisted,
+    Private,
+}
+
+impl From<ITableVisibility> for TableVisibility {
+    fn from(value: ITableVisibility) -> Self {
+        match value {
+            ITableVisibility::Public => TableVisibility::Public,
+            ITableVisibility::Unlisted => TableVisibility::Unlisted,
+            ITableVisibility::Private => TableVisibility::Private,
+        }
+    }
+}
+
+impl From<TableVisibility> for ITableVisibility {
+    fn from(value: TableVisibility) -> Self {
+        match value {
+            TableVisibility::Public => ITableVisibility::Public,
+            TableVisibility::Unlisted => ITableVisibility::Unlisted,
+            TableVisibility::Private => ITableVisibility::Private,
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct ITableDetails {
     pub id: Uuid,
@@ -130,6 +306,13 @@ pub struct ITableDetails {
     pub players: Vec<PlayerInfo>,
     pub sessions: Vec<SessionInfo>,
     pub status: ITableStatus,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: ITableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: IAgeRating,
+    pub location: Option<ITableLocation>,
+    pub visibility: ITableVisibility,
     pub created_at: DateTime<Utc>,
 }
 
@@ -145,6 +328,13 @@ impl From<TableDetails> for ITableDetails {
             players: value.players.into_iter().map(PlayerInfo::from).collect(),
             sessions: value.sessions.into_iter().map(SessionInfo::from).collect(),
             status: value.status.into(),
+            tags: value.tags,
+            language: value.language,
+            format: value.format.into(),
+            beginner_friendly: value.beginner_friendly,
+            age_rating: value.age_rating.into(),
+            location: value.location.map(ITableLocation::from),
+            visibility: value.visibility.into(),
             created_at: value.created_at,
         }
     }
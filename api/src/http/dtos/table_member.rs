@@ -1,7 +1,8 @@
+use crate::http::dtos::ISortOrder;
 use chrono::{DateTime, Utc};
 use domain::entities::TableMember;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
@@ -20,3 +21,9 @@ impl From<TableMember> for TableMemberResponse {
         }
     }
 }
+
+/// Members are listed in joining order; only its direction can be chosen.
+#[derive(Deserialize, Serialize, ToSchema, IntoParams, Default)]
+pub struct TableMemberListQuery {
+    pub order: Option<ISortOrder>,
+}
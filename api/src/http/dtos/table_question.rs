@@ -0,0 +1,81 @@
+use domain::entities::{NewTableQuestion, QuestionKind, TableQuestion};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, Copy)]
+pub enum IQuestionKind {
+    Text,
+    SingleChoice,
+    MultiChoice,
+}
+
+impl From<IQuestionKind> for QuestionKind {
+    fn from(value: IQuestionKind) -> Self {
+        match value {
+            IQuestionKind::Text => QuestionKind::Text,
+            IQuestionKind::SingleChoice => QuestionKind::SingleChoice,
+            IQuestionKind::MultiChoice => QuestionKind::MultiChoice,
+        }
+    }
+}
+
+impl From<QuestionKind> for IQuestionKind {
+    fn from(value: QuestionKind) -> Self {
+        match value {
+            QuestionKind::Text => IQuestionKind::Text,
+            QuestionKind::SingleChoice => IQuestionKind::SingleChoice,
+            QuestionKind::MultiChoice => IQuestionKind::MultiChoice,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct TableQuestionRequest {
+    #[validate(length(min = 1, max = 300))]
+    pub prompt: String,
+    pub kind: IQuestionKind,
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+impl From<TableQuestionRequest> for NewTableQuestion {
+    fn from(value: TableQuestionRequest) -> Self {
+        Self {
+            prompt: value.prompt,
+            kind: value.kind.into(),
+            options: value.options,
+            required: value.required,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct ReplaceQuestionnaireRequest {
+    #[validate(nested)]
+    pub questions: Vec<TableQuestionRequest>,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct TableQuestionResponse {
+    pub id: Uuid,
+    pub prompt: String,
+    pub kind: IQuestionKind,
+    pub options: Vec<String>,
+    pub required: bool,
+}
+
+impl From<TableQuestion> for TableQuestionResponse {
+    fn from(value: TableQuestion) -> Self {
+        Self {
+            id: value.id,
+            prompt: value.prompt,
+            kind: value.kind.into(),
+            options: value.options,
+            required: value.required,
+        }
+    }
+}
@@ -33,6 +33,14 @@ pub struct DeleteAccountRequest {
     pub password: String,
 }
 
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+pub struct RestoreAccountRequest {
+    #[validate(email)]
+    pub email: String,
+    #[validate(length(min = 6))]
+    pub password: String,
+}
+
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct UpdateProfileResponse {
     pub id: Uuid,
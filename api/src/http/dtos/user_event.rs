@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{UserEvent, UserEventKind};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// The `data` of an event on the live-update stream. The SSE `id` and
+/// `event` fields repeat `id` and `kind`.
+#[derive(Deserialize, Serialize, ToSchema)]
+pub struct UserEventResponse {
+    pub id: i64,
+    pub kind: UserEventKind,
+    /// The session, intent or request the event is about.
+    pub payload: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<UserEvent> for UserEventResponse {
+    fn from(value: UserEvent) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind,
+            payload: value.payload,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -1,10 +1,5 @@
-use axum::{
-    Json,
-    http::StatusCode,
-    response::{IntoResponse, Response},
-};
-use serde_json::json;
-use shared::error::Error;
+use axum::response::{IntoResponse, Response};
+use shared::error::{ApplicationError, Error};
 
 #[derive(Debug)]
 pub enum AuthError {
@@ -13,15 +8,11 @@ pub enum AuthError {
 
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, "Invalid token"),
-        };
-
-        let body = Json(json!({
-            "error": error_message,
-        }));
-
-        (status, body).into_response()
+        match self {
+            AuthError::InvalidToken => {
+                Error::Application(ApplicationError::Unauthenticated).into_response()
+            }
+        }
     }
 }
 
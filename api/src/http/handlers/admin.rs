@@ -0,0 +1,252 @@
+use crate::http::dtos::*;
+use crate::http::middleware::auth::{ClaimsExtractor, auth_middleware};
+use axum::extract::*;
+use axum::middleware::from_fn_with_state;
+use domain::entities::*;
+use infrastructure::state::AppState;
+use shared::Result;
+use shared::error::Error;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/users",
+    tag = "admin",
+    params(AdminUsersQuery, PaginationQuery),
+    security(("auth" = [])),
+    summary = "List registered users (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn list_users(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Query(query): Query<AdminUsersQuery>,
+    Query(page): Query<PaginationQuery>,
+) -> Result<Json<PaginatedResponse<AdminUserItem>>> {
+    let users = app_state
+        .admin_service
+        .list_users(claims.get_user_id(), query.into(), page.try_into()?)
+        .await?;
+
+    Ok(Json(users.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{user_id}/suspend",
+    tag = "admin",
+    security(("auth" = [])),
+    summary = "Suspend a user account (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn suspend_user(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+) -> Result<AdminUserItem> {
+    let user = app_state
+        .admin_service
+        .suspend_user(claims.get_user_id(), user_id)
+        .await?;
+
+    Ok(user.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{user_id}/unsuspend",
+    tag = "admin",
+    security(("auth" = [])),
+    summary = "Lift the suspension of a user account (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn unsuspend_user(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+) -> Result<AdminUserItem> {
+    let user = app_state
+        .admin_service
+        .unsuspend_user(claims.get_user_id(), user_id)
+        .await?;
+
+    Ok(user.into())
+}
+
+#[utoipa::path(
+    put,
+    path = "/users/{user_id}/role",
+    tag = "admin",
+    security(("auth" = [])),
+    summary = "Change the global role of a user (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn update_user_role(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<UpdateUserRoleRequest>,
+) -> Result<AdminUserItem> {
+    let user = app_state
+        .admin_service
+        .update_user_role(claims.get_user_id(), user_id, payload.role.into())
+        .await?;
+
+    Ok(user.into())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/tables/{table_id}",
+    tag = "admin",
+    security(("auth" = [])),
+    summary = "Force delete a table regardless of its owner (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn force_delete_table(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+) -> Result<AdminDeleteResponse> {
+    app_state
+        .admin_service
+        .force_delete_table(claims.get_user_id(), table_id)
+        .await?;
+
+    Ok(AdminDeleteResponse {
+        message: format!("Table {} deleted successfully", table_id),
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/game_systems",
+    tag = "admin",
+    security(("auth" = [])),
+    summary = "Create a new RPG System (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn create_game_system(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Json(payload): Json<CreateGameSystemRequest>,
+) -> Result<CreateTableResponse> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let id = app_state
+        .admin_service
+        .create_game_system(
+            claims.get_user_id(),
+            CreateGameSystemCommand::new(payload.name),
+        )
+        .await?
+        .id;
+
+    Ok(CreateTableResponse { id })
+}
+
+#[utoipa::path(
+    put,
+    path = "/game_systems/{game_system_id}",
+    tag = "admin",
+    security(("auth" = [])),
+    summary = "Rename an RPG System (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn update_game_system(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(game_system_id): Path<Uuid>,
+    Json(payload): Json<UpdateGameSystemRequest>,
+) -> Result<Json<GameSystemResponse>> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let command = UpdateGameSystemCommand {
+        id: game_system_id,
+        name: Some(payload.name),
+    };
+
+    let game_system = app_state
+        .admin_service
+        .update_game_system(claims.get_user_id(), command)
+        .await?;
+
+    Ok(Json(GameSystemResponse::from(&game_system)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/game_systems/{game_system_id}",
+    tag = "admin",
+    security(("auth" = [])),
+    summary = "Delete an RPG System (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn delete_game_system(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(game_system_id): Path<Uuid>,
+) -> Result<AdminDeleteResponse> {
+    app_state
+        .admin_service
+        .delete_game_system(
+            claims.get_user_id(),
+            DeleteGameSystemCommand { id: game_system_id },
+        )
+        .await?;
+
+    Ok(AdminDeleteResponse {
+        message: format!("Game system {} deleted successfully", game_system_id),
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/audit",
+    tag = "admin",
+    params(AuditLogQuery),
+    security(("auth" = [])),
+    summary = "Query the platform-wide audit log (admin only)"
+)]
+#[axum::debug_handler]
+pub async fn list_audit_logs(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<Json<Vec<AuditLogResponse>>> {
+    let entries = app_state
+        .admin_service
+        .list_audit_logs(claims.get_user_id(), query.into())
+        .await?
+        .into_iter()
+        .map(AuditLogResponse::from)
+        .collect();
+
+    Ok(Json(entries))
+}
+
+pub fn admin_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .nest(
+            "/admin",
+            OpenApiRouter::new()
+                .routes(routes!(list_users))
+                .routes(routes!(suspend_user))
+                .routes(routes!(unsuspend_user))
+                .routes(routes!(update_user_role))
+                .routes(routes!(force_delete_table))
+                .routes(routes!(create_game_system))
+                .routes(routes!(update_game_system, delete_game_system))
+                .routes(routes!(list_audit_logs)),
+        )
+        .layer(from_fn_with_state(state.clone(), auth_middleware))
+        .with_state(state)
+}
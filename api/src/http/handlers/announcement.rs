@@ -0,0 +1,283 @@
+use crate::http::dtos::*;
+use crate::http::middleware::auth::{ClaimsExtractor, auth_middleware};
+use axum::extract::*;
+use axum::http::StatusCode;
+use axum::middleware::from_fn_with_state;
+use domain::entities::commands::announcement_commands::*;
+use infrastructure::state::AppState;
+use shared::Result;
+use shared::error::*;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/tables/{table_id}/announcements",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Post an announcement to a table (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn create_announcement(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    Json(payload): Json<CreateAnnouncementRequest>,
+) -> Result<(StatusCode, Json<AnnouncementResponse>)> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let user_id = claims.get_user_id();
+    let command = CreateAnnouncementCommand {
+        pinned: payload.pinned,
+        ..CreateAnnouncementCommand::new(table_id, user_id, payload.title, payload.body)
+    };
+
+    let announcement = app_state
+        .announcement_service
+        .create_announcement(user_id, command)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(announcement.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/tables/{table_id}/announcements",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Get the announcements of a table, pinned first (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_table_announcements(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+) -> Result<Json<Vec<AnnouncementListingResponse>>> {
+    let announcements = app_state
+        .announcement_service
+        .list_announcements(claims.get_user_id(), table_id)
+        .await?
+        .into_iter()
+        .map(AnnouncementListingResponse::from)
+        .collect();
+
+    Ok(Json(announcements))
+}
+
+#[utoipa::path(
+    get,
+    path = "/announcements/{announcement_id}",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Get an announcement (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_announcement(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(announcement_id): Path<Uuid>,
+) -> Result<AnnouncementResponse> {
+    let announcement = app_state
+        .announcement_service
+        .get_announcement(claims.get_user_id(), announcement_id)
+        .await?;
+
+    Ok(announcement.into())
+}
+
+#[utoipa::path(
+    put,
+    path = "/announcements/{announcement_id}",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Edit, pin or unpin an announcement (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn update_announcement(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(announcement_id): Path<Uuid>,
+    Json(payload): Json<UpdateAnnouncementRequest>,
+) -> Result<AnnouncementResponse> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let command = UpdateAnnouncementCommand {
+        title: payload.title,
+        body: payload.body,
+        pinned: payload.pinned,
+        ..UpdateAnnouncementCommand::new(announcement_id)
+    };
+
+    let announcement = app_state
+        .announcement_service
+        .update_announcement(claims.get_user_id(), command)
+        .await?;
+
+    Ok(announcement.into())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/announcements/{announcement_id}",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Delete an announcement with its comments and read receipts (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn delete_announcement(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(announcement_id): Path<Uuid>,
+) -> Result<StatusCode> {
+    app_state
+        .announcement_service
+        .delete_announcement(claims.get_user_id(), announcement_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/announcements/{announcement_id}/read",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Mark an announcement as read (members only)"
+)]
+#[axum::debug_handler]
+pub async fn mark_announcement_read(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(announcement_id): Path<Uuid>,
+) -> Result<StatusCode> {
+    app_state
+        .announcement_service
+        .mark_read(claims.get_user_id(), announcement_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/announcements/{announcement_id}/reads",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Get who has read an announcement (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn get_announcement_reads(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(announcement_id): Path<Uuid>,
+) -> Result<Json<Vec<AnnouncementReadReceiptResponse>>> {
+    let receipts = app_state
+        .announcement_service
+        .get_read_receipts(claims.get_user_id(), announcement_id)
+        .await?
+        .into_iter()
+        .map(AnnouncementReadReceiptResponse::from)
+        .collect();
+
+    Ok(Json(receipts))
+}
+
+#[utoipa::path(
+    post,
+    path = "/announcements/{announcement_id}/comments",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Comment on an announcement (members only)"
+)]
+#[axum::debug_handler]
+pub async fn create_announcement_comment(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(announcement_id): Path<Uuid>,
+    Json(payload): Json<CreateAnnouncementCommentRequest>,
+) -> Result<(StatusCode, Json<AnnouncementCommentResponse>)> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let user_id = claims.get_user_id();
+    let command = CreateAnnouncementCommentCommand::new(announcement_id, user_id, payload.body);
+
+    let comment = app_state
+        .announcement_service
+        .add_comment(user_id, command)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(comment.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/announcements/{announcement_id}/comments",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Get the comments on an announcement, oldest first (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_announcement_comments(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(announcement_id): Path<Uuid>,
+) -> Result<Json<Vec<AnnouncementCommentResponse>>> {
+    let comments = app_state
+        .announcement_service
+        .list_comments(claims.get_user_id(), announcement_id)
+        .await?
+        .into_iter()
+        .map(AnnouncementCommentResponse::from)
+        .collect();
+
+    Ok(Json(comments))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/announcements/{announcement_id}/comments/{comment_id}",
+    tag = "announcement",
+    security(("auth" = [])),
+    summary = "Delete a comment (comment author or GM)"
+)]
+#[axum::debug_handler]
+pub async fn delete_announcement_comment(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path((announcement_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode> {
+    app_state
+        .announcement_service
+        .delete_comment(claims.get_user_id(), announcement_id, comment_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn announcement_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .routes(routes!(create_announcement, get_table_announcements))
+        .routes(routes!(
+            get_announcement,
+            update_announcement,
+            delete_announcement
+        ))
+        .routes(routes!(mark_announcement_read))
+        .routes(routes!(get_announcement_reads))
+        .routes(routes!(
+            create_announcement_comment,
+            get_announcement_comments
+        ))
+        .routes(routes!(delete_announcement_comment))
+        .layer(from_fn_with_state(state.clone(), auth_middleware))
+        .with_state(state)
+}
@@ -0,0 +1,197 @@
+use crate::http::dtos::*;
+use crate::http::middleware::auth::{ClaimsExtractor, auth_middleware};
+use crate::http::multipart::{MULTIPART_OVERHEAD_BYTES, UploadedFile, read_file_part};
+use application::attachment_service::AttachmentUpload;
+use axum::extract::*;
+use axum::http::StatusCode;
+use axum::middleware::from_fn_with_state;
+use infrastructure::state::AppState;
+use shared::Result;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+impl From<UploadedFile> for AttachmentUpload {
+    fn from(value: UploadedFile) -> Self {
+        Self {
+            file_name: value.file_name,
+            content_type: value.content_type,
+            bytes: value.bytes,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/tables/{table_id}/attachments",
+    tag = "attachment",
+    request_body(content = FileUploadForm, content_type = "multipart/form-data"),
+    security(("auth" = [])),
+    summary = "Upload a handout shared with the whole table (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn upload_table_attachment(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<AttachmentResponse>)> {
+    let file = read_file_part(multipart).await?;
+
+    let attachment = app_state
+        .attachment_service
+        .upload_to_table(claims.get_user_id(), table_id, file.into())
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(attachment.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/tables/{table_id}/attachments",
+    tag = "attachment",
+    security(("auth" = [])),
+    summary = "List the table-wide handouts (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_table_attachments(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+) -> Result<Json<Vec<AttachmentResponse>>> {
+    let attachments = app_state
+        .attachment_service
+        .list_for_table(claims.get_user_id(), table_id)
+        .await?
+        .into_iter()
+        .map(AttachmentResponse::from)
+        .collect();
+
+    Ok(Json(attachments))
+}
+
+#[utoipa::path(
+    get,
+    path = "/tables/{table_id}/attachments/usage",
+    tag = "attachment",
+    security(("auth" = [])),
+    summary = "Get how much of the table's attachment quota is used (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_attachment_usage(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+) -> Result<AttachmentUsageResponse> {
+    let usage = app_state
+        .attachment_service
+        .get_usage(claims.get_user_id(), table_id)
+        .await?;
+
+    Ok(usage.into())
+}
+
+#[utoipa::path(
+    post,
+    path = "/sessions/{session_id}/attachments",
+    tag = "attachment",
+    request_body(content = FileUploadForm, content_type = "multipart/form-data"),
+    security(("auth" = [])),
+    summary = "Upload a map or handout for a session (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn upload_session_attachment(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(session_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<AttachmentResponse>)> {
+    let file = read_file_part(multipart).await?;
+
+    let attachment = app_state
+        .attachment_service
+        .upload_to_session(claims.get_user_id(), session_id, file.into())
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(attachment.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/sessions/{session_id}/attachments",
+    tag = "attachment",
+    security(("auth" = [])),
+    summary = "List the attachments of a session (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_session_attachments(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<Vec<AttachmentResponse>>> {
+    let attachments = app_state
+        .attachment_service
+        .list_for_session(claims.get_user_id(), session_id)
+        .await?
+        .into_iter()
+        .map(AttachmentResponse::from)
+        .collect();
+
+    Ok(Json(attachments))
+}
+
+#[utoipa::path(
+    get,
+    path = "/attachments/{attachment_id}",
+    tag = "attachment",
+    security(("auth" = [])),
+    summary = "Download an attachment (members only)"
+)]
+#[axum::debug_handler]
+pub async fn download_attachment(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<AttachmentDownload> {
+    let file = app_state
+        .attachment_service
+        .download(claims.get_user_id(), attachment_id)
+        .await?;
+
+    Ok(AttachmentDownload(file))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/attachments/{attachment_id}",
+    tag = "attachment",
+    security(("auth" = [])),
+    summary = "Delete an attachment (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn delete_attachment(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<StatusCode> {
+    app_state
+        .attachment_service
+        .delete(claims.get_user_id(), attachment_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn attachment_routes(state: Arc<AppState>) -> OpenApiRouter {
+    let body_limit = state.attachment_service.max_attachment_bytes() + MULTIPART_OVERHEAD_BYTES;
+
+    OpenApiRouter::new()
+        .routes(routes!(upload_table_attachment, get_table_attachments))
+        .routes(routes!(get_attachment_usage))
+        .routes(routes!(upload_session_attachment, get_session_attachments))
+        .routes(routes!(download_attachment, delete_attachment))
+        .layer(DefaultBodyLimit::max(body_limit))
+        .layer(from_fn_with_state(state.clone(), auth_middleware))
+        .with_state(state)
+}
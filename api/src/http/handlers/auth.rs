@@ -2,8 +2,11 @@ use crate::http::dtos::LoginResponse;
 use crate::http::dtos::*;
 use crate::http::middleware::auth::ClaimsExtractor;
 use crate::http::middleware::auth::auth_middleware;
+use application::account_export_service::AccountExportOutcome;
 use application::user_service::UpdateProfileCommand;
+use axum::extract::Path;
 use axum::middleware::from_fn_with_state;
+use axum::response::{IntoResponse, Response};
 use axum::{extract::State, http::StatusCode, *};
 use domain::auth::*;
 use domain::entities::commands::DeleteAccountCommand;
@@ -13,6 +16,7 @@ use shared::*;
 use std::sync::Arc;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
+use uuid::Uuid;
 use validator::Validate;
 
 #[utoipa::path(post, path = "/login", summary = "User login", tag = "auth")]
@@ -43,6 +47,71 @@ async fn login(
     ))
 }
 
+#[utoipa::path(
+    post,
+    path = "/magic-link",
+    summary = "Request a one-time sign-in link by email",
+    description = "Always answers `202 Accepted`, whether or not the address belongs to an account.",
+    tag = "auth"
+)]
+#[axum::debug_handler]
+async fn request_magic_link(
+    State(app_state): State<Arc<AppState>>,
+    Json(payload): Json<MagicLinkRequest>,
+) -> Result<(StatusCode, Json<MagicLinkResponse>)> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    app_state
+        .auth_service
+        .request_magic_link(MagicLinkRequestCommand {
+            email: payload.email,
+        })
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(MagicLinkResponse {
+            message: "If the address belongs to an account, a sign-in link is on its way"
+                .to_string(),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/magic-link/verify",
+    summary = "Sign in with a one-time link token",
+    tag = "auth"
+)]
+#[axum::debug_handler]
+async fn login_with_magic_link(
+    State(app_state): State<Arc<AppState>>,
+    Json(payload): Json<MagicLinkLoginRequest>,
+) -> Result<(StatusCode, Json<LoginResponse>)> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let auth_response = app_state
+        .auth_service
+        .login_with_magic_link(MagicLinkLoginCommand {
+            token: payload.token,
+        })
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(LoginResponse {
+            user: auth_response.user.into(),
+            token: auth_response.access_token,
+            refresh_token: auth_response.refresh_token,
+            expires_in: auth_response.expires_in,
+        }),
+    ))
+}
+
 #[utoipa::path(post, path = "/register", summary = "User registration", tag = "auth")]
 #[axum::debug_handler]
 async fn register(
@@ -241,10 +310,96 @@ pub async fn delete_account(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/account/restore",
+    tag = "auth",
+    summary = "Restore a deleted account within its grace period"
+)]
+#[axum::debug_handler]
+pub async fn restore_account(
+    State(app_state): State<Arc<AppState>>,
+    Json(payload): Json<RestoreAccountRequest>,
+) -> Result<UserResponse> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let user = app_state
+        .user_service
+        .restore_account(&payload.email, &payload.password)
+        .await?;
+
+    Ok(user.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/export",
+    tag = "auth",
+    summary = "Export all personal data of the authenticated user",
+    description = "Small accounts receive the JSON archive directly. Larger accounts get `202 Accepted` with an export job to poll; while one is pending, the same job is returned.",
+    security(("auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn export_account(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+) -> Result<Response> {
+    let user_id = claims.get_user_id();
+
+    let outcome = app_state
+        .account_export_service
+        .request_export(user_id)
+        .await?;
+
+    match outcome {
+        AccountExportOutcome::Ready(archive) => Ok(AccountExportDownload {
+            user_id,
+            archive: serde_json::to_value(&archive).map_err(|_| Error::InternalServerError)?,
+        }
+        .into_response()),
+        AccountExportOutcome::Pending(export) => {
+            Ok(AccountExportStatusResponse::from(export).into_response())
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/export/{export_id}",
+    tag = "auth",
+    summary = "Download a personal data export job once it is ready",
+    security(("auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn get_account_export(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(export_id): Path<Uuid>,
+) -> Result<Response> {
+    let user_id = claims.get_user_id();
+
+    let export = app_state
+        .account_export_service
+        .find_export(user_id, export_id)
+        .await?;
+
+    match export.payload.clone() {
+        Some(archive) if export.is_ready() => {
+            Ok(AccountExportDownload { user_id, archive }.into_response())
+        }
+        _ => Ok(AccountExportStatusResponse::from(export).into_response()),
+    }
+}
+
 pub fn auth_routes(state: Arc<AppState>) -> OpenApiRouter {
     let public = OpenApiRouter::new()
         .routes(routes!(register))
-        .routes(routes!(login));
+        .routes(routes!(login))
+        .routes(routes!(request_magic_link))
+        .routes(routes!(login_with_magic_link))
+        .routes(routes!(restore_account));
 
     let protected = OpenApiRouter::new()
         .routes(routes!(logout))
@@ -253,6 +408,8 @@ pub fn auth_routes(state: Arc<AppState>) -> OpenApiRouter {
         .routes(routes!(update_profile))
         .routes(routes!(change_password))
         .routes(routes!(delete_account))
+        .routes(routes!(export_account))
+        .routes(routes!(get_account_export))
         .layer(from_fn_with_state(state.clone(), auth_middleware));
 
     OpenApiRouter::new()
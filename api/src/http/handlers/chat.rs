@@ -0,0 +1,246 @@
+use crate::http::dtos::*;
+use crate::http::middleware::auth::{
+    ClaimsExtractor, auth_middleware, query_token_auth_middleware,
+};
+use application::chat_service::ChatService;
+use axum::extract::ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code};
+use axum::extract::*;
+use axum::middleware::from_fn_with_state;
+use axum::response::Response;
+use domain::entities::commands::chat_commands::*;
+use futures::{SinkExt, StreamExt};
+use infrastructure::setup::shutdown::Shutdown;
+use infrastructure::state::AppState;
+use shared::Result;
+use shared::error::*;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::warn;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+/// Typing indicators from one connection are forwarded at most this often.
+const TYPING_THROTTLE: Duration = Duration::from_secs(2);
+/// How long a removed member or suspended user may keep an open socket.
+const ACCESS_RECHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+#[utoipa::path(
+    get,
+    path = "/tables/{table_id}/chat",
+    tag = "chat",
+    params(
+        ("access_token" = Option<String>, Query, description = "JWT, for clients that cannot send an Authorization header")
+    ),
+    security(("auth" = [])),
+    summary = "Open the chat WebSocket of a table (members only)",
+    description = "Clients send `{\"type\":\"message\",\"body\":\"...\"}` and `{\"type\":\"typing\"}` frames. \
+        The server sends `message`, `typing` and `error` frames, see `ChatServerFrame`. \
+        The socket is closed with code 1008 once the user loses access to the table or the token expires."
+)]
+#[axum::debug_handler]
+pub async fn open_table_chat(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    ws: WebSocketUpgrade,
+) -> Result<Response> {
+    let user_id = claims.get_user_id();
+    app_state.chat_service.join(user_id, table_id).await?;
+
+    let chat_service = app_state.chat_service.clone();
+    let shutdown = app_state.shutdown.clone();
+    let token_ttl = (claims.0.exp - chrono::Utc::now().timestamp()).max(0) as u64;
+    let token_expiry = tokio::time::Instant::now() + Duration::from_secs(token_ttl);
+
+    Ok(ws.on_upgrade(move |socket| {
+        run_chat(
+            socket,
+            chat_service,
+            shutdown,
+            user_id,
+            table_id,
+            token_expiry,
+        )
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/tables/{table_id}/chat/messages",
+    tag = "chat",
+    params(ChatHistoryQuery),
+    security(("auth" = [])),
+    summary = "Get the chat history of a table, newest first (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_chat_history(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    Query(query): Query<ChatHistoryQuery>,
+) -> Result<Json<ChatHistoryResponse>> {
+    let page = app_state
+        .chat_service
+        .get_history(claims.get_user_id(), table_id, query.before, query.limit)
+        .await?;
+
+    Ok(Json(page.into()))
+}
+
+async fn run_chat(
+    socket: WebSocket,
+    chat_service: ChatService,
+    shutdown: Shutdown,
+    user_id: Uuid,
+    table_id: Uuid,
+    token_expiry: tokio::time::Instant,
+) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut events = chat_service.subscribe();
+    let mut last_typing: Option<Instant> = None;
+    let mut access_check = tokio::time::interval_at(
+        tokio::time::Instant::now() + ACCESS_RECHECK_INTERVAL,
+        ACCESS_RECHECK_INTERVAL,
+    );
+
+    loop {
+        tokio::select! {
+            _ = shutdown.triggered() => {
+                // "Going away" tells clients to reconnect to another instance.
+                let frame = CloseFrame {
+                    code: close_code::AWAY,
+                    reason: "Server is shutting down".into(),
+                };
+                let _ = sender.send(Message::Close(Some(frame))).await;
+                break;
+            }
+            _ = tokio::time::sleep_until(token_expiry) => {
+                close_for_policy(&mut sender, "Access token expired").await;
+                break;
+            }
+            _ = access_check.tick() => {
+                if let Err(err) = chat_service.join(user_id, table_id).await {
+                    if is_revoked(&err) {
+                        close_for_policy(&mut sender, "Access to the table was revoked").await;
+                        break;
+                    }
+                    warn!("Could not recheck chat access of {}: {}", user_id, err);
+                }
+            }
+            incoming = receiver.next() => {
+                let text = match incoming {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => continue,
+                };
+
+                let result = match serde_json::from_str::<ChatClientFrame>(text.as_str()) {
+                    Ok(ChatClientFrame::Message { body }) => {
+                        let command = CreateChatMessageCommand::new(table_id, user_id, body);
+                        chat_service.send_message(user_id, command).await.map(|_| ())
+                    }
+                    Ok(ChatClientFrame::Typing) => {
+                        if last_typing.is_some_and(|at| at.elapsed() < TYPING_THROTTLE) {
+                            continue;
+                        }
+                        last_typing = Some(Instant::now());
+                        chat_service.send_typing(user_id, table_id).await
+                    }
+                    Err(err) => Err(Error::Application(ApplicationError::InvalidInput {
+                        message: format!("Invalid chat frame: {}", err),
+                    })),
+                };
+
+                if let Err(err) = result {
+                    // Losing access to the table ends the conversation.
+                    let revoked = is_revoked(&err);
+                    let message = match err {
+                        Error::Application(ApplicationError::InvalidInput { message }) => message,
+                        other => other.to_string(),
+                    };
+                    let frame = ChatServerFrame::Error { message };
+
+                    if send_frame(&mut sender, &frame).await.is_err() || revoked {
+                        break;
+                    }
+                }
+            }
+            event = events.recv() => {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("Chat connection of {} skipped {} events", user_id, skipped);
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                if event.table_id() != table_id {
+                    continue;
+                }
+
+                let frame = ChatServerFrame::from(event);
+                if matches!(frame, ChatServerFrame::Typing { user_id: typist, .. } if typist == user_id) {
+                    continue;
+                }
+
+                if send_frame(&mut sender, &frame).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Errors from [`ChatService::join`] that mean the user may no longer be in
+/// the chat, as opposed to transient failures.
+fn is_revoked(err: &Error) -> bool {
+    matches!(
+        err,
+        Error::Application(
+            ApplicationError::Forbidden
+                | ApplicationError::Unauthenticated
+                | ApplicationError::AccountSuspended
+        ) | Error::Domain(DomainError::EntityNotFound { .. })
+    )
+}
+
+async fn close_for_policy<S>(sender: &mut S, reason: &'static str)
+where
+    S: SinkExt<Message> + Unpin,
+{
+    let frame = CloseFrame {
+        code: close_code::POLICY,
+        reason: reason.into(),
+    };
+    let _ = sender.send(Message::Close(Some(frame))).await;
+}
+
+async fn send_frame<S>(sender: &mut S, frame: &ChatServerFrame) -> std::result::Result<(), ()>
+where
+    S: SinkExt<Message> + Unpin,
+{
+    let text = serde_json::to_string(frame).map_err(|_| ())?;
+    sender
+        .send(Message::Text(text.into()))
+        .await
+        .map_err(|_| ())
+}
+
+pub fn chat_routes(state: Arc<AppState>) -> OpenApiRouter {
+    let socket_routes = OpenApiRouter::new()
+        .routes(routes!(open_table_chat))
+        .layer(from_fn_with_state(
+            state.clone(),
+            query_token_auth_middleware,
+        ))
+        .with_state(state.clone());
+
+    OpenApiRouter::new()
+        .routes(routes!(get_chat_history))
+        .layer(from_fn_with_state(state.clone(), auth_middleware))
+        .with_state(state)
+        .merge(socket_routes)
+}
@@ -1,39 +1,10 @@
-use crate::http::{
-    dtos::{CreateGameSystemRequest, CreateTableResponse, GameSystemResponse},
-    middleware::auth::auth_middleware,
-};
+use crate::http::{dtos::GameSystemResponse, middleware::auth::auth_middleware};
 use axum::{Json, extract::State, middleware::from_fn_with_state};
-use domain::entities::{CreateGameSystemCommand, GetGameSystemCommand};
+use domain::entities::GetGameSystemCommand;
 use infrastructure::state::AppState;
-use shared::{Error, Result};
+use shared::Result;
 use std::sync::Arc;
 use utoipa_axum::{router::OpenApiRouter, routes};
-use validator::Validate;
-
-#[utoipa::path(
-    post,
-    path = "/",
-    tag = "game-system",
-    summary = "Create a new RPG System",
-    security(("auth" = []))
-)]
-#[axum::debug_handler]
-async fn create_game_system(
-    State(app_state): State<Arc<AppState>>,
-    Json(payload): Json<CreateGameSystemRequest>,
-) -> Result<CreateTableResponse> {
-    if let Err(validation_error) = payload.validate() {
-        return Err(Error::Validation(validation_error));
-    }
-
-    let id = app_state
-        .game_system_service
-        .create(CreateGameSystemCommand::new(payload.name))
-        .await?
-        .id;
-
-    Ok(CreateTableResponse { id })
-}
 
 #[utoipa::path(
     get,
@@ -59,7 +30,6 @@ async fn get_game_systems(
 
 pub fn game_system_routes(state: Arc<AppState>) -> OpenApiRouter {
     let protected = OpenApiRouter::new()
-        .routes(routes!(create_game_system))
         .routes(routes!(get_game_systems))
         .layer(from_fn_with_state(state.clone(), auth_middleware));
 
@@ -1,7 +1,9 @@
-use axum::Json;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use chrono::Utc;
+use infrastructure::state::AppState;
 use serde_json::Value;
 use serde_json::json;
+use std::sync::Arc;
 
 #[utoipa::path(get, path = "/health", summary = "Get API status", tag = "health")]
 pub async fn health_check() -> Json<Value> {
@@ -12,3 +14,41 @@ pub async fn health_check() -> Json<Value> {
         "version": env!("CARGO_PKG_VERSION")
     }))
 }
+
+/// Answers as long as the process can serve requests at all; dependencies
+/// are deliberately not checked so an outage does not get the pod restarted.
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    summary = "Liveness probe",
+    tag = "health",
+    responses((status = 200, description = "The process is running"))
+)]
+pub async fn liveness_check() -> Json<Value> {
+    Json(json!({
+        "status": "alive",
+        "timestamp": Utc::now().to_rfc3339(),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    summary = "Readiness probe",
+    description = "Checks the database, pending migrations and the configured storage and mail backends, reporting each component. Failure details are only logged; the mail check is reused for a minute.",
+    tag = "health",
+    responses(
+        (status = 200, description = "Every dependency is available"),
+        (status = 503, description = "At least one dependency is unavailable")
+    )
+)]
+pub async fn readiness_check(State(app_state): State<Arc<AppState>>) -> impl IntoResponse {
+    let report = app_state.health.readiness().await;
+    let status = if report.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(report))
+}
@@ -0,0 +1,163 @@
+use crate::http::dtos::*;
+use crate::http::middleware::auth::{ClaimsExtractor, auth_middleware};
+use crate::http::multipart::{MULTIPART_OVERHEAD_BYTES, read_file_part};
+use axum::extract::*;
+use axum::http::StatusCode;
+use axum::middleware::from_fn_with_state;
+use infrastructure::state::AppState;
+use shared::Result;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+#[utoipa::path(
+    put,
+    path = "/tables/{table_id}/cover",
+    tag = "image",
+    request_body(content = FileUploadForm, content_type = "multipart/form-data"),
+    security(("auth" = [])),
+    summary = "Upload or replace the cover image of a table (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn upload_table_cover(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    multipart: Multipart,
+) -> Result<ImageResponse> {
+    let file = read_file_part(multipart).await?;
+
+    let image = app_state
+        .image_service
+        .upload_table_cover(claims.get_user_id(), table_id, file.bytes)
+        .await?;
+
+    Ok(image.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/tables/{table_id}/cover",
+    tag = "image",
+    params(ImageQuery),
+    security(("auth" = [])),
+    summary = "Download the cover image of a table"
+)]
+#[axum::debug_handler]
+pub async fn get_table_cover(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    Query(query): Query<ImageQuery>,
+) -> Result<ImageFileResponse> {
+    let file = app_state
+        .image_service
+        .get_table_cover(claims.get_user_id(), table_id, query.size.into())
+        .await?;
+
+    Ok(ImageFileResponse(file))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/tables/{table_id}/cover",
+    tag = "image",
+    security(("auth" = [])),
+    summary = "Remove the cover image of a table (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn delete_table_cover(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+) -> Result<StatusCode> {
+    app_state
+        .image_service
+        .delete_table_cover(claims.get_user_id(), table_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/users/me/avatar",
+    tag = "image",
+    request_body(content = FileUploadForm, content_type = "multipart/form-data"),
+    security(("auth" = [])),
+    summary = "Upload or replace your avatar"
+)]
+#[axum::debug_handler]
+pub async fn upload_avatar(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    multipart: Multipart,
+) -> Result<ImageResponse> {
+    let file = read_file_part(multipart).await?;
+
+    let image = app_state
+        .image_service
+        .upload_avatar(claims.get_user_id(), file.bytes)
+        .await?;
+
+    Ok(image.into())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/me/avatar",
+    tag = "image",
+    security(("auth" = [])),
+    summary = "Remove your avatar"
+)]
+#[axum::debug_handler]
+pub async fn delete_avatar(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+) -> Result<StatusCode> {
+    app_state
+        .image_service
+        .delete_avatar(claims.get_user_id())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{user_id}/avatar",
+    tag = "image",
+    params(ImageQuery),
+    security(("auth" = [])),
+    summary = "Download the avatar of a user"
+)]
+#[axum::debug_handler]
+pub async fn get_avatar(
+    State(app_state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+    Query(query): Query<ImageQuery>,
+) -> Result<ImageFileResponse> {
+    let file = app_state
+        .image_service
+        .get_avatar(user_id, query.size.into())
+        .await?;
+
+    Ok(ImageFileResponse(file))
+}
+
+pub fn image_routes(state: Arc<AppState>) -> OpenApiRouter {
+    let body_limit = state.image_service.max_upload_bytes() + MULTIPART_OVERHEAD_BYTES;
+
+    OpenApiRouter::new()
+        .routes(routes!(
+            upload_table_cover,
+            get_table_cover,
+            delete_table_cover
+        ))
+        .routes(routes!(upload_avatar, delete_avatar))
+        .routes(routes!(get_avatar))
+        .layer(DefaultBodyLimit::max(body_limit))
+        .layer(from_fn_with_state(state.clone(), auth_middleware))
+        .with_state(state)
+}
@@ -0,0 +1,188 @@
+use crate::http::dtos::*;
+use crate::http::middleware::auth::{ClaimsExtractor, auth_middleware};
+use axum::extract::*;
+use axum::http::StatusCode;
+use axum::middleware::from_fn_with_state;
+use domain::entities::commands::journal_commands::*;
+use infrastructure::state::AppState;
+use shared::Result;
+use shared::error::*;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/tables/{table_id}/journal",
+    tag = "journal",
+    security(("auth" = [])),
+    summary = "Write a journal entry, optionally about a session (members only)"
+)]
+#[axum::debug_handler]
+pub async fn create_journal_entry(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    Json(payload): Json<CreateJournalEntryRequest>,
+) -> Result<(StatusCode, Json<JournalEntryResponse>)> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let user_id = claims.get_user_id();
+    let command = CreateJournalEntryCommand::new(
+        table_id,
+        payload.session_id,
+        user_id,
+        payload.title,
+        payload.body,
+    );
+
+    let entry = app_state
+        .journal_service
+        .create_entry(user_id, command)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(entry.into())))
+}
+
+#[utoipa::path(
+    get,
+    path = "/tables/{table_id}/journal",
+    tag = "journal",
+    params(JournalQuery),
+    security(("auth" = [])),
+    summary = "Get the campaign log of a table in chronological order (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_campaign_log(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    Query(query): Query<JournalQuery>,
+) -> Result<Json<Vec<JournalEntryResponse>>> {
+    let entries = app_state
+        .journal_service
+        .get_campaign_log(claims.get_user_id(), table_id, query.session_id)
+        .await?
+        .into_iter()
+        .map(JournalEntryResponse::from)
+        .collect();
+
+    Ok(Json(entries))
+}
+
+#[utoipa::path(
+    get,
+    path = "/journal/{entry_id}",
+    tag = "journal",
+    security(("auth" = [])),
+    summary = "Get a journal entry (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_journal_entry(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(entry_id): Path<Uuid>,
+) -> Result<JournalEntryResponse> {
+    let entry = app_state
+        .journal_service
+        .get_entry(claims.get_user_id(), entry_id)
+        .await?;
+
+    Ok(entry.into())
+}
+
+#[utoipa::path(
+    put,
+    path = "/journal/{entry_id}",
+    tag = "journal",
+    security(("auth" = [])),
+    summary = "Edit a journal entry, recording a new revision (author or GM)"
+)]
+#[axum::debug_handler]
+pub async fn update_journal_entry(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(entry_id): Path<Uuid>,
+    Json(payload): Json<UpdateJournalEntryRequest>,
+) -> Result<JournalEntryResponse> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let user_id = claims.get_user_id();
+    let command = UpdateJournalEntryCommand {
+        title: payload.title,
+        body: payload.body,
+        session_id: payload.session_id,
+        ..UpdateJournalEntryCommand::new(entry_id, user_id)
+    };
+
+    let entry = app_state
+        .journal_service
+        .update_entry(user_id, command)
+        .await?;
+
+    Ok(entry.into())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/journal/{entry_id}",
+    tag = "journal",
+    security(("auth" = [])),
+    summary = "Delete a journal entry and its history (author or GM)"
+)]
+#[axum::debug_handler]
+pub async fn delete_journal_entry(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(entry_id): Path<Uuid>,
+) -> Result<StatusCode> {
+    app_state
+        .journal_service
+        .delete_entry(claims.get_user_id(), entry_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/journal/{entry_id}/revisions",
+    tag = "journal",
+    security(("auth" = [])),
+    summary = "Get the revision history of a journal entry, newest first (members only)"
+)]
+#[axum::debug_handler]
+pub async fn get_journal_revisions(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(entry_id): Path<Uuid>,
+) -> Result<Json<Vec<JournalEntryRevisionResponse>>> {
+    let revisions = app_state
+        .journal_service
+        .get_revisions(claims.get_user_id(), entry_id)
+        .await?
+        .into_iter()
+        .map(JournalEntryRevisionResponse::from)
+        .collect();
+
+    Ok(Json(revisions))
+}
+
+pub fn journal_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .routes(routes!(create_journal_entry, get_campaign_log))
+        .routes(routes!(
+            get_journal_entry,
+            update_journal_entry,
+            delete_journal_entry
+        ))
+        .routes(routes!(get_journal_revisions))
+        .layer(from_fn_with_state(state.clone(), auth_middleware))
+        .with_state(state)
+}
@@ -0,0 +1,13 @@
+use axum::{extract::State, http::header, response::IntoResponse};
+use infrastructure::state::AppState;
+use std::sync::Arc;
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[utoipa::path(get, path = "/metrics", summary = "Prometheus metrics", tag = "health")]
+pub async fn metrics_handler(State(app_state): State<Arc<AppState>>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        app_state.metrics.render(),
+    )
+}
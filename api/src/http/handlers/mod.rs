@@ -1,34 +1,57 @@
-use crate::http::handlers::{cors::cors_layer, tracing::trace_middleware};
+use crate::http::handlers::{
+    cors::cors_layer,
+    problem::problem_details_middleware,
+    request_id::request_id_middleware,
+    tracing::{record_route, trace_middleware},
+};
 pub use crate::http::{
-    middleware::{cors, tracing},
-    open_api::ApiDoc,
+    middleware::{cors, problem, request_id, tracing},
+    open_api::{ApiDoc, ProblemDetailsAddon},
 };
 use axum::{Router, middleware::from_fn, routing::get};
 use infrastructure::state::AppState;
 use std::sync::Arc;
-use utoipa::OpenApi;
 use utoipa::openapi::OpenApi as OpenApiSpec;
+use utoipa::{Modify, OpenApi};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_swagger_ui::SwaggerUi;
 
+pub mod admin;
+pub mod announcement;
+pub mod attachment;
 pub mod auth;
+pub mod chat;
 pub mod docs;
 pub mod game_system;
 pub mod health;
+pub mod image;
+pub mod journal;
+pub mod metrics;
+pub mod search;
 pub mod session;
 pub mod session_intent;
 pub mod table;
 pub mod table_request;
 pub mod user;
+pub mod user_event;
 
+pub use admin::admin_routes;
+pub use announcement::announcement_routes;
+pub use attachment::attachment_routes;
 pub use auth::auth_routes;
+pub use chat::chat_routes;
 pub use game_system::game_system_routes;
-pub use health::health_check;
+pub use health::{health_check, liveness_check, readiness_check};
+pub use image::image_routes;
+pub use journal::journal_routes;
+pub use metrics::metrics_handler;
+pub use search::search_routes;
 pub use session::session_routes;
 pub use session_intent::session_intent_routes;
 pub use table::table_routes;
 pub use table_request::table_request_routes;
 pub use user::user_routes;
+pub use user_event::user_event_routes;
 
 pub fn create_router(app_state: Arc<AppState>) -> Router {
     let open_api_router = OpenApiRouter::with_openapi(ApiDoc::openapi()).nest(
@@ -40,21 +63,47 @@ pub fn create_router(app_state: Arc<AppState>) -> Router {
             .merge(table_request_routes(app_state.clone()))
             .merge(user_routes(app_state.clone()))
             .merge(game_system_routes(app_state.clone()))
-            .merge(session_intent_routes(app_state.clone())),
+            .merge(session_intent_routes(app_state.clone()))
+            .merge(search_routes(app_state.clone()))
+            .merge(image_routes(app_state.clone()))
+            .merge(attachment_routes(app_state.clone()))
+            .merge(journal_routes(app_state.clone()))
+            .merge(announcement_routes(app_state.clone()))
+            .merge(chat_routes(app_state.clone()))
+            .merge(user_event_routes(app_state.clone()))
+            .merge(admin_routes(app_state.clone())),
     );
 
-    let (router, api_doc) = open_api_router.split_for_parts();
+    let cors = cors_layer(&app_state.config.cors);
+    let (router, mut api_doc) = open_api_router.split_for_parts();
+    ProblemDetailsAddon.modify(&mut api_doc);
 
     router
-        .merge(build_system_routes(api_doc))
-        .layer(cors_layer())
+        .merge(build_system_routes(app_state, api_doc))
+        .route_layer(from_fn(record_route))
+        .layer(from_fn(problem_details_middleware))
+        .layer(cors)
         .layer(from_fn(trace_middleware))
+        .layer(from_fn(request_id_middleware))
 }
 
-fn build_system_routes(openapi_spec: OpenApiSpec) -> Router {
-    let swagger_ui = SwaggerUi::new("/docs").url("/api-docs/openapi.json", openapi_spec);
+fn build_system_routes(app_state: Arc<AppState>, openapi_spec: OpenApiSpec) -> Router {
+    let features = app_state.config.features;
 
-    Router::new()
+    let mut router = Router::new()
         .route("/health", get(health_check))
-        .merge(swagger_ui)
+        .route("/health/live", get(liveness_check))
+        .route("/health/ready", get(readiness_check));
+
+    if features.metrics {
+        router = router.route("/metrics", get(metrics_handler));
+    }
+
+    let router = router.with_state(app_state);
+
+    if features.api_docs {
+        router.merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", openapi_spec))
+    } else {
+        router
+    }
 }
@@ -0,0 +1,39 @@
+use crate::http::middleware::auth::{ClaimsExtractor, auth_middleware};
+use axum::extract::*;
+use axum::middleware::from_fn_with_state;
+use domain::search::{SearchQuery, SearchResponse};
+use infrastructure::state::AppState;
+use shared::Result;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+#[utoipa::path(
+    get,
+    path = "/search",
+    tag = "search",
+    params(SearchQuery),
+    responses((status = 200, body = SearchResponse)),
+    security(("auth" = [])),
+    summary = "Full-text search across tables, sessions, users and game systems"
+)]
+#[axum::debug_handler]
+pub async fn search(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<SearchResponse>> {
+    let response = app_state
+        .search_service
+        .search(claims.get_user_id(), query)
+        .await?;
+
+    Ok(Json(response))
+}
+
+pub fn search_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .routes(routes!(search))
+        .layer(from_fn_with_state(state.clone(), auth_middleware))
+        .with_state(state)
+}
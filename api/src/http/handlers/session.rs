@@ -73,6 +73,27 @@ pub async fn delete_session(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/{session_id}/restore",
+    security(("auth" = [])),
+    tag = "session",
+    summary = "Restore a deleted session within its grace period"
+)]
+#[axum::debug_handler]
+pub async fn restore_session(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<GetSessionsResponse>> {
+    let session = app_state
+        .session_service
+        .restore_session(claims.get_user_id(), session_id)
+        .await?;
+
+    Ok(Json(session.into()))
+}
+
 #[utoipa::path(
     post,
     path = "/{session_id}/start",
@@ -134,7 +155,8 @@ pub fn session_routes(state: Arc<AppState>) -> OpenApiRouter {
                 .routes(routes!(finalize_session_with_checkins))
                 .routes(routes!(start_session))
                 .routes(routes!(update_session))
-                .routes(routes!(delete_session)),
+                .routes(routes!(delete_session))
+                .routes(routes!(restore_session)),
         )
         .layer(from_fn_with_state(state.clone(), auth_middleware))
         .with_state(state)
@@ -3,6 +3,7 @@ use crate::http::middleware::auth::{ClaimsExtractor, auth_middleware};
 use axum::extract::*;
 use axum::middleware::from_fn_with_state;
 use domain::entities::commands::table_commands::*;
+use domain::entities::commands::table_question_commands::*;
 use domain::entities::commands::table_request_commands::*;
 use infrastructure::state::AppState;
 use shared::Result;
@@ -30,13 +31,22 @@ pub async fn create_table(
         return Err(Error::Validation(validation_error));
     }
 
-    let command = CreateTableCommand::new(
-        claims.get_user_id(),
-        payload.title,
-        payload.description,
-        payload.max_players as u32,
-        payload.system_id,
-    );
+    let command = CreateTableCommand {
+        tags: payload.tags,
+        language: payload.language,
+        format: payload.format.into(),
+        beginner_friendly: payload.beginner_friendly,
+        age_rating: payload.age_rating.into(),
+        location: payload.location.map(Into::into),
+        visibility: payload.visibility.into(),
+        ..CreateTableCommand::new(
+            claims.get_user_id(),
+            payload.title,
+            payload.description,
+            payload.max_players as u32,
+            payload.system_id,
+        )
+    };
 
     let table = app_state.table_service.create_table(command).await?;
 
@@ -50,21 +60,27 @@ pub async fn create_table(
     path = "/",
     summary = "Get list of tables",
     tag = "table",
+    params(SearchTablesQuery, PaginationQuery),
     security(("auth" = []))
 )]
 #[axum::debug_handler]
 pub async fn get_tables(
+    claims: ClaimsExtractor,
     State(app_state): State<Arc<AppState>>,
-) -> Result<Json<Vec<TableListItem>>> {
+    Query(query): Query<SearchTablesQuery>,
+    Query(page): Query<PaginationQuery>,
+) -> Result<Json<PaginatedResponse<TableListItem>>> {
+    let command = GetTableCommand {
+        page: Some(page.try_into()?),
+        ..query.try_into()?
+    };
+
     let tables = app_state
         .table_service
-        .get_all_tables()
-        .await?
-        .into_iter()
-        .map(|t| t.into())
-        .collect::<Vec<TableListItem>>();
+        .list_tables(claims.get_user_id(), command)
+        .await?;
 
-    Ok(Json(tables))
+    Ok(Json(tables.into()))
 }
 
 #[utoipa::path(
@@ -102,12 +118,33 @@ pub async fn update_table(
     State(app_state): State<Arc<AppState>>,
     Path(table_id): Path<Uuid>,
     Json(payload): Json<UpdateTableRequest>,
-) -> Result<Json<()>> {
+) -> Result<Json<TableListItem>> {
     if let Err(validation_error) = payload.validate() {
         return Err(Error::Validation(validation_error));
     }
 
-    todo!()
+    let command = UpdateTableCommand {
+        title: payload.title,
+        description: payload.description,
+        slots: payload.max_players.map(|slots| slots as u32),
+        game_system_id: payload.system_id,
+        status: payload.status.map(Into::into),
+        tags: payload.tags,
+        language: payload.language.map(Some),
+        format: payload.format.map(Into::into),
+        beginner_friendly: payload.beginner_friendly,
+        age_rating: payload.age_rating.map(Into::into),
+        location: payload.location.map(|location| location.map(Into::into)),
+        visibility: payload.visibility.map(Into::into),
+        ..UpdateTableCommand::new(table_id)
+    };
+
+    let table = app_state
+        .table_service
+        .update_table(claims.get_user_id(), command)
+        .await?;
+
+    Ok(Json(table.into()))
 }
 
 #[utoipa::path(
@@ -133,11 +170,33 @@ pub async fn delete_table(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/{table_id}/restore",
+    summary = "Restore a deleted Table within its grace period",
+    tag = "table",
+    security(("auth" = []))
+)]
+#[axum::debug_handler]
+pub async fn restore_table(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+) -> Result<Json<TableListItem>> {
+    let table = app_state
+        .table_service
+        .restore_table(claims.get_user_id(), table_id)
+        .await?;
+
+    Ok(Json(table.into()))
+}
+
 #[utoipa::path(
     get,
     path = "/{table_id}/sessions",
     tag = "session",
     summary = "Get a list of sessions of a specific table",
+    params(SessionListQuery, PaginationQuery),
     security(("auth" = []))
 )]
 #[axum::debug_handler]
@@ -145,18 +204,17 @@ pub async fn get_sessions(
     Path(table_id): Path<Uuid>,
     claims: ClaimsExtractor,
     State(app_state): State<Arc<AppState>>,
-) -> Result<Json<Vec<GetSessionsResponse>>> {
+    Query(query): Query<SessionListQuery>,
+    Query(page): Query<PaginationQuery>,
+) -> Result<Json<PaginatedResponse<GetSessionsResponse>>> {
     let user_id = claims.get_user_id();
 
     let sessions = app_state
         .session_service
-        .get_table_sessions(table_id, user_id)
-        .await?
-        .into_iter()
-        .map(GetSessionsResponse::from)
-        .collect();
+        .get_table_sessions(table_id, user_id, query.into(), page.try_into()?)
+        .await?;
 
-    Ok(Json(sessions))
+    Ok(Json(sessions.into()))
 }
 
 #[utoipa::path(
@@ -195,28 +253,30 @@ pub async fn create_session(
     get,
     path = "/{table_id}/requests",
     summary = "Get all the requests recived in a existing Table",
+    description = "Only the table's GM can list its requests.",
     tag = "table-request",
+    params(TableRequestListQuery, PaginationQuery),
     security(("auth" = []))
 )]
 #[axum::debug_handler]
 pub async fn get_received_requests(
+    claims: ClaimsExtractor,
     Path(table_id): Path<Uuid>,
     State(app_state): State<Arc<AppState>>,
-) -> Result<Json<Vec<ReceivedRequestItem>>> {
-    let requests = app_state.table_service.get_table_requests(table_id).await?;
+    Query(query): Query<TableRequestListQuery>,
+    Query(page): Query<PaginationQuery>,
+) -> Result<Json<PaginatedResponse<ReceivedRequestItem>>> {
+    let requests = app_state
+        .table_service
+        .get_table_requests(
+            claims.get_user_id(),
+            table_id,
+            query.into(),
+            page.try_into()?,
+        )
+        .await?;
 
-    let requests = requests
-        .into_iter()
-        .map(|request| ReceivedRequestItem {
-            id: request.id,
-            player_id: request.user_id,
-            table_id: request.table_id,
-            request_date: request.created_at,
-            message: request.message,
-        })
-        .collect::<Vec<ReceivedRequestItem>>();
-
-    Ok(Json(requests))
+    Ok(Json(requests.into()))
 }
 
 #[utoipa::path(
@@ -235,7 +295,16 @@ async fn create_request(
 ) -> Result<Json<CreateTableRequestResponse>> {
     let requester_id = claims.get_user_id();
 
-    let command = CreateTableRequestCommand::new(requester_id, table_id, payload.message);
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let command = CreateTableRequestCommand::new(
+        requester_id,
+        table_id,
+        payload.message,
+        payload.answers.into_iter().map(Into::into).collect(),
+    );
 
     let table_request = app_state.table_request_service.create(command).await?;
 
@@ -244,27 +313,115 @@ async fn create_request(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{table_id}/questions",
+    tag = "table-request",
+    security(("auth" = [])),
+    summary = "Get the application questionnaire of a table"
+)]
+#[axum::debug_handler]
+async fn get_questionnaire(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+) -> Result<Json<Vec<TableQuestionResponse>>> {
+    let questions = app_state
+        .table_service
+        .get_questionnaire(claims.get_user_id(), table_id)
+        .await?
+        .into_iter()
+        .map(TableQuestionResponse::from)
+        .collect();
+
+    Ok(Json(questions))
+}
+
+#[utoipa::path(
+    put,
+    path = "/{table_id}/questions",
+    tag = "table-request",
+    security(("auth" = [])),
+    summary = "Replace the application questionnaire of a table (GM only)"
+)]
+#[axum::debug_handler]
+async fn replace_questionnaire(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    Json(payload): Json<ReplaceQuestionnaireRequest>,
+) -> Result<Json<Vec<TableQuestionResponse>>> {
+    if let Err(validation_error) = payload.validate() {
+        return Err(Error::Validation(validation_error));
+    }
+
+    let command = ReplaceTableQuestionsCommand {
+        table_id,
+        questions: payload.questions.into_iter().map(Into::into).collect(),
+    };
+
+    let questions = app_state
+        .table_service
+        .replace_questionnaire(claims.get_user_id(), command)
+        .await?
+        .into_iter()
+        .map(TableQuestionResponse::from)
+        .collect();
+
+    Ok(Json(questions))
+}
+
 #[utoipa::path(get,
     path = "/{table_id}/members",
     tag = "table",
+    params(TableMemberListQuery, PaginationQuery),
     security(("auth" = [])),
     summary = "Get all members of a specific table"
 )]
 async fn get_table_members(
+    claims: ClaimsExtractor,
     State(app_state): State<Arc<AppState>>,
     Path(table_id): Path<Uuid>,
-) -> Result<Json<Vec<TableMemberResponse>>> {
+    Query(query): Query<TableMemberListQuery>,
+    Query(page): Query<PaginationQuery>,
+) -> Result<Json<PaginatedResponse<TableMemberResponse>>> {
     let members = app_state
         .table_member_service
-        .find_by_table_id(&table_id)
+        .list_by_table_id(
+            claims.get_user_id(),
+            table_id,
+            query.order.unwrap_or_default().into(),
+            page.try_into()?,
+        )
         .await?;
 
-    let members = members
+    Ok(Json(members.into()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{table_id}/audit",
+    tag = "table",
+    params(AuditLogQuery),
+    security(("auth" = [])),
+    summary = "Get the audit history of a table (GM only)"
+)]
+#[axum::debug_handler]
+pub async fn get_table_audit_log(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    Path(table_id): Path<Uuid>,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<Json<Vec<AuditLogResponse>>> {
+    let entries = app_state
+        .table_service
+        .get_table_audit_log(claims.get_user_id(), table_id, query.into())
+        .await?
         .into_iter()
-        .map(TableMemberResponse::from)
-        .collect::<Vec<TableMemberResponse>>();
+        .map(AuditLogResponse::from)
+        .collect();
 
-    Ok(Json(members))
+    Ok(Json(entries))
 }
 
 pub fn table_routes(state: Arc<AppState>) -> OpenApiRouter {
@@ -277,11 +434,14 @@ pub fn table_routes(state: Arc<AppState>) -> OpenApiRouter {
                 .routes(routes!(get_table_details))
                 .routes(routes!(update_table))
                 .routes(routes!(delete_table))
+                .routes(routes!(restore_table))
                 .routes(routes!(create_session))
                 .routes(routes!(create_request))
                 .routes(routes!(get_sessions))
                 .routes(routes!(get_received_requests))
                 .routes(routes!(get_table_members))
+                .routes(routes!(get_questionnaire, replace_questionnaire))
+                .routes(routes!(get_table_audit_log))
                 .layer(from_fn_with_state(state.clone(), auth_middleware)),
         )
         .with_state(state)
@@ -14,24 +14,22 @@ use uuid::Uuid;
     path = "/sent",
     tag = "table-request",
     summary = "Get sent requests",
+    params(TableRequestListQuery, PaginationQuery),
     security(("auth" = [])),
 )]
 #[axum::debug_handler]
 pub async fn get_sent_requests(
     claims: ClaimsExtractor,
     State(app_state): State<Arc<AppState>>,
-) -> Result<Json<Vec<SentRequestItem>>> {
+    Query(query): Query<TableRequestListQuery>,
+    Query(page): Query<PaginationQuery>,
+) -> Result<Json<PaginatedResponse<SentRequestItem>>> {
     let requests = app_state
         .table_request_service
-        .get_sent_requests(claims.get_user_id())
+        .get_sent_requests(claims.get_user_id(), query.into(), page.try_into()?)
         .await?;
 
-    let requests = requests
-        .into_iter()
-        .map(SentRequestItem::from)
-        .collect::<Vec<SentRequestItem>>();
-
-    Ok(Json(requests))
+    Ok(Json(requests.into()))
 }
 
 #[utoipa::path(
@@ -0,0 +1,143 @@
+use crate::http::dtos::*;
+use crate::http::middleware::auth::{ClaimsExtractor, query_token_auth_middleware};
+use application::user_event_service::UserEventService;
+use axum::extract::*;
+use axum::http::HeaderMap;
+use axum::middleware::from_fn_with_state;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use domain::entities::UserEvent;
+use futures::stream;
+use futures::{Stream, StreamExt};
+use infrastructure::state::AppState;
+use shared::Result;
+use shared::error::*;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::warn;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+use uuid::Uuid;
+
+const LAST_EVENT_ID: &str = "last-event-id";
+
+#[utoipa::path(
+    get,
+    path = "/events/stream",
+    tag = "events",
+    params(
+        ("Last-Event-ID" = Option<i64>, Header, description = "Resume after this event"),
+        ("access_token" = Option<String>, Query, description = "JWT, for clients that cannot send an Authorization header")
+    ),
+    security(("auth" = [])),
+    summary = "Stream the user's live updates as Server-Sent Events",
+    description = "Events are named after their kind (`session_scheduled`, `session_started`, \
+        `session_completed`, `intent_changed`, `request_accepted`) and carry a `UserEventResponse`. \
+        Without `Last-Event-ID` the stream starts with the next event."
+)]
+#[axum::debug_handler]
+pub async fn stream_user_events(
+    claims: ClaimsExtractor,
+    State(app_state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = std::result::Result<Event, Infallible>>>> {
+    let user_id = claims.get_user_id();
+    let user_event_service = app_state.user_event_service.clone();
+
+    // Subscribe before reading the log so nothing slips in between.
+    let wakeups = user_event_service.subscribe();
+
+    let last_event_id = match headers.get(LAST_EVENT_ID) {
+        Some(value) => value
+            .to_str()
+            .ok()
+            .and_then(|value| value.trim().parse::<i64>().ok())
+            .ok_or_else(|| {
+                Error::Application(ApplicationError::InvalidInput {
+                    message: "Last-Event-ID must be an event id".to_string(),
+                })
+            })?,
+        None => user_event_service.get_latest_event_id(user_id).await?,
+    };
+
+    let state = EventStream {
+        user_event_service,
+        user_id,
+        last_event_id,
+        pending: VecDeque::new(),
+        wakeups,
+    };
+
+    // Ending the stream on shutdown lets the server drain; clients resume
+    // from their `Last-Event-ID` on another instance.
+    let events =
+        stream::unfold(state, EventStream::next).take_until(app_state.shutdown.triggered());
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+struct EventStream {
+    user_event_service: UserEventService,
+    user_id: Uuid,
+    last_event_id: i64,
+    pending: VecDeque<UserEvent>,
+    wakeups: broadcast::Receiver<Uuid>,
+}
+
+impl EventStream {
+    /// Drains the log page by page, then waits until the user has news.
+    async fn next(mut self) -> Option<(std::result::Result<Event, Infallible>, Self)> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                self.last_event_id = event.id;
+                return Some((Ok(to_sse_event(event)), self));
+            }
+
+            match self
+                .user_event_service
+                .get_events_after(self.user_id, self.last_event_id)
+                .await
+            {
+                Ok(events) if !events.is_empty() => {
+                    self.pending.extend(events);
+                    continue;
+                }
+                Ok(_) => {}
+                Err(err) => warn!("Failed to read events of {}: {}", self.user_id, err),
+            }
+
+            loop {
+                match self.wakeups.recv().await {
+                    Ok(user_id) if user_id == self.user_id => break,
+                    Ok(_) => continue,
+                    // Missed wakeups may have been ours, so check the log.
+                    Err(RecvError::Lagged(_)) => break,
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        }
+    }
+}
+
+fn to_sse_event(event: UserEvent) -> Event {
+    let id = event.id.to_string();
+    let kind = event.kind.as_str();
+
+    Event::default()
+        .id(id)
+        .event(kind)
+        .json_data(UserEventResponse::from(event))
+        .unwrap_or_default()
+}
+
+pub fn user_event_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .routes(routes!(stream_user_events))
+        .layer(from_fn_with_state(
+            state.clone(),
+            query_token_auth_middleware,
+        ))
+        .with_state(state)
+}
@@ -1,6 +1,6 @@
 use axum::{
-    extract::{FromRequestParts, Request, State},
-    http::{StatusCode, request::Parts},
+    extract::{FromRequestParts, Query, Request, State},
+    http::request::Parts,
     middleware::Next,
     response::Response,
 };
@@ -10,7 +10,12 @@ use axum_extra::{
 };
 use domain::auth::{AuthenticationService, Claims};
 use infrastructure::state::AppState;
+use metrics::counter;
+use serde::Deserialize;
+use shared::error::{ApplicationError, Error};
+use shared::metrics::AUTH_FAILURES_TOTAL;
 use std::sync::Arc;
+use tracing::{Span, field};
 use uuid::Uuid;
 
 // Wrapper to implement FromRequestParts locally
@@ -26,7 +31,7 @@ impl<S> FromRequestParts<S> for ClaimsExtractor
 where
     S: Send + Sync,
 {
-    type Rejection = StatusCode;
+    type Rejection = Error;
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
         parts
@@ -34,7 +39,7 @@ where
             .get::<Claims>()
             .cloned()
             .map(ClaimsExtractor)
-            .ok_or(StatusCode::UNAUTHORIZED)
+            .ok_or(Error::Application(ApplicationError::Unauthenticated))
     }
 }
 
@@ -43,25 +48,77 @@ pub async fn auth_middleware(
     auth_header: Option<TypedHeader<Authorization<Bearer>>>,
     mut request: Request,
     next: Next,
-) -> Result<Response, StatusCode> {
+) -> Result<Response, Error> {
     let token = match auth_header {
         Some(TypedHeader(auth)) => auth.token().to_owned(),
-        None => return Err(StatusCode::UNAUTHORIZED),
+        None => return Err(unauthenticated("missing_token")),
     };
 
+    let token_data = authenticate(&app_state, &token).await?;
+    Span::current().record("user_id", field::display(token_data.sub));
+    request.extensions_mut().insert(token_data);
+
+    Ok(next.run(request).await)
+}
+
+#[derive(Deserialize)]
+pub struct AccessTokenQuery {
+    pub access_token: Option<String>,
+}
+
+/// Like [`auth_middleware`], but also accepts the token as an `access_token`
+/// query parameter, because browsers cannot set headers on WebSocket
+/// handshakes or `EventSource` requests.
+pub async fn query_token_auth_middleware(
+    State(app_state): State<Arc<AppState>>,
+    auth_header: Option<TypedHeader<Authorization<Bearer>>>,
+    Query(query): Query<AccessTokenQuery>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, Error> {
+    let token = match (auth_header, query.access_token) {
+        (Some(TypedHeader(auth)), _) => auth.token().to_owned(),
+        (None, Some(token)) => token,
+        (None, None) => return Err(unauthenticated("missing_token")),
+    };
+
+    let token_data = authenticate(&app_state, &token).await?;
+    Span::current().record("user_id", field::display(token_data.sub));
+    request.extensions_mut().insert(token_data);
+
+    Ok(next.run(request).await)
+}
+
+/// Counts a rejected token before turning it into a 401.
+fn unauthenticated(reason: &'static str) -> Error {
+    counter!(AUTH_FAILURES_TOTAL, "reason" => reason).increment(1);
+    Error::Application(ApplicationError::Unauthenticated)
+}
+
+async fn authenticate(app_state: &AppState, token: &str) -> Result<Claims, Error> {
     let token_data = app_state
         .auth_service
-        .validate_token(&token)
+        .validate_token(token)
         .await
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+        .map_err(|_| unauthenticated("invalid_token"))?;
 
     let now = chrono::Utc::now().timestamp();
 
     if token_data.exp < now {
-        return Err(StatusCode::UNAUTHORIZED);
+        return Err(unauthenticated("expired_token"));
     }
 
-    request.extensions_mut().insert(token_data);
+    let user = app_state
+        .user_service
+        .find_by_id(token_data.sub)
+        .await
+        .map_err(|_| Error::InternalServerError)?
+        .ok_or_else(|| unauthenticated("unknown_user"))?;
 
-    Ok(next.run(request).await)
+    if user.is_suspended() {
+        counter!(AUTH_FAILURES_TOTAL, "reason" => "account_suspended").increment(1);
+        return Err(Error::Application(ApplicationError::AccountSuspended));
+    }
+
+    Ok(token_data)
 }
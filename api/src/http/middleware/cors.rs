@@ -1,19 +1,47 @@
-use axum::http::Method;
-use tower_http::cors::{Any, CorsLayer};
+use axum::http::{HeaderName, HeaderValue, Method};
+use infrastructure::config::CorsConfig;
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, Any, CorsLayer};
+
+/// Builds the layer from a policy already checked by [`CorsConfig::validate`],
+/// so entries that do not parse cannot occur and are skipped.
+pub fn cors_layer(config: &CorsConfig) -> CorsLayer {
+    let origins: AllowOrigin = if config.allows_any_origin() {
+        Any.into()
+    } else {
+        config
+            .allowed_origins
+            .iter()
+            .filter_map(|origin| HeaderValue::from_str(origin).ok())
+            .collect::<Vec<_>>()
+            .into()
+    };
+
+    let methods: AllowMethods = if config.allows_any_method() {
+        Any.into()
+    } else {
+        config
+            .allowed_methods
+            .iter()
+            .filter_map(|method| method.parse::<Method>().ok())
+            .collect::<Vec<_>>()
+            .into()
+    };
+
+    let headers: AllowHeaders = if config.allows_any_header() {
+        Any.into()
+    } else {
+        config
+            .allowed_headers
+            .iter()
+            .filter_map(|header| header.parse::<HeaderName>().ok())
+            .collect::<Vec<_>>()
+            .into()
+    };
 
-pub fn cors_layer() -> CorsLayer {
     CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods([
-            Method::GET,
-            Method::POST,
-            Method::PUT,
-            Method::DELETE,
-            Method::OPTIONS,
-        ])
-        .allow_headers([
-            axum::http::header::AUTHORIZATION,
-            axum::http::header::CONTENT_TYPE,
-            axum::http::header::ACCEPT,
-        ])
+        .allow_origin(origins)
+        .allow_methods(methods)
+        .allow_headers(headers)
+        .allow_credentials(config.allow_credentials)
+        .max_age(config.max_age)
 }
@@ -1,3 +1,5 @@
 pub mod auth;
 pub mod cors;
+pub mod problem;
+pub mod request_id;
 pub mod tracing;
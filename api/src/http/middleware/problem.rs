@@ -0,0 +1,62 @@
+use crate::http::middleware::request_id::RequestId;
+use axum::{
+    body::to_bytes,
+    extract::Request,
+    http::{HeaderMap, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use shared::error::ProblemDetails;
+
+/// Framework rejections are short plain-text messages; anything longer is
+/// not worth echoing back.
+const MAX_REJECTION_BODY_BYTES: usize = 4096;
+
+/// Turns every error response into `application/problem+json` carrying the
+/// request path and id. Errors raised by handlers already are problems and
+/// only get enriched; rejections from extractors and routing are converted.
+/// Plain JSON bodies are left alone.
+pub async fn problem_details_middleware(request: Request, next: Next) -> Response {
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|request_id| request_id.as_str().to_owned());
+    let instance = request.uri().path().to_owned();
+
+    let response = next.run(request).await;
+    if !response.status().is_client_error() && !response.status().is_server_error() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let mut problem = match parts.extensions.remove::<ProblemDetails>() {
+        Some(problem) => problem,
+        // A JSON body was built on purpose by the handler, such as the
+        // readiness report, and is returned untouched.
+        None if is_json(&parts.headers) => return Response::from_parts(parts, body),
+        None => {
+            let bytes = to_bytes(body, MAX_REJECTION_BODY_BYTES)
+                .await
+                .unwrap_or_default();
+            ProblemDetails::from_status(parts.status, String::from_utf8_lossy(&bytes).trim())
+        }
+    };
+    problem.instance = Some(instance);
+    problem.request_id = request_id;
+
+    // Keep headers such as `Allow` or `WWW-Authenticate` from the original.
+    parts.headers.remove(header::CONTENT_TYPE);
+    parts.headers.remove(header::CONTENT_LENGTH);
+
+    let (problem_parts, problem_body) = problem.into_response().into_parts();
+    parts.headers.extend(problem_parts.headers);
+
+    Response::from_parts(parts, problem_body)
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/json"))
+}
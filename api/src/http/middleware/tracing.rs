@@ -1,9 +1,136 @@
-use axum::{extract::Request, middleware::Next, response::Response};
-use tracing::debug;
+use crate::http::middleware::request_id::RequestId;
+use axum::{
+    extract::{MatchedPath, Request},
+    http::HeaderMap,
+    middleware::Next,
+    response::Response,
+};
+use metrics::{Gauge, counter, gauge, histogram};
+use opentelemetry::{global, propagation::Extractor, trace::TraceContextExt};
+use shared::metrics::{
+    HTTP_REQUEST_DURATION_SECONDS, HTTP_REQUESTS_IN_FLIGHT, HTTP_REQUESTS_TOTAL, UNMATCHED_ROUTE,
+};
+use std::time::Instant;
+use tracing::{Instrument, Span, error, field, info, info_span, warn};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
+/// Wraps each request in an `http_request` span, writes one access log line
+/// when it completes and records the HTTP request metrics. The route template
+/// and user id are filled in by [`record_route`] and the auth middleware once
+/// they are known. A W3C `traceparent` header makes the span a child of the
+/// caller's trace.
 pub async fn trace_middleware(request: Request, next: Next) -> Response {
-    debug!("Processing request: {} {}", request.method(), request.uri());
-    let response = next.run(request).await;
-    debug!("Request completed with status: {}", response.status());
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map(|request_id| request_id.as_str().to_owned())
+        .unwrap_or_default();
+
+    let span = info_span!(
+        "http_request",
+        request_id = %request_id,
+        method = %request.method(),
+        path = %request.uri().path(),
+        route = field::Empty,
+        user_id = field::Empty,
+        otel.kind = "server",
+        otel.status_code = field::Empty,
+    );
+
+    let parent = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    // Only fails when no OpenTelemetry layer is installed.
+    let _ = span.set_parent(parent);
+
+    let method = request.method().to_string();
+    let in_flight = InFlightGuard::new();
+
+    let started_at = Instant::now();
+    let response = next.run(request).instrument(span.clone()).await;
+    let elapsed = started_at.elapsed();
+    let latency_ms = elapsed.as_secs_f64() * 1000.0;
+    let status = response.status().as_u16();
+
+    drop(in_flight);
+
+    let route = response
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(UNMATCHED_ROUTE, MatchedPath::as_str)
+        .to_owned();
+    let labels = [
+        ("method", method),
+        ("route", route),
+        ("status", status.to_string()),
+    ];
+    counter!(HTTP_REQUESTS_TOTAL, &labels).increment(1);
+    histogram!(HTTP_REQUEST_DURATION_SECONDS, &labels).record(elapsed);
+
+    span.in_scope(|| {
+        if response.status().is_server_error() {
+            Span::current().record("otel.status_code", "ERROR");
+            error!(status, latency_ms, "request failed");
+        } else if response.status().is_client_error() {
+            warn!(status, latency_ms, "request rejected");
+        } else {
+            info!(status, latency_ms, "request completed");
+        }
+    });
+
+    response
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+/// Keeps `http_requests_in_flight` accurate even when a request future is
+/// dropped because the client went away.
+struct InFlightGuard(Gauge);
+
+impl InFlightGuard {
+    fn new() -> Self {
+        let gauge = gauge!(HTTP_REQUESTS_IN_FLIGHT);
+        gauge.increment(1);
+        Self(gauge)
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.decrement(1);
+    }
+}
+
+/// Records the matched route template (e.g. `/v1/tables/{id}`) on the
+/// request span and hands it back on the response for the metric labels.
+/// Must be installed with `route_layer`, after routing.
+pub async fn record_route(request: Request, next: Next) -> Response {
+    let matched_path = request.extensions().get::<MatchedPath>().cloned();
+
+    if let Some(path) = &matched_path {
+        let span = Span::current();
+        span.record("route", path.as_str());
+        // The exported span has already started, so it is renamed directly.
+        span.context()
+            .span()
+            .update_name(format!("{} {}", request.method(), path.as_str()));
+    }
+
+    let mut response = next.run(request).await;
+
+    if let Some(path) = matched_path {
+        response.extensions_mut().insert(path);
+    }
+
     response
 }
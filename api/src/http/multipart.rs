@@ -0,0 +1,46 @@
+use axum::extract::Multipart;
+use shared::Result;
+use shared::error::{ApplicationError, Error};
+
+/// Leaves room for the multipart boundaries and headers around the file.
+pub const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
+/// The `file` part of a multipart upload, with what the client claimed
+/// about it.
+pub struct UploadedFile {
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+/// Reads the `file` part of a multipart upload, skipping any other fields.
+pub async fn read_file_part(mut multipart: Multipart) -> Result<UploadedFile> {
+    let invalid = |message: &str| {
+        Error::Application(ApplicationError::InvalidInput {
+            message: message.to_string(),
+        })
+    };
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|err| invalid(&err.body_text()))?
+    {
+        if field.name() == Some("file") {
+            let file_name = field.file_name().map(str::to_string);
+            let content_type = field.content_type().map(str::to_string);
+            let bytes = field
+                .bytes()
+                .await
+                .map_err(|err| invalid(&err.body_text()))?;
+
+            return Ok(UploadedFile {
+                file_name,
+                content_type,
+                bytes: bytes.to_vec(),
+            });
+        }
+    }
+
+    Err(invalid("Missing 'file' field in multipart body"))
+}
@@ -1,6 +1,9 @@
+use shared::error::{FieldError, PROBLEM_JSON_CONTENT_TYPE, ProblemDetails};
 use utoipa::Modify;
-use utoipa::openapi::OpenApi;
+use utoipa::openapi::content::ContentBuilder;
+use utoipa::openapi::response::ResponseBuilder;
 use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::{OpenApi, Ref};
 
 #[derive(utoipa::ToSchema)]
 pub struct SecurityAddon;
@@ -15,9 +18,47 @@ impl Modify for SecurityAddon {
     }
 }
 
+/// Documents the problem details body as the default response of every
+/// operation, since any of them can fail that way. Runs on the finished
+/// document, as routes are only added after `ApiDoc::openapi()`.
+pub struct ProblemDetailsAddon;
+
+impl Modify for ProblemDetailsAddon {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let problem = ResponseBuilder::new()
+            .description("Error described as RFC 7807 problem details")
+            .content(
+                PROBLEM_JSON_CONTENT_TYPE,
+                ContentBuilder::new()
+                    .schema(Some(Ref::from_schema_name("ProblemDetails")))
+                    .build(),
+            )
+            .build();
+
+        for path in openapi.paths.paths.values_mut() {
+            let operations = [
+                &mut path.get,
+                &mut path.put,
+                &mut path.post,
+                &mut path.delete,
+                &mut path.patch,
+            ];
+
+            for operation in operations.into_iter().flatten() {
+                operation
+                    .responses
+                    .responses
+                    .entry("default".to_string())
+                    .or_insert_with(|| problem.clone().into());
+            }
+        }
+    }
+}
+
 #[derive(utoipa::OpenApi)]
 #[openapi(
     modifiers(&SecurityAddon),
+    components(schemas(ProblemDetails, FieldError)),
     info(
         title = "JOS",
         description = "Join Our Session (JOS) - API for managing RPG tables and sessions",
@@ -3,5 +3,6 @@ pub mod http {
     pub mod error;
     pub mod handlers;
     pub mod middleware;
+    pub mod multipart;
     pub mod open_api;
 }
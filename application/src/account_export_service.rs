@@ -0,0 +1,208 @@
+use chrono::{Duration, Utc};
+use domain::entities::*;
+use domain::repositories::{
+    AccountExportRepository, SessionCheckinRepository, SessionIntentRepository,
+    TableMemberRepository, TableRepository, TableRequestRepository, UserRepository,
+};
+use shared::Result;
+use shared::error::{DomainError, Error};
+use std::sync::Arc;
+use tokio::sync::Notify;
+use tracing::{error, instrument};
+use uuid::Uuid;
+
+/// Accounts with more personal records than this are exported in the
+/// background instead of inline with the request.
+pub const SYNC_EXPORT_RECORD_LIMIT: i64 = 500;
+
+/// A claimed export not completed within this window is assumed abandoned
+/// and handed to the next worker.
+const EXPORT_CLAIM_TIMEOUT_MINUTES: i64 = 15;
+
+pub enum AccountExportOutcome {
+    Ready(Box<PersonalDataArchive>),
+    Pending(AccountExport),
+}
+
+#[derive(Clone)]
+pub struct AccountExportService {
+    user_repository: Arc<dyn UserRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    table_member_repository: Arc<dyn TableMemberRepository>,
+    table_request_repository: Arc<dyn TableRequestRepository>,
+    session_intent_repository: Arc<dyn SessionIntentRepository>,
+    session_checkin_repository: Arc<dyn SessionCheckinRepository>,
+    account_export_repository: Arc<dyn AccountExportRepository>,
+    work_queued: Arc<Notify>,
+}
+
+impl AccountExportService {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        table_member_repository: Arc<dyn TableMemberRepository>,
+        table_request_repository: Arc<dyn TableRequestRepository>,
+        session_intent_repository: Arc<dyn SessionIntentRepository>,
+        session_checkin_repository: Arc<dyn SessionCheckinRepository>,
+        account_export_repository: Arc<dyn AccountExportRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            table_repository,
+            table_member_repository,
+            table_request_repository,
+            session_intent_repository,
+            session_checkin_repository,
+            account_export_repository,
+            work_queued: Arc::new(Notify::new()),
+        }
+    }
+
+    #[instrument(
+        name = "AccountExportService::request_export",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn request_export(&self, user_id: Uuid) -> Result<AccountExportOutcome> {
+        let records = self
+            .account_export_repository
+            .count_personal_records(user_id)
+            .await?;
+
+        if records <= SYNC_EXPORT_RECORD_LIMIT {
+            let archive = self.build_archive(user_id).await?;
+            return Ok(AccountExportOutcome::Ready(Box::new(archive)));
+        }
+
+        let export = self
+            .account_export_repository
+            .find_or_create_pending(CreateAccountExportCommand::new(user_id))
+            .await?;
+        self.work_queued.notify_one();
+
+        Ok(AccountExportOutcome::Pending(export))
+    }
+
+    /// Returns an export job owned by `user_id`. Jobs belonging to other
+    /// users are reported as missing.
+    #[instrument(name = "AccountExportService::find_export", level = "debug", skip_all)]
+    pub async fn find_export(&self, user_id: Uuid, export_id: Uuid) -> Result<AccountExport> {
+        self.account_export_repository
+            .find_by_id(export_id)
+            .await?
+            .filter(|export| export.user_id == user_id)
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "AccountExport",
+                    entity_id: export_id.to_string(),
+                })
+            })
+    }
+
+    #[instrument(
+        name = "AccountExportService::build_archive",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn build_archive(&self, user_id: Uuid) -> Result<PersonalDataArchive> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "User",
+                    entity_id: user_id.to_string(),
+                })
+            })?;
+
+        let tables = self.table_repository.find_by_user_id(user_id).await?;
+        let memberships = self
+            .table_member_repository
+            .read(GetTableMemberCommand {
+                user_id: Some(user_id),
+                ..Default::default()
+            })
+            .await?;
+        let table_requests = self
+            .table_request_repository
+            .find_by_user_id(user_id)
+            .await?;
+        let session_intents = self
+            .session_intent_repository
+            .find_by_user_id(user_id)
+            .await?;
+        let session_checkins = self
+            .session_checkin_repository
+            .find_by_user_id(user_id)
+            .await?;
+
+        Ok(PersonalDataArchive {
+            generated_at: Utc::now(),
+            profile: user.into(),
+            tables,
+            memberships,
+            table_requests,
+            session_intents,
+            session_checkins,
+        })
+    }
+
+    /// Resolves when an export has been queued since the last call.
+    pub async fn work_queued(&self) {
+        self.work_queued.notified().await;
+    }
+
+    /// Claims and builds the oldest pending export, if any. Returns `None`
+    /// when the queue is empty or `cancelled` resolved first, in which case
+    /// the export is released so the next worker starts it right away.
+    #[instrument(name = "AccountExportService::run_next", level = "debug", skip_all)]
+    pub async fn run_next(
+        &self,
+        cancelled: impl Future<Output = ()>,
+    ) -> Result<Option<AccountExport>> {
+        let stale_before = Utc::now() - Duration::minutes(EXPORT_CLAIM_TIMEOUT_MINUTES);
+
+        let Some(export) = self
+            .account_export_repository
+            .claim_next(stale_before)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        tokio::select! {
+            result = self.run_export(export.id, export.user_id) => result.map(Some),
+            _ = cancelled => {
+                self.account_export_repository.release_claim(export.id).await?;
+                Ok(None)
+            }
+        }
+    }
+
+    async fn run_export(&self, export_id: Uuid, user_id: Uuid) -> Result<AccountExport> {
+        let payload = self.build_archive(user_id).await.and_then(|archive| {
+            serde_json::to_value(&archive).map_err(|err| {
+                error!("Failed to serialize account export {}: {}", export_id, err);
+                Error::InternalServerError
+            })
+        });
+
+        let command = match payload {
+            Ok(payload) => CompleteAccountExportCommand {
+                id: export_id,
+                status: ExportStatus::Ready,
+                payload: Some(payload),
+                error: None,
+            },
+            Err(err) => CompleteAccountExportCommand {
+                id: export_id,
+                status: ExportStatus::Failed,
+                payload: None,
+                error: Some(err.to_string()),
+            },
+        };
+
+        self.account_export_repository.complete(command).await
+    }
+}
@@ -0,0 +1,243 @@
+use chrono::{SubsecRound, Utc};
+use domain::entities::*;
+use domain::repositories::{
+    AuditLogRepository, GameSystemRepository, Page, PaginationParams, TableRepository,
+    UserRepository,
+};
+use serde_json::json;
+use shared::Result;
+use shared::error::{ApplicationError, DomainError, Error};
+use std::sync::Arc;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct AdminService {
+    user_repository: Arc<dyn UserRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    game_system_repository: Arc<dyn GameSystemRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+impl AdminService {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        game_system_repository: Arc<dyn GameSystemRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            table_repository,
+            game_system_repository,
+            audit_log_repository,
+        }
+    }
+
+    #[instrument(name = "AdminService::ensure_admin", level = "debug", skip_all)]
+    pub async fn ensure_admin(&self, actor_id: Uuid) -> Result<User> {
+        let actor = self
+            .user_repository
+            .find_by_id(actor_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "User",
+                    entity_id: actor_id.to_string(),
+                })
+            })?;
+
+        if !actor.is_admin() || actor.is_suspended() {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        Ok(actor)
+    }
+
+    /// Users are listed newest first, so any page can be resumed with a
+    /// cursor.
+    #[instrument(name = "AdminService::list_users", level = "debug", skip_all)]
+    pub async fn list_users(
+        &self,
+        actor_id: Uuid,
+        mut command: GetUserCommand,
+        page: PaginationParams,
+    ) -> Result<Page<User>> {
+        self.ensure_admin(actor_id).await?;
+
+        command.page = Some(page.clone());
+
+        let total = self.user_repository.count(command.clone()).await?;
+        let users = self.user_repository.read(command).await?;
+
+        Ok(Page::new(users, total, &page, true, |user| user.id))
+    }
+
+    #[instrument(name = "AdminService::suspend_user", level = "debug", skip_all)]
+    pub async fn suspend_user(&self, actor_id: Uuid, user_id: Uuid) -> Result<User> {
+        self.ensure_admin(actor_id).await?;
+
+        if actor_id == user_id {
+            return Err(Error::Domain(DomainError::BusinessRuleViolation {
+                message: "Administrators cannot suspend their own account".to_string(),
+            }));
+        }
+
+        let user = self.find_user(user_id).await?;
+
+        if user.is_suspended() {
+            return Ok(user);
+        }
+
+        let suspended_at = Utc::now().trunc_subsecs(6);
+        let audit = CreateAuditLogCommand::new(
+            Some(actor_id),
+            AuditAction::UserSuspended,
+            AuditEntityType::User,
+            user_id,
+        )
+        .with_before(&json!({ "suspended_at": user.suspended_at }))
+        .with_after(&json!({ "suspended_at": suspended_at }));
+
+        let suspended = self
+            .user_repository
+            .set_suspended_at(user_id, Some(suspended_at), audit)
+            .await?;
+
+        Ok(suspended)
+    }
+
+    #[instrument(name = "AdminService::unsuspend_user", level = "debug", skip_all)]
+    pub async fn unsuspend_user(&self, actor_id: Uuid, user_id: Uuid) -> Result<User> {
+        self.ensure_admin(actor_id).await?;
+        let user = self.find_user(user_id).await?;
+
+        let audit = CreateAuditLogCommand::new(
+            Some(actor_id),
+            AuditAction::UserUnsuspended,
+            AuditEntityType::User,
+            user_id,
+        )
+        .with_before(&json!({ "suspended_at": user.suspended_at }))
+        .with_after(&json!({ "suspended_at": null }));
+
+        let unsuspended = self
+            .user_repository
+            .set_suspended_at(user_id, None, audit)
+            .await?;
+
+        Ok(unsuspended)
+    }
+
+    #[instrument(name = "AdminService::update_user_role", level = "debug", skip_all)]
+    pub async fn update_user_role(
+        &self,
+        actor_id: Uuid,
+        user_id: Uuid,
+        role: UserRole,
+    ) -> Result<User> {
+        self.ensure_admin(actor_id).await?;
+
+        if actor_id == user_id && role != UserRole::Admin {
+            return Err(Error::Domain(DomainError::BusinessRuleViolation {
+                message: "Administrators cannot revoke their own role".to_string(),
+            }));
+        }
+
+        let user = self.find_user(user_id).await?;
+
+        let audit = CreateAuditLogCommand::new(
+            Some(actor_id),
+            AuditAction::UserRoleChanged,
+            AuditEntityType::User,
+            user_id,
+        )
+        .with_before(&json!({ "role": user.role }))
+        .with_after(&json!({ "role": role }));
+
+        let updated = self.user_repository.set_role(user_id, role, audit).await?;
+
+        Ok(updated)
+    }
+
+    #[instrument(name = "AdminService::force_delete_table", level = "debug", skip_all)]
+    pub async fn force_delete_table(&self, actor_id: Uuid, table_id: Uuid) -> Result<Table> {
+        self.ensure_admin(actor_id).await?;
+
+        let table = self
+            .table_repository
+            .find_by_id(table_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "Table",
+                    entity_id: table_id.to_string(),
+                })
+            })?;
+
+        let audit = CreateAuditLogCommand::new(
+            Some(actor_id),
+            AuditAction::TableDeleted,
+            AuditEntityType::Table,
+            table.id,
+        )
+        .with_table(table.id)
+        .with_before(&table);
+
+        let deleted = self.table_repository.soft_delete(table.id, audit).await?;
+
+        Ok(deleted)
+    }
+
+    #[instrument(name = "AdminService::list_audit_logs", level = "debug", skip_all)]
+    pub async fn list_audit_logs(
+        &self,
+        actor_id: Uuid,
+        command: GetAuditLogCommand,
+    ) -> Result<Vec<AuditLog>> {
+        self.ensure_admin(actor_id).await?;
+        self.audit_log_repository.read(command).await
+    }
+
+    #[instrument(name = "AdminService::create_game_system", level = "debug", skip_all)]
+    pub async fn create_game_system(
+        &self,
+        actor_id: Uuid,
+        command: CreateGameSystemCommand,
+    ) -> Result<GameSystem> {
+        self.ensure_admin(actor_id).await?;
+        self.game_system_repository.create(command).await
+    }
+
+    #[instrument(name = "AdminService::update_game_system", level = "debug", skip_all)]
+    pub async fn update_game_system(
+        &self,
+        actor_id: Uuid,
+        command: UpdateGameSystemCommand,
+    ) -> Result<GameSystem> {
+        self.ensure_admin(actor_id).await?;
+        self.game_system_repository.update(command).await
+    }
+
+    #[instrument(name = "AdminService::delete_game_system", level = "debug", skip_all)]
+    pub async fn delete_game_system(
+        &self,
+        actor_id: Uuid,
+        command: DeleteGameSystemCommand,
+    ) -> Result<GameSystem> {
+        self.ensure_admin(actor_id).await?;
+        self.game_system_repository.delete(command).await
+    }
+
+    async fn find_user(&self, user_id: Uuid) -> Result<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "User",
+                    entity_id: user_id.to_string(),
+                })
+            })
+    }
+}
@@ -0,0 +1,297 @@
+use crate::table_access::{ensure_table_member, find_visible_table};
+use domain::entities::*;
+use domain::repositories::{AnnouncementRepository, TableMemberRepository, TableRepository};
+use shared::Result;
+use shared::error::{ApplicationError, DomainError, Error};
+use std::sync::Arc;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub const MAX_ANNOUNCEMENT_TITLE_LEN: usize = 200;
+pub const MAX_ANNOUNCEMENT_BODY_LEN: usize = 5_000;
+pub const MAX_ANNOUNCEMENT_COMMENT_LEN: usize = 2_000;
+
+/// The announcements board of a table. The GM posts, pins and edits
+/// announcements; members can read them, comment and mark them read.
+#[derive(Clone)]
+pub struct AnnouncementService {
+    announcement_repository: Arc<dyn AnnouncementRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    table_member_repository: Arc<dyn TableMemberRepository>,
+}
+
+impl AnnouncementService {
+    pub fn new(
+        announcement_repository: Arc<dyn AnnouncementRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        table_member_repository: Arc<dyn TableMemberRepository>,
+    ) -> Self {
+        Self {
+            announcement_repository,
+            table_repository,
+            table_member_repository,
+        }
+    }
+
+    #[instrument(
+        name = "AnnouncementService::create_announcement",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn create_announcement(
+        &self,
+        gm_id: Uuid,
+        mut command: CreateAnnouncementCommand,
+    ) -> Result<Announcement> {
+        let table = self.find_table(gm_id, command.table_id).await?;
+        ensure_gm(gm_id, &table)?;
+
+        command.author_id = gm_id;
+        command.title = normalize_title(command.title)?;
+        validate_body(&command.body)?;
+
+        self.announcement_repository.create(command).await
+    }
+
+    #[instrument(
+        name = "AnnouncementService::list_announcements",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn list_announcements(
+        &self,
+        user_id: Uuid,
+        table_id: Uuid,
+    ) -> Result<Vec<AnnouncementListing>> {
+        let table = self.find_table(user_id, table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        self.announcement_repository
+            .find_by_table_id(table_id, user_id)
+            .await
+    }
+
+    #[instrument(
+        name = "AnnouncementService::get_announcement",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn get_announcement(
+        &self,
+        user_id: Uuid,
+        announcement_id: Uuid,
+    ) -> Result<Announcement> {
+        let announcement = self.find_announcement(announcement_id).await?;
+        let table = self.find_table(user_id, announcement.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        Ok(announcement)
+    }
+
+    #[instrument(
+        name = "AnnouncementService::update_announcement",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn update_announcement(
+        &self,
+        gm_id: Uuid,
+        mut command: UpdateAnnouncementCommand,
+    ) -> Result<Announcement> {
+        let announcement = self.find_announcement(command.id).await?;
+        let table = self.find_table(gm_id, announcement.table_id).await?;
+        ensure_gm(gm_id, &table)?;
+
+        command.title = command.title.map(normalize_title).transpose()?;
+        if let Some(body) = &command.body {
+            validate_body(body)?;
+        }
+
+        self.announcement_repository
+            .update(command)
+            .await?
+            .ok_or_else(|| announcement_not_found(announcement.id))
+    }
+
+    #[instrument(
+        name = "AnnouncementService::delete_announcement",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn delete_announcement(&self, gm_id: Uuid, announcement_id: Uuid) -> Result<()> {
+        let announcement = self.find_announcement(announcement_id).await?;
+        let table = self.find_table(gm_id, announcement.table_id).await?;
+        ensure_gm(gm_id, &table)?;
+
+        self.announcement_repository.delete(announcement_id).await
+    }
+
+    #[instrument(name = "AnnouncementService::mark_read", level = "debug", skip_all)]
+    pub async fn mark_read(&self, user_id: Uuid, announcement_id: Uuid) -> Result<()> {
+        let announcement = self.get_announcement(user_id, announcement_id).await?;
+
+        self.announcement_repository
+            .mark_read(announcement.id, user_id)
+            .await
+    }
+
+    /// Only the GM sees who has read an announcement.
+    #[instrument(
+        name = "AnnouncementService::get_read_receipts",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn get_read_receipts(
+        &self,
+        gm_id: Uuid,
+        announcement_id: Uuid,
+    ) -> Result<Vec<AnnouncementReadReceipt>> {
+        let announcement = self.find_announcement(announcement_id).await?;
+        let table = self.find_table(gm_id, announcement.table_id).await?;
+        ensure_gm(gm_id, &table)?;
+
+        self.announcement_repository
+            .find_read_receipts(announcement_id)
+            .await
+    }
+
+    #[instrument(name = "AnnouncementService::add_comment", level = "debug", skip_all)]
+    pub async fn add_comment(
+        &self,
+        user_id: Uuid,
+        mut command: CreateAnnouncementCommentCommand,
+    ) -> Result<AnnouncementComment> {
+        let announcement = self
+            .get_announcement(user_id, command.announcement_id)
+            .await?;
+
+        command.author_id = user_id;
+        command.body = normalize_comment(command.body)?;
+        command.announcement_id = announcement.id;
+
+        self.announcement_repository.create_comment(command).await
+    }
+
+    #[instrument(name = "AnnouncementService::list_comments", level = "debug", skip_all)]
+    pub async fn list_comments(
+        &self,
+        user_id: Uuid,
+        announcement_id: Uuid,
+    ) -> Result<Vec<AnnouncementComment>> {
+        let announcement = self.get_announcement(user_id, announcement_id).await?;
+
+        self.announcement_repository
+            .find_comments(announcement.id)
+            .await
+    }
+
+    /// Comments can be removed by their author or the GM.
+    #[instrument(
+        name = "AnnouncementService::delete_comment",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn delete_comment(
+        &self,
+        user_id: Uuid,
+        announcement_id: Uuid,
+        comment_id: Uuid,
+    ) -> Result<()> {
+        let announcement = self.find_announcement(announcement_id).await?;
+        let table = self.find_table(user_id, announcement.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        let comment = self
+            .announcement_repository
+            .find_comment_by_id(comment_id)
+            .await?
+            .filter(|comment| comment.announcement_id == announcement.id)
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "AnnouncementComment",
+                    entity_id: comment_id.to_string(),
+                })
+            })?;
+
+        if comment.author_id != user_id && table.gm_id != user_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        self.announcement_repository
+            .delete_comment(comment.id)
+            .await
+    }
+
+    async fn find_announcement(&self, announcement_id: Uuid) -> Result<Announcement> {
+        self.announcement_repository
+            .find_by_id(announcement_id)
+            .await?
+            .ok_or_else(|| announcement_not_found(announcement_id))
+    }
+
+    async fn find_table(&self, user_id: Uuid, table_id: Uuid) -> Result<Table> {
+        find_visible_table(self.table_repository.as_ref(), user_id, table_id).await
+    }
+
+    async fn ensure_member(&self, user_id: Uuid, table: &Table) -> Result<()> {
+        ensure_table_member(self.table_member_repository.as_ref(), user_id, table).await
+    }
+}
+
+fn ensure_gm(user_id: Uuid, table: &Table) -> Result<()> {
+    if table.gm_id != user_id {
+        return Err(Error::Application(ApplicationError::Forbidden));
+    }
+
+    Ok(())
+}
+
+fn announcement_not_found(announcement_id: Uuid) -> Error {
+    Error::Domain(DomainError::EntityNotFound {
+        entity_type: "Announcement",
+        entity_id: announcement_id.to_string(),
+    })
+}
+
+fn normalize_title(title: String) -> Result<String> {
+    let title = title.trim().to_string();
+
+    if title.is_empty() || title.chars().count() > MAX_ANNOUNCEMENT_TITLE_LEN {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!(
+                "Announcement titles must be between 1 and {} characters",
+                MAX_ANNOUNCEMENT_TITLE_LEN
+            ),
+        }));
+    }
+
+    Ok(title)
+}
+
+fn validate_body(body: &str) -> Result<()> {
+    if body.trim().is_empty() || body.chars().count() > MAX_ANNOUNCEMENT_BODY_LEN {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!(
+                "Announcements must be between 1 and {} characters",
+                MAX_ANNOUNCEMENT_BODY_LEN
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+fn normalize_comment(body: String) -> Result<String> {
+    let body = body.trim().to_string();
+
+    if body.is_empty() || body.chars().count() > MAX_ANNOUNCEMENT_COMMENT_LEN {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!(
+                "Comments must be between 1 and {} characters",
+                MAX_ANNOUNCEMENT_COMMENT_LEN
+            ),
+        }));
+    }
+
+    Ok(body)
+}
@@ -0,0 +1,329 @@
+use crate::table_access::{ensure_table_member, find_visible_table};
+use domain::entities::*;
+use domain::repositories::{
+    AttachmentRepository, SessionRepository, TableMemberRepository, TableRepository,
+};
+use domain::storage::{ObjectStorage, delete_best_effort};
+use shared::Result;
+use shared::error::{ApplicationError, DomainError, Error};
+use std::sync::Arc;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub const MAX_FILE_NAME_LEN: usize = 255;
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// A file offered by the client, before it is stored.
+#[derive(Debug, Clone)]
+pub struct AttachmentUpload {
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AttachmentFile {
+    pub attachment: Attachment,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct AttachmentService {
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    table_member_repository: Arc<dyn TableMemberRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    max_attachment_bytes: usize,
+    table_quota_bytes: u64,
+}
+
+impl AttachmentService {
+    pub fn new(
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        table_member_repository: Arc<dyn TableMemberRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        max_attachment_bytes: usize,
+        table_quota_bytes: u64,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            table_repository,
+            session_repository,
+            table_member_repository,
+            storage,
+            max_attachment_bytes,
+            table_quota_bytes,
+        }
+    }
+
+    pub fn max_attachment_bytes(&self) -> usize {
+        self.max_attachment_bytes
+    }
+
+    #[instrument(name = "AttachmentService::upload_to_table", level = "debug", skip_all)]
+    pub async fn upload_to_table(
+        &self,
+        gm_id: Uuid,
+        table_id: Uuid,
+        upload: AttachmentUpload,
+    ) -> Result<Attachment> {
+        let table = self.find_table(gm_id, table_id).await?;
+        self.upload(gm_id, &table, None, upload).await
+    }
+
+    #[instrument(
+        name = "AttachmentService::upload_to_session",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn upload_to_session(
+        &self,
+        gm_id: Uuid,
+        session_id: Uuid,
+        upload: AttachmentUpload,
+    ) -> Result<Attachment> {
+        let session = self.find_session(session_id).await?;
+        let table = self.find_table(gm_id, session.table_id).await?;
+        self.upload(gm_id, &table, Some(session.id), upload).await
+    }
+
+    #[instrument(name = "AttachmentService::list_for_table", level = "debug", skip_all)]
+    pub async fn list_for_table(&self, user_id: Uuid, table_id: Uuid) -> Result<Vec<Attachment>> {
+        let table = self.find_table(user_id, table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        self.attachment_repository.find_by_table_id(table_id).await
+    }
+
+    #[instrument(
+        name = "AttachmentService::list_for_session",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn list_for_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<Vec<Attachment>> {
+        let session = self.find_session(session_id).await?;
+        let table = self.find_table(user_id, session.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        self.attachment_repository
+            .find_by_session_id(session_id)
+            .await
+    }
+
+    #[instrument(name = "AttachmentService::get_usage", level = "debug", skip_all)]
+    pub async fn get_usage(&self, user_id: Uuid, table_id: Uuid) -> Result<AttachmentUsage> {
+        let table = self.find_table(user_id, table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        let used_bytes = self
+            .attachment_repository
+            .used_bytes_by_table_id(table_id)
+            .await?;
+
+        Ok(AttachmentUsage {
+            used_bytes,
+            quota_bytes: self.table_quota_bytes,
+        })
+    }
+
+    #[instrument(name = "AttachmentService::download", level = "debug", skip_all)]
+    pub async fn download(&self, user_id: Uuid, attachment_id: Uuid) -> Result<AttachmentFile> {
+        let attachment = self.find_attachment(attachment_id).await?;
+        let table = self.find_table(user_id, attachment.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        let bytes = self
+            .storage
+            .get(&attachment.storage_key)
+            .await?
+            .ok_or_else(|| attachment_not_found(attachment_id))?;
+
+        Ok(AttachmentFile { attachment, bytes })
+    }
+
+    #[instrument(name = "AttachmentService::delete", level = "debug", skip_all)]
+    pub async fn delete(&self, gm_id: Uuid, attachment_id: Uuid) -> Result<()> {
+        let attachment = self.find_attachment(attachment_id).await?;
+        let table = self.find_table(gm_id, attachment.table_id).await?;
+
+        if table.gm_id != gm_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        if let Some(deleted) = self.attachment_repository.delete(attachment_id).await? {
+            delete_best_effort(self.storage.as_ref(), &deleted.storage_key).await;
+        }
+
+        Ok(())
+    }
+
+    async fn upload(
+        &self,
+        gm_id: Uuid,
+        table: &Table,
+        session_id: Option<Uuid>,
+        upload: AttachmentUpload,
+    ) -> Result<Attachment> {
+        if table.gm_id != gm_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        if upload.bytes.is_empty() {
+            return Err(Error::Application(ApplicationError::InvalidInput {
+                message: "Attachments cannot be empty".to_string(),
+            }));
+        }
+
+        if upload.bytes.len() > self.max_attachment_bytes {
+            return Err(Error::Application(ApplicationError::InvalidInput {
+                message: format!(
+                    "Attachments must be at most {} bytes",
+                    self.max_attachment_bytes
+                ),
+            }));
+        }
+
+        let size_bytes = upload.bytes.len() as u64;
+
+        // Cheap early rejection; the repository repeats the check atomically.
+        let used_bytes = self
+            .attachment_repository
+            .used_bytes_by_table_id(table.id)
+            .await?;
+        if used_bytes + size_bytes > self.table_quota_bytes {
+            return Err(quota_exceeded(self.table_quota_bytes));
+        }
+
+        let id = Uuid::now_v7();
+        let storage_key = format!("attachments/{}/{}", table.id, id);
+        let content_type = normalize_content_type(upload.content_type);
+
+        self.storage
+            .put(&storage_key, upload.bytes, &content_type)
+            .await?;
+
+        let command = CreateAttachmentCommand {
+            id,
+            table_id: table.id,
+            session_id,
+            uploaded_by: gm_id,
+            file_name: sanitize_file_name(upload.file_name),
+            content_type,
+            size_bytes,
+            storage_key: storage_key.clone(),
+        };
+
+        let created = self
+            .attachment_repository
+            .create_within_quota(command, self.table_quota_bytes)
+            .await;
+
+        match created {
+            Ok(Some(attachment)) => Ok(attachment),
+            Ok(None) => {
+                delete_best_effort(self.storage.as_ref(), &storage_key).await;
+                Err(quota_exceeded(self.table_quota_bytes))
+            }
+            Err(err) => {
+                delete_best_effort(self.storage.as_ref(), &storage_key).await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn find_table(&self, user_id: Uuid, table_id: Uuid) -> Result<Table> {
+        find_visible_table(self.table_repository.as_ref(), user_id, table_id).await
+    }
+
+    async fn find_session(&self, session_id: Uuid) -> Result<Session> {
+        self.session_repository
+            .find_by_id(session_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "Session",
+                    entity_id: session_id.to_string(),
+                })
+            })
+    }
+
+    async fn find_attachment(&self, attachment_id: Uuid) -> Result<Attachment> {
+        self.attachment_repository
+            .find_by_id(attachment_id)
+            .await?
+            .ok_or_else(|| attachment_not_found(attachment_id))
+    }
+
+    async fn ensure_member(&self, user_id: Uuid, table: &Table) -> Result<()> {
+        ensure_table_member(self.table_member_repository.as_ref(), user_id, table).await
+    }
+}
+
+fn attachment_not_found(attachment_id: Uuid) -> Error {
+    Error::Domain(DomainError::EntityNotFound {
+        entity_type: "Attachment",
+        entity_id: attachment_id.to_string(),
+    })
+}
+
+fn quota_exceeded(quota_bytes: u64) -> Error {
+    Error::Domain(DomainError::BusinessRuleViolation {
+        message: format!(
+            "This upload would exceed the table's attachment quota of {} bytes",
+            quota_bytes
+        ),
+    })
+}
+
+/// Keeps only the last path segment of the client's file name and drops
+/// characters that would break a `Content-Disposition` header.
+fn sanitize_file_name(file_name: Option<String>) -> String {
+    let file_name = file_name.unwrap_or_default();
+    let base = file_name.rsplit(['/', '\\']).next().unwrap_or_default();
+
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .take(MAX_FILE_NAME_LEN)
+        .collect();
+    let cleaned = cleaned.trim();
+
+    if cleaned.is_empty() || cleaned == "." || cleaned == ".." {
+        "attachment".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// Accepts well-formed `type/subtype` values and falls back to a generic
+/// binary type otherwise.
+fn normalize_content_type(content_type: Option<String>) -> String {
+    let Some(content_type) = content_type.map(|c| c.trim().to_ascii_lowercase()) else {
+        return DEFAULT_CONTENT_TYPE.to_string();
+    };
+
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+    let valid = essence.len() <= 100
+        && essence.split_once('/').is_some_and(|(kind, subtype)| {
+            let token = |part: &str| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+            };
+            token(kind) && token(subtype)
+        });
+
+    if valid {
+        essence.to_string()
+    } else {
+        DEFAULT_CONTENT_TYPE.to_string()
+    }
+}
@@ -2,63 +2,132 @@ use base64::Engine;
 use chrono::Utc;
 use domain::auth::*;
 use domain::entities::*;
-use domain::repositories::{RefreshTokenRepository, UserRepository};
-use log::warn;
+use domain::mail::{EmailMessage, Mailer};
+use domain::repositories::{
+    AuditLogRepository, MagicLinkRepository, RefreshTokenRepository, UserRepository,
+};
+use log::{error, warn};
+use metrics::counter;
 use rand::Rng;
 use rand::RngCore;
+use sha2::{Digest, Sha256};
 use shared::Result;
 use shared::error::ApplicationError;
 use shared::error::Error;
+use shared::metrics::{AUTH_FAILURES_TOTAL, USERS_REGISTERED_TOTAL};
 use std::sync::Arc;
 use std::time::Duration;
+use tracing::instrument;
+use url::Url;
 use uuid::Uuid;
 
+pub const MAGIC_LINK_TTL: Duration = Duration::from_mins(15);
+/// Maximum number of sign-in links a single account may request within
+/// `MAGIC_LINK_RATE_WINDOW`.
+pub const MAGIC_LINK_RATE_LIMIT: i64 = 3;
+pub const MAGIC_LINK_RATE_WINDOW: Duration = Duration::from_mins(15);
+
 #[derive(Clone)]
 pub struct AuthService {
     user_repository: Arc<dyn UserRepository>,
     password_provider: Arc<dyn PasswordProvider>,
     jwt_provider: Arc<dyn TokenProvider>,
     refresh_token_repository: Arc<dyn RefreshTokenRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    magic_link_repository: Arc<dyn MagicLinkRepository>,
+    mailer: Arc<dyn Mailer>,
+    magic_link_url: Url,
     jwt_expiration_duration: Duration,
+    refresh_token_expiration_duration: Duration,
 }
 
 impl AuthService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_repository: Arc<dyn UserRepository>,
         password_provider: Arc<dyn PasswordProvider>,
         jwt_provider: Arc<dyn TokenProvider>,
         refresh_token_repository: Arc<dyn RefreshTokenRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        magic_link_repository: Arc<dyn MagicLinkRepository>,
+        mailer: Arc<dyn Mailer>,
+        magic_link_url: Url,
         jwt_expiration_duration: Duration,
+        refresh_token_expiration_duration: Duration,
     ) -> Self {
         Self {
             user_repository,
             password_provider,
             jwt_provider,
             refresh_token_repository,
+            audit_log_repository,
+            magic_link_repository,
+            mailer,
+            magic_link_url,
             jwt_expiration_duration,
+            refresh_token_expiration_duration,
         }
     }
 
+    fn generate_opaque_token() -> String {
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn hash_token(token: &str) -> String {
+        let digest = Sha256::digest(token.as_bytes());
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest)
+    }
+
     async fn issue_refresh_token(&self, user_id: Uuid) -> Result<String> {
         self.refresh_token_repository
             .delete_by_user(user_id)
             .await?;
 
-        let mut bytes = [0u8; 32];
-        rand::thread_rng().fill_bytes(&mut bytes);
-        let token = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+        let token = Self::generate_opaque_token();
 
         let command = CreateRefreshTokenCommand {
             id: Uuid::now_v7(),
             user_id,
             token: token.clone(),
-            expires_at: Utc::now() + self.jwt_expiration_duration,
+            expires_at: Utc::now() + self.refresh_token_expiration_duration,
         };
 
         self.refresh_token_repository.create(command).await?;
         Ok(token)
     }
 
+    async fn start_session(&self, user: User) -> Result<LoginResponse> {
+        if user.is_suspended() {
+            return Err(Error::Application(ApplicationError::AccountSuspended));
+        }
+
+        let access_token = self.jwt_provider.generate_token(user.id).await?;
+        let refresh_token = self.issue_refresh_token(user.id).await?;
+        self.record(Some(user.id), AuditAction::LoginSucceeded, user.id)
+            .await?;
+        let expires_in = (Utc::now() + self.jwt_expiration_duration).timestamp_millis();
+
+        Ok(LoginResponse {
+            user,
+            access_token,
+            refresh_token,
+            expires_in,
+        })
+    }
+
+    async fn record(
+        &self,
+        actor_id: Option<Uuid>,
+        action: AuditAction,
+        user_id: Uuid,
+    ) -> Result<()> {
+        let command = CreateAuditLogCommand::new(actor_id, action, AuditEntityType::User, user_id);
+        self.audit_log_repository.create(command).await?;
+        Ok(())
+    }
+
     async fn validate_credentials(&self, email: &str, password: &str) -> Result<Option<User>> {
         let user = self.user_repository.find_by_email(email).await?;
 
@@ -72,6 +141,8 @@ impl AuthService {
                 if is_valid {
                     Ok(Some(user))
                 } else {
+                    self.record(None, AuditAction::LoginFailed, user.id).await?;
+
                     let time_to_sleep = rand::thread_rng()
                         .gen_range(DEFAULT_MIN_DELAY_MILIS..DEFAULT_MAX_DELAY_MILIS);
                     std::thread::sleep(Duration::from_millis(time_to_sleep));
@@ -88,26 +159,103 @@ impl AuthService {
     }
 }
 
+/// Counts a failed sign-in attempt before rejecting it.
+fn invalid_credentials(reason: &'static str) -> Error {
+    counter!(AUTH_FAILURES_TOTAL, "reason" => reason).increment(1);
+    Error::Application(ApplicationError::InvalidCredentials)
+}
+
 #[async_trait::async_trait]
 impl AuthenticationService for AuthService {
+    #[instrument(name = "AuthService::login", level = "debug", skip_all)]
     async fn login(&self, command: LoginCommand) -> Result<LoginResponse> {
         let user = self
             .validate_credentials(&command.email, &command.password)
             .await?
-            .ok_or_else(|| Error::Application(ApplicationError::InvalidCredentials))?;
+            .ok_or_else(|| invalid_credentials("invalid_credentials"))?;
 
-        let access_token = self.jwt_provider.generate_token(user.id).await?;
-        let refresh_token = self.issue_refresh_token(user.id).await?;
-        let expires_in = (Utc::now() + self.jwt_expiration_duration).timestamp_millis();
+        self.start_session(user).await
+    }
 
-        Ok(LoginResponse {
-            user,
-            access_token,
-            refresh_token,
-            expires_in,
-        })
+    #[instrument(name = "AuthService::request_magic_link", level = "debug", skip_all)]
+    async fn request_magic_link(&self, command: MagicLinkRequestCommand) -> Result<()> {
+        // Unknown, suspended and rate-limited addresses all succeed silently so
+        // the endpoint cannot be used to probe for registered emails.
+        let Some(user) = self.user_repository.find_by_email(&command.email).await? else {
+            return Ok(());
+        };
+
+        if user.is_suspended() {
+            return Ok(());
+        }
+
+        let window_start = Utc::now() - MAGIC_LINK_RATE_WINDOW;
+        let recent = self
+            .magic_link_repository
+            .count_created_since(user.id, window_start)
+            .await?;
+
+        if recent >= MAGIC_LINK_RATE_LIMIT {
+            warn!("Magic link rate limit reached for user {}", user.id);
+            return Ok(());
+        }
+
+        let token = Self::generate_opaque_token();
+        let create_command = CreateMagicLinkTokenCommand::new(
+            user.id,
+            Self::hash_token(&token),
+            Utc::now() + MAGIC_LINK_TTL,
+        );
+        self.magic_link_repository.create(create_command).await?;
+
+        let mut link = self.magic_link_url.clone();
+        link.query_pairs_mut().append_pair("token", &token);
+
+        let message = EmailMessage {
+            to: user.email.clone(),
+            subject: "Your sign-in link".to_string(),
+            body: format!(
+                "Hi {},\n\nUse the link below to sign in. It expires in {} minutes and can only be used once.\n\n{}\n\nIf you did not request this, you can ignore this email.",
+                user.username,
+                MAGIC_LINK_TTL.as_secs() / 60,
+                link
+            ),
+        };
+        // Failures after this point are only logged: reporting them would
+        // tell the caller that the address belongs to an account.
+        if let Err(err) = self.mailer.send(message).await {
+            error!("Failed to send magic link to user {}: {}", user.id, err);
+            return Ok(());
+        }
+
+        if let Err(err) = self
+            .record(Some(user.id), AuditAction::MagicLinkRequested, user.id)
+            .await
+        {
+            error!("Failed to audit magic link for user {}: {}", user.id, err);
+        }
+
+        Ok(())
     }
 
+    #[instrument(name = "AuthService::login_with_magic_link", level = "debug", skip_all)]
+    async fn login_with_magic_link(&self, command: MagicLinkLoginCommand) -> Result<LoginResponse> {
+        let token = self
+            .magic_link_repository
+            .consume(&Self::hash_token(&command.token))
+            .await?
+            .ok_or_else(|| invalid_credentials("invalid_magic_link"))?;
+
+        let user = self
+            .user_repository
+            .find_by_id(token.user_id)
+            .await?
+            .ok_or_else(|| invalid_credentials("invalid_magic_link"))?;
+
+        self.start_session(user).await
+    }
+
+    #[instrument(name = "AuthService::register", level = "debug", skip_all)]
     async fn register(&self, command: RegisterCommand) -> Result<LoginResponse> {
         let hashed_password = self
             .password_provider
@@ -121,7 +269,10 @@ impl AuthenticationService for AuthService {
             password: hashed_password,
         };
 
-        self.user_repository.create(create_command).await?;
+        let user = self.user_repository.create(create_command).await?;
+        self.record(Some(user.id), AuditAction::UserRegistered, user.id)
+            .await?;
+        counter!(USERS_REGISTERED_TOTAL).increment(1);
 
         let login_command = LoginCommand {
             email: command.email,
@@ -131,6 +282,7 @@ impl AuthenticationService for AuthService {
         self.login(login_command).await
     }
 
+    #[instrument(name = "AuthService::change_password", level = "debug", skip_all)]
     async fn change_password(&self, user_id: Uuid, command: ChangePasswordCommand) -> Result<()> {
         if command.new_password != command.confirm_password {
             return Err(Error::Application(ApplicationError::InvalidCredentials));
@@ -174,13 +326,17 @@ impl AuthenticationService for AuthService {
             username: None,
             email: None,
             password: Some(new_password_hash),
+            role: None,
         };
 
         self.user_repository.update(user_update_command).await?;
+        self.record(Some(user_id), AuditAction::PasswordChanged, user_id)
+            .await?;
 
         Ok(())
     }
 
+    #[instrument(name = "AuthService::refresh_token", level = "debug", skip_all)]
     async fn refresh_token(&self, command: RefreshTokenCommand) -> Result<RefreshResponse> {
         let existing = self
             .refresh_token_repository
@@ -189,20 +345,30 @@ impl AuthenticationService for AuthService {
 
         let record = existing.ok_or_else(|| {
             warn!("Invalid refresh token");
-            Error::Application(ApplicationError::InvalidCredentials)
+            invalid_credentials("invalid_refresh_token")
         })?;
 
         if record.expires_at < Utc::now() {
             self.refresh_token_repository
                 .delete_by_token(&command.token)
                 .await?;
-            return Err(Error::Application(ApplicationError::InvalidCredentials));
+            return Err(invalid_credentials("expired_refresh_token"));
         }
 
         self.refresh_token_repository
             .delete_by_token(&command.token)
             .await?;
 
+        let user = self
+            .user_repository
+            .find_by_id(record.user_id)
+            .await?
+            .ok_or_else(|| invalid_credentials("invalid_refresh_token"))?;
+
+        if user.is_suspended() {
+            return Err(Error::Application(ApplicationError::AccountSuspended));
+        }
+
         let access_token = self.jwt_provider.generate_token(record.user_id).await?;
         let new_refresh_token = self.issue_refresh_token(record.user_id).await?;
 
@@ -213,13 +379,17 @@ impl AuthenticationService for AuthService {
         })
     }
 
+    #[instrument(name = "AuthService::logout", level = "debug", skip_all)]
     async fn logout(&self, command: LogoutCommand) -> Result<()> {
         self.refresh_token_repository
             .delete_by_user(command.user_id)
             .await?;
+        self.record(Some(command.user_id), AuditAction::Logout, command.user_id)
+            .await?;
         Ok(())
     }
 
+    #[instrument(name = "AuthService::validate_token", level = "debug", skip_all)]
     async fn validate_token(&self, token: &str) -> Result<Claims> {
         self.jwt_provider.decode_token(token).await
     }
@@ -0,0 +1,167 @@
+use crate::table_access::{ensure_table_member, find_visible_table};
+use domain::chat::{ChatEvent, ChatPublisher};
+use domain::entities::*;
+use domain::repositories::{
+    ChatMessageRepository, TableMemberRepository, TableRepository, UserRepository,
+};
+use shared::Result;
+use shared::error::{ApplicationError, Error};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Kept well below Postgres' 8000 byte NOTIFY payload limit, since messages
+/// are fanned out through it whole.
+pub const MAX_CHAT_MESSAGE_LEN: usize = 1_000;
+pub const DEFAULT_CHAT_PAGE_SIZE: u32 = 50;
+pub const MAX_CHAT_PAGE_SIZE: u32 = 100;
+const LOCAL_EVENT_BUFFER: usize = 1_024;
+
+/// One page of chat history, newest first.
+#[derive(Debug, Clone)]
+pub struct ChatHistoryPage {
+    pub messages: Vec<ChatMessage>,
+    /// Pass as `before` to get the next, older page.
+    pub next_cursor: Option<Uuid>,
+}
+
+/// Per-table chat restricted to the GM and members. Events are published to
+/// every server instance and handed to local connections by [`deliver`].
+///
+/// [`deliver`]: ChatService::deliver
+#[derive(Clone)]
+pub struct ChatService {
+    chat_message_repository: Arc<dyn ChatMessageRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    table_member_repository: Arc<dyn TableMemberRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    publisher: Arc<dyn ChatPublisher>,
+    local_events: broadcast::Sender<ChatEvent>,
+}
+
+impl ChatService {
+    pub fn new(
+        chat_message_repository: Arc<dyn ChatMessageRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        table_member_repository: Arc<dyn TableMemberRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        publisher: Arc<dyn ChatPublisher>,
+    ) -> Self {
+        let (local_events, _) = broadcast::channel(LOCAL_EVENT_BUFFER);
+
+        Self {
+            chat_message_repository,
+            table_repository,
+            table_member_repository,
+            user_repository,
+            publisher,
+            local_events,
+        }
+    }
+
+    /// Checks that the user may take part in the table's chat. Open
+    /// connections call this again periodically, so it also rejects accounts
+    /// suspended or deleted since they connected.
+    #[instrument(name = "ChatService::join", level = "debug", skip_all)]
+    pub async fn join(&self, user_id: Uuid, table_id: Uuid) -> Result<()> {
+        let user = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(Error::Application(ApplicationError::Unauthenticated))?;
+        if user.is_suspended() {
+            return Err(Error::Application(ApplicationError::AccountSuspended));
+        }
+
+        let table = find_visible_table(self.table_repository.as_ref(), user_id, table_id).await?;
+        ensure_table_member(self.table_member_repository.as_ref(), user_id, &table).await
+    }
+
+    #[instrument(name = "ChatService::send_message", level = "debug", skip_all)]
+    pub async fn send_message(
+        &self,
+        user_id: Uuid,
+        mut command: CreateChatMessageCommand,
+    ) -> Result<ChatMessage> {
+        self.join(user_id, command.table_id).await?;
+
+        command.author_id = user_id;
+        command.body = normalize_body(command.body)?;
+
+        let message = self.chat_message_repository.create(command).await?;
+        self.publisher
+            .publish(&ChatEvent::Message(message.clone()))
+            .await?;
+
+        Ok(message)
+    }
+
+    /// Typing indicators are not persisted. Callers are expected to have
+    /// joined the table already.
+    #[instrument(name = "ChatService::send_typing", level = "debug", skip_all)]
+    pub async fn send_typing(&self, user_id: Uuid, table_id: Uuid) -> Result<()> {
+        self.publisher
+            .publish(&ChatEvent::Typing { table_id, user_id })
+            .await
+    }
+
+    #[instrument(name = "ChatService::get_history", level = "debug", skip_all)]
+    pub async fn get_history(
+        &self,
+        user_id: Uuid,
+        table_id: Uuid,
+        before: Option<Uuid>,
+        limit: Option<u32>,
+    ) -> Result<ChatHistoryPage> {
+        self.join(user_id, table_id).await?;
+
+        let limit = limit
+            .unwrap_or(DEFAULT_CHAT_PAGE_SIZE)
+            .clamp(1, MAX_CHAT_PAGE_SIZE);
+
+        // One extra row tells whether an older page exists.
+        let mut messages = self
+            .chat_message_repository
+            .find_by_table_id(table_id, before, limit + 1)
+            .await?;
+
+        let next_cursor = if messages.len() > limit as usize {
+            messages.truncate(limit as usize);
+            messages.last().map(|message| message.id)
+        } else {
+            None
+        };
+
+        Ok(ChatHistoryPage {
+            messages,
+            next_cursor,
+        })
+    }
+
+    /// Receives the events of every table; connections filter their own.
+    pub fn subscribe(&self) -> broadcast::Receiver<ChatEvent> {
+        self.local_events.subscribe()
+    }
+
+    /// Hands an event received from the publisher to local connections.
+    pub fn deliver(&self, event: ChatEvent) {
+        // Sending only fails when nobody is connected.
+        let _ = self.local_events.send(event);
+    }
+}
+
+fn normalize_body(body: String) -> Result<String> {
+    let body = body.trim().to_string();
+
+    if body.is_empty() || body.chars().count() > MAX_CHAT_MESSAGE_LEN {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!(
+                "Chat messages must be between 1 and {} characters",
+                MAX_CHAT_MESSAGE_LEN
+            ),
+        }));
+    }
+
+    Ok(body)
+}
@@ -2,6 +2,7 @@ use domain::entities::*;
 use domain::repositories::GameSystemRepository;
 use shared::Result;
 use std::sync::Arc;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -16,22 +17,27 @@ impl GameSystemService {
         }
     }
 
+    #[instrument(name = "GameSystemService::create", level = "debug", skip_all)]
     pub async fn create(&self, command: CreateGameSystemCommand) -> Result<GameSystem> {
         self.game_system_repository.create(command).await
     }
 
+    #[instrument(name = "GameSystemService::get", level = "debug", skip_all)]
     pub async fn get(&self, command: GetGameSystemCommand) -> Result<Vec<GameSystem>> {
         self.game_system_repository.read(command).await
     }
 
+    #[instrument(name = "GameSystemService::update", level = "debug", skip_all)]
     pub async fn update(&self, command: UpdateGameSystemCommand) -> Result<GameSystem> {
         self.game_system_repository.update(command).await
     }
 
+    #[instrument(name = "GameSystemService::delete", level = "debug", skip_all)]
     pub async fn delete(&self, command: DeleteGameSystemCommand) -> Result<GameSystem> {
         self.game_system_repository.delete(command).await
     }
 
+    #[instrument(name = "GameSystemService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, id: Uuid) -> Result<Option<GameSystem>> {
         self.game_system_repository.find_by_id(id).await
     }
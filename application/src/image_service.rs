@@ -0,0 +1,313 @@
+use ::image::codecs::jpeg::JpegEncoder;
+use ::image::codecs::png::PngEncoder;
+use ::image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+use domain::entities::*;
+use domain::repositories::{ImageRepository, TableRepository};
+use domain::storage::{ObjectStorage, delete_best_effort};
+use shared::Result;
+use shared::error::{ApplicationError, DomainError, Error};
+use std::io::Cursor;
+use std::sync::Arc;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub const MAX_IMAGE_DIMENSION: u32 = 8192;
+pub const THUMBNAIL_SIZE: u32 = 256;
+const JPEG_QUALITY: u8 = 85;
+
+/// The bytes of a stored image together with the type to serve them as.
+#[derive(Debug, Clone)]
+pub struct ImageFile {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Clone)]
+pub struct ImageService {
+    image_repository: Arc<dyn ImageRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    max_upload_bytes: usize,
+}
+
+impl ImageService {
+    pub fn new(
+        image_repository: Arc<dyn ImageRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        max_upload_bytes: usize,
+    ) -> Self {
+        Self {
+            image_repository,
+            table_repository,
+            storage,
+            max_upload_bytes,
+        }
+    }
+
+    pub fn max_upload_bytes(&self) -> usize {
+        self.max_upload_bytes
+    }
+
+    #[instrument(name = "ImageService::upload_table_cover", level = "debug", skip_all)]
+    pub async fn upload_table_cover(
+        &self,
+        gm_id: Uuid,
+        table_id: Uuid,
+        bytes: Vec<u8>,
+    ) -> Result<Image> {
+        self.ensure_table_gm(gm_id, table_id).await?;
+        self.store(ImageKind::TableCover, table_id, gm_id, bytes)
+            .await
+    }
+
+    #[instrument(name = "ImageService::delete_table_cover", level = "debug", skip_all)]
+    pub async fn delete_table_cover(&self, gm_id: Uuid, table_id: Uuid) -> Result<()> {
+        self.ensure_table_gm(gm_id, table_id).await?;
+        self.remove(ImageKind::TableCover, table_id).await
+    }
+
+    #[instrument(name = "ImageService::get_table_cover", level = "debug", skip_all)]
+    pub async fn get_table_cover(
+        &self,
+        viewer_id: Uuid,
+        table_id: Uuid,
+        variant: ImageVariant,
+    ) -> Result<ImageFile> {
+        self.table_repository
+            .find_visible_by_id(table_id, viewer_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "Table",
+                    entity_id: table_id.to_string(),
+                })
+            })?;
+
+        self.load(ImageKind::TableCover, table_id, variant).await
+    }
+
+    #[instrument(name = "ImageService::upload_avatar", level = "debug", skip_all)]
+    pub async fn upload_avatar(&self, user_id: Uuid, bytes: Vec<u8>) -> Result<Image> {
+        self.store(ImageKind::Avatar, user_id, user_id, bytes).await
+    }
+
+    #[instrument(name = "ImageService::delete_avatar", level = "debug", skip_all)]
+    pub async fn delete_avatar(&self, user_id: Uuid) -> Result<()> {
+        self.remove(ImageKind::Avatar, user_id).await
+    }
+
+    #[instrument(name = "ImageService::get_avatar", level = "debug", skip_all)]
+    pub async fn get_avatar(&self, user_id: Uuid, variant: ImageVariant) -> Result<ImageFile> {
+        self.load(ImageKind::Avatar, user_id, variant).await
+    }
+
+    async fn ensure_table_gm(&self, gm_id: Uuid, table_id: Uuid) -> Result<()> {
+        let table = self
+            .table_repository
+            .find_by_id(table_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "Table",
+                    entity_id: table_id.to_string(),
+                })
+            })?;
+
+        if table.gm_id != gm_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        Ok(())
+    }
+
+    /// Processes the upload, writes both files and only then swaps the
+    /// database row, so a failed upload never leaves a dangling reference.
+    async fn store(
+        &self,
+        kind: ImageKind,
+        subject_id: Uuid,
+        uploaded_by: Uuid,
+        bytes: Vec<u8>,
+    ) -> Result<Image> {
+        if bytes.len() > self.max_upload_bytes {
+            return Err(Error::Application(ApplicationError::InvalidInput {
+                message: format!("Images must be at most {} bytes", self.max_upload_bytes),
+            }));
+        }
+
+        let processed = tokio::task::spawn_blocking(move || process_image(&bytes))
+            .await
+            .map_err(|_| Error::InternalServerError)??;
+
+        let id = Uuid::now_v7();
+        let prefix = format!("{}/{}/{}", kind.as_str(), subject_id, id);
+        let storage_key = format!("{}.{}", prefix, processed.extension);
+        let thumbnail_key = format!("{}_thumb.{}", prefix, processed.extension);
+        let size_bytes = processed.original.len() as u64;
+
+        self.storage
+            .put(&storage_key, processed.original, processed.content_type)
+            .await?;
+        self.storage
+            .put(&thumbnail_key, processed.thumbnail, processed.content_type)
+            .await?;
+
+        let previous = self.image_repository.find(kind, subject_id).await?;
+
+        let image = self
+            .image_repository
+            .upsert(CreateImageCommand {
+                id,
+                kind,
+                subject_id,
+                uploaded_by,
+                content_type: processed.content_type.to_string(),
+                width: processed.width,
+                height: processed.height,
+                size_bytes,
+                storage_key,
+                thumbnail_key,
+            })
+            .await?;
+
+        if let Some(previous) = previous {
+            self.delete_files(&previous).await;
+        }
+
+        Ok(image)
+    }
+
+    async fn remove(&self, kind: ImageKind, subject_id: Uuid) -> Result<()> {
+        let image = self
+            .image_repository
+            .delete(kind, subject_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "Image",
+                    entity_id: subject_id.to_string(),
+                })
+            })?;
+
+        self.delete_files(&image).await;
+
+        Ok(())
+    }
+
+    async fn load(
+        &self,
+        kind: ImageKind,
+        subject_id: Uuid,
+        variant: ImageVariant,
+    ) -> Result<ImageFile> {
+        let not_found = || {
+            Error::Domain(DomainError::EntityNotFound {
+                entity_type: "Image",
+                entity_id: subject_id.to_string(),
+            })
+        };
+
+        let image = self
+            .image_repository
+            .find(kind, subject_id)
+            .await?
+            .ok_or_else(not_found)?;
+
+        let bytes = self
+            .storage
+            .get(image.key(variant))
+            .await?
+            .ok_or_else(not_found)?;
+
+        Ok(ImageFile {
+            content_type: image.content_type,
+            bytes,
+        })
+    }
+
+    async fn delete_files(&self, image: &Image) {
+        for key in [&image.storage_key, &image.thumbnail_key] {
+            delete_best_effort(self.storage.as_ref(), key).await;
+        }
+    }
+}
+
+struct ProcessedImage {
+    original: Vec<u8>,
+    thumbnail: Vec<u8>,
+    content_type: &'static str,
+    extension: &'static str,
+    width: u32,
+    height: u32,
+}
+
+fn invalid_image() -> Error {
+    Error::Application(ApplicationError::InvalidInput {
+        message: "File is not a supported image (JPEG, PNG, WebP or GIF)".to_string(),
+    })
+}
+
+/// Decodes the upload based on its content rather than the declared type,
+/// applies the EXIF orientation and re-encodes it. Re-encoding drops all
+/// metadata, including EXIF location data.
+fn process_image(bytes: &[u8]) -> Result<ProcessedImage> {
+    let format = ::image::guess_format(bytes).map_err(|_| invalid_image())?;
+
+    if !matches!(
+        format,
+        ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP | ImageFormat::Gif
+    ) {
+        return Err(invalid_image());
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+    limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+
+    let mut reader = ImageReader::new(Cursor::new(bytes));
+    reader.set_format(format);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().map_err(|_| invalid_image())?;
+    let orientation = decoder.orientation().map_err(|_| invalid_image())?;
+    let mut decoded = DynamicImage::from_decoder(decoder).map_err(|_| invalid_image())?;
+    decoded.apply_orientation(orientation);
+
+    let thumbnail = decoded.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE);
+    let has_alpha = decoded.color().has_alpha();
+
+    let (content_type, extension) = if has_alpha {
+        ("image/png", "png")
+    } else {
+        ("image/jpeg", "jpg")
+    };
+
+    Ok(ProcessedImage {
+        original: encode(&decoded, has_alpha)?,
+        thumbnail: encode(&thumbnail, has_alpha)?,
+        content_type,
+        extension,
+        width: decoded.width(),
+        height: decoded.height(),
+    })
+}
+
+/// Images with transparency stay PNG; everything else becomes JPEG.
+fn encode(image: &DynamicImage, has_alpha: bool) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+
+    let result = if has_alpha {
+        image
+            .to_rgba8()
+            .write_with_encoder(PngEncoder::new(&mut buffer))
+    } else {
+        image
+            .to_rgb8()
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut buffer, JPEG_QUALITY))
+    };
+
+    result.map_err(|_| Error::InternalServerError)?;
+
+    Ok(buffer)
+}
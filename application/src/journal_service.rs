@@ -0,0 +1,209 @@
+use crate::markdown::render_markdown;
+use crate::table_access::{ensure_table_member, find_visible_table};
+use domain::entities::*;
+use domain::repositories::{
+    JournalRepository, SessionRepository, TableMemberRepository, TableRepository,
+};
+use shared::Result;
+use shared::error::{ApplicationError, DomainError, Error};
+use std::sync::Arc;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub const MAX_JOURNAL_TITLE_LEN: usize = 200;
+pub const MAX_JOURNAL_BODY_LEN: usize = 50_000;
+
+/// The campaign journal of a table. Only its GM and members can read or
+/// write it; entries can be changed by their author or the GM.
+#[derive(Clone)]
+pub struct JournalService {
+    journal_repository: Arc<dyn JournalRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    table_member_repository: Arc<dyn TableMemberRepository>,
+}
+
+impl JournalService {
+    pub fn new(
+        journal_repository: Arc<dyn JournalRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        table_member_repository: Arc<dyn TableMemberRepository>,
+    ) -> Self {
+        Self {
+            journal_repository,
+            table_repository,
+            session_repository,
+            table_member_repository,
+        }
+    }
+
+    #[instrument(name = "JournalService::create_entry", level = "debug", skip_all)]
+    pub async fn create_entry(
+        &self,
+        user_id: Uuid,
+        mut command: CreateJournalEntryCommand,
+    ) -> Result<JournalEntry> {
+        let table = self.find_table(user_id, command.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        if let Some(session_id) = command.session_id {
+            self.ensure_session_of_table(session_id, table.id).await?;
+        }
+
+        command.author_id = user_id;
+        command.title = normalize_title(command.title)?;
+        validate_body(&command.body)?;
+        command.body_html = render_markdown(&command.body);
+
+        self.journal_repository.create(command).await
+    }
+
+    /// Returns the journal in campaign order, optionally limited to the
+    /// entries about one session.
+    #[instrument(name = "JournalService::get_campaign_log", level = "debug", skip_all)]
+    pub async fn get_campaign_log(
+        &self,
+        user_id: Uuid,
+        table_id: Uuid,
+        session_id: Option<Uuid>,
+    ) -> Result<Vec<JournalEntry>> {
+        let table = self.find_table(user_id, table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        self.journal_repository
+            .find_by_table_id(table_id, session_id)
+            .await
+    }
+
+    #[instrument(name = "JournalService::get_entry", level = "debug", skip_all)]
+    pub async fn get_entry(&self, user_id: Uuid, entry_id: Uuid) -> Result<JournalEntry> {
+        let entry = self.find_entry(entry_id).await?;
+        let table = self.find_table(user_id, entry.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+
+        Ok(entry)
+    }
+
+    #[instrument(name = "JournalService::get_revisions", level = "debug", skip_all)]
+    pub async fn get_revisions(
+        &self,
+        user_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<Vec<JournalEntryRevision>> {
+        let entry = self.get_entry(user_id, entry_id).await?;
+
+        self.journal_repository.find_revisions(entry.id).await
+    }
+
+    #[instrument(name = "JournalService::update_entry", level = "debug", skip_all)]
+    pub async fn update_entry(
+        &self,
+        user_id: Uuid,
+        mut command: UpdateJournalEntryCommand,
+    ) -> Result<JournalEntry> {
+        let entry = self.find_entry(command.id).await?;
+        let table = self.find_table(user_id, entry.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+        ensure_can_edit(user_id, &entry, &table)?;
+
+        if let Some(Some(session_id)) = command.session_id {
+            self.ensure_session_of_table(session_id, table.id).await?;
+        }
+
+        command.edited_by = user_id;
+        command.title = command.title.map(normalize_title).transpose()?;
+
+        if let Some(body) = &command.body {
+            validate_body(body)?;
+            command.body_html = Some(render_markdown(body));
+        }
+
+        self.journal_repository
+            .update(command)
+            .await?
+            .ok_or_else(|| entry_not_found(entry.id))
+    }
+
+    #[instrument(name = "JournalService::delete_entry", level = "debug", skip_all)]
+    pub async fn delete_entry(&self, user_id: Uuid, entry_id: Uuid) -> Result<()> {
+        let entry = self.find_entry(entry_id).await?;
+        let table = self.find_table(user_id, entry.table_id).await?;
+        self.ensure_member(user_id, &table).await?;
+        ensure_can_edit(user_id, &entry, &table)?;
+
+        self.journal_repository.delete(entry_id).await?;
+
+        Ok(())
+    }
+
+    async fn find_entry(&self, entry_id: Uuid) -> Result<JournalEntry> {
+        self.journal_repository
+            .find_by_id(entry_id)
+            .await?
+            .ok_or_else(|| entry_not_found(entry_id))
+    }
+
+    async fn find_table(&self, user_id: Uuid, table_id: Uuid) -> Result<Table> {
+        find_visible_table(self.table_repository.as_ref(), user_id, table_id).await
+    }
+
+    async fn ensure_member(&self, user_id: Uuid, table: &Table) -> Result<()> {
+        ensure_table_member(self.table_member_repository.as_ref(), user_id, table).await
+    }
+
+    async fn ensure_session_of_table(&self, session_id: Uuid, table_id: Uuid) -> Result<()> {
+        let session = self.session_repository.find_by_id(session_id).await?;
+
+        match session {
+            Some(session) if session.table_id == table_id => Ok(()),
+            _ => Err(Error::Domain(DomainError::EntityNotFound {
+                entity_type: "Session",
+                entity_id: session_id.to_string(),
+            })),
+        }
+    }
+}
+
+fn ensure_can_edit(user_id: Uuid, entry: &JournalEntry, table: &Table) -> Result<()> {
+    if entry.author_id != user_id && table.gm_id != user_id {
+        return Err(Error::Application(ApplicationError::Forbidden));
+    }
+
+    Ok(())
+}
+
+fn entry_not_found(entry_id: Uuid) -> Error {
+    Error::Domain(DomainError::EntityNotFound {
+        entity_type: "JournalEntry",
+        entity_id: entry_id.to_string(),
+    })
+}
+
+fn normalize_title(title: String) -> Result<String> {
+    let title = title.trim().to_string();
+
+    if title.is_empty() || title.chars().count() > MAX_JOURNAL_TITLE_LEN {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!(
+                "Journal titles must be between 1 and {} characters",
+                MAX_JOURNAL_TITLE_LEN
+            ),
+        }));
+    }
+
+    Ok(title)
+}
+
+fn validate_body(body: &str) -> Result<()> {
+    if body.trim().is_empty() || body.chars().count() > MAX_JOURNAL_BODY_LEN {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!(
+                "Journal entries must be between 1 and {} characters",
+                MAX_JOURNAL_BODY_LEN
+            ),
+        }));
+    }
+
+    Ok(())
+}
@@ -1,21 +1,45 @@
+pub mod account_export_service;
+pub mod admin_service;
+pub mod announcement_service;
+pub mod attachment_service;
 pub mod auth_service;
+pub mod chat_service;
 pub mod game_system_service;
+pub mod image_service;
+pub mod journal_service;
+pub mod markdown;
+pub mod pagination;
 pub mod password_service;
+pub mod purge_service;
+pub mod questionnaire;
+pub mod search_service;
 pub mod session_checkin_service;
 pub mod session_intent_service;
 pub mod session_service;
+pub mod table_access;
 pub mod table_member_service;
 pub mod table_request_service;
 pub mod table_service;
+pub mod user_event_service;
 pub mod user_service;
 
+pub use account_export_service::*;
+pub use admin_service::*;
+pub use announcement_service::*;
+pub use attachment_service::*;
 pub use auth_service::*;
+pub use chat_service::*;
 pub use game_system_service::*;
+pub use image_service::*;
+pub use journal_service::*;
 pub use password_service::*;
+pub use purge_service::*;
+pub use search_service::*;
 pub use session_checkin_service::*;
 pub use session_intent_service::*;
 pub use session_service::*;
 pub use table_member_service::*;
 pub use table_request_service::*;
 pub use table_service::*;
+pub use user_event_service::*;
 pub use user_service::*;
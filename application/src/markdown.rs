@@ -0,0 +1,20 @@
+use pulldown_cmark::{Options, Parser, html};
+
+/// Renders user-written markdown to HTML that is safe to embed in a page.
+/// Raw HTML in the source is passed through the sanitizer rather than
+/// escaped, so harmless tags keep working while scripts, event handlers and
+/// `javascript:` links are dropped.
+pub fn render_markdown(source: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, Parser::new_ext(source, options));
+
+    ammonia::Builder::default()
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .clean(&unsafe_html)
+        .to_string()
+}
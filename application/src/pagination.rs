@@ -0,0 +1,14 @@
+use domain::repositories::PaginationParams;
+use shared::Result;
+use shared::error::{ApplicationError, Error};
+
+/// Cursors are item ids, so they can only resume listings ordered by id.
+pub(crate) fn ensure_cursor_supported(page: &PaginationParams, keyset: bool) -> Result<()> {
+    if page.cursor.is_some() && !keyset {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: "Cursors can only be used when sorting by creation time".to_string(),
+        }));
+    }
+
+    Ok(())
+}
@@ -4,6 +4,7 @@ use domain::repositories::UserRepository;
 use shared::error::{ApplicationError, DomainError};
 use shared::{Error, Result};
 use std::sync::Arc;
+use tracing::instrument;
 
 #[derive(Clone)]
 pub struct PasswordService {
@@ -22,22 +23,27 @@ impl PasswordService {
         }
     }
 
+    #[instrument(name = "PasswordService::generate_hash", level = "debug", skip_all)]
     pub async fn generate_hash(&self, password: String) -> Result<String> {
         self.password_provider.generate_hash(password).await
     }
 
+    #[instrument(name = "PasswordService::verify_hash", level = "debug", skip_all)]
     pub async fn verify_hash(&self, password: String, hash: String) -> Result<bool> {
         self.password_provider.verify_hash(password, hash).await
     }
 
+    #[instrument(name = "PasswordService::validate_password", level = "debug", skip_all)]
     pub async fn validate_password(&self, password: &str) -> Result<()> {
         self.password_provider.validate_password(password).await
     }
 
+    #[instrument(name = "PasswordService::hash_password", level = "debug", skip_all)]
     pub async fn hash_password(&self, password: String) -> Result<String> {
         self.generate_hash(password).await
     }
 
+    #[instrument(name = "PasswordService::update_password", level = "debug", skip_all)]
     pub async fn update_password(&self, command: UpdatePasswordCommand) -> Result<()> {
         let user = self
             .user_repository
@@ -64,6 +70,7 @@ impl PasswordService {
             username: None,
             email: None,
             password: Some(new_password_hash),
+            role: None,
         };
 
         self.user_repository.update(update_command).await?;
@@ -0,0 +1,128 @@
+use chrono::Utc;
+use domain::entities::Image;
+use domain::repositories::{
+    AttachmentRepository, ImageRepository, MagicLinkRepository, SessionRepository, TableRepository,
+    UserEventRepository, UserRepository,
+};
+use domain::storage::{ObjectStorage, delete_best_effort};
+use shared::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::instrument;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PurgeReport {
+    pub images: u64,
+    pub attachments: u64,
+    pub sessions: u64,
+    pub tables: u64,
+    pub users: u64,
+    pub magic_links: u64,
+    pub user_events: u64,
+}
+
+impl PurgeReport {
+    pub fn total(&self) -> u64 {
+        self.images
+            + self.attachments
+            + self.sessions
+            + self.tables
+            + self.users
+            + self.magic_links
+            + self.user_events
+    }
+}
+
+/// Permanently removes soft-deleted rows once their restore window is over,
+/// along with their uploaded files, expired sign-in links and old
+/// live-update events.
+#[derive(Clone)]
+pub struct PurgeService {
+    user_repository: Arc<dyn UserRepository>,
+    table_repository: Arc<dyn TableRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    magic_link_repository: Arc<dyn MagicLinkRepository>,
+    user_event_repository: Arc<dyn UserEventRepository>,
+    image_repository: Arc<dyn ImageRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    storage: Arc<dyn ObjectStorage>,
+    deletion_grace_period: Duration,
+    user_event_retention: Duration,
+}
+
+impl PurgeService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        table_repository: Arc<dyn TableRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        magic_link_repository: Arc<dyn MagicLinkRepository>,
+        user_event_repository: Arc<dyn UserEventRepository>,
+        image_repository: Arc<dyn ImageRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        storage: Arc<dyn ObjectStorage>,
+        deletion_grace_period: Duration,
+        user_event_retention: Duration,
+    ) -> Self {
+        Self {
+            user_repository,
+            table_repository,
+            session_repository,
+            magic_link_repository,
+            user_event_repository,
+            image_repository,
+            attachment_repository,
+            storage,
+            deletion_grace_period,
+            user_event_retention,
+        }
+    }
+
+    #[instrument(name = "PurgeService::purge_expired", level = "debug", skip_all)]
+    pub async fn purge_expired(&self) -> Result<PurgeReport> {
+        let deleted_before = Utc::now() - self.deletion_grace_period;
+
+        // Uploads first: their rows are what lead to the stored files.
+        let images = self.image_repository.purge_orphaned(deleted_before).await?;
+        for image in &images {
+            self.delete_image_files(image).await;
+        }
+
+        let attachments = self
+            .attachment_repository
+            .purge_deleted(deleted_before)
+            .await?;
+        for attachment in &attachments {
+            delete_best_effort(self.storage.as_ref(), &attachment.storage_key).await;
+        }
+
+        // Children first so a purged user never takes a restorable table along.
+        let sessions = self
+            .session_repository
+            .purge_deleted(deleted_before)
+            .await?;
+        let tables = self.table_repository.purge_deleted(deleted_before).await?;
+        let users = self.user_repository.purge_deleted(deleted_before).await?;
+        let magic_links = self.magic_link_repository.purge_expired(Utc::now()).await?;
+        let user_events = self
+            .user_event_repository
+            .purge_older_than(Utc::now() - self.user_event_retention)
+            .await?;
+
+        Ok(PurgeReport {
+            images: images.len() as u64,
+            attachments: attachments.len() as u64,
+            sessions,
+            tables,
+            users,
+            magic_links,
+            user_events,
+        })
+    }
+
+    async fn delete_image_files(&self, image: &Image) {
+        for key in [&image.storage_key, &image.thumbnail_key] {
+            delete_best_effort(self.storage.as_ref(), key).await;
+        }
+    }
+}
@@ -0,0 +1,343 @@
+//! Validation shared by questionnaire editing and table request submission.
+
+use domain::entities::{ApplicationAnswer, NewTableQuestion, QuestionKind, TableQuestion};
+use shared::Result;
+use shared::error::{ApplicationError, Error};
+
+pub const MAX_QUESTIONS: usize = 20;
+pub const MAX_PROMPT_LEN: usize = 300;
+pub const MAX_OPTIONS: usize = 20;
+pub const MAX_OPTION_LEN: usize = 100;
+pub const MAX_TEXT_ANSWER_LEN: usize = 2000;
+
+pub fn normalize_questions(questions: Vec<NewTableQuestion>) -> Result<Vec<NewTableQuestion>> {
+    if questions.len() > MAX_QUESTIONS {
+        return Err(invalid_input(format!(
+            "A questionnaire can have at most {} questions",
+            MAX_QUESTIONS
+        )));
+    }
+
+    questions
+        .into_iter()
+        .map(|question| {
+            let prompt = question.prompt.trim().to_string();
+
+            if prompt.is_empty() || prompt.chars().count() > MAX_PROMPT_LEN {
+                return Err(invalid_input(format!(
+                    "Question prompts must be between 1 and {} characters",
+                    MAX_PROMPT_LEN
+                )));
+            }
+
+            let mut options: Vec<String> = Vec::with_capacity(question.options.len());
+            for option in question.options {
+                let option = option.trim().to_string();
+                if option.is_empty() || option.chars().count() > MAX_OPTION_LEN {
+                    return Err(invalid_input(format!(
+                        "Options of '{}' must be between 1 and {} characters",
+                        prompt, MAX_OPTION_LEN
+                    )));
+                }
+                if options.contains(&option) {
+                    return Err(invalid_input(format!(
+                        "'{}' lists the option '{}' twice",
+                        prompt, option
+                    )));
+                }
+                options.push(option);
+            }
+
+            match question.kind {
+                QuestionKind::Text if !options.is_empty() => {
+                    return Err(invalid_input(format!(
+                        "Text question '{}' cannot have options",
+                        prompt
+                    )));
+                }
+                QuestionKind::SingleChoice | QuestionKind::MultiChoice
+                    if !(2..=MAX_OPTIONS).contains(&options.len()) =>
+                {
+                    return Err(invalid_input(format!(
+                        "Choice question '{}' needs between 2 and {} options",
+                        prompt, MAX_OPTIONS
+                    )));
+                }
+                _ => {}
+            }
+
+            Ok(NewTableQuestion {
+                prompt,
+                options,
+                ..question
+            })
+        })
+        .collect()
+}
+
+/// Checks submitted answers against the questionnaire and returns them in
+/// question order with prompts filled in. Blank answers are dropped.
+pub fn validate_answers(
+    questions: &[TableQuestion],
+    answers: &[ApplicationAnswer],
+) -> Result<Vec<ApplicationAnswer>> {
+    if let Some(unknown) = answers
+        .iter()
+        .find(|answer| !questions.iter().any(|q| q.id == answer.question_id))
+    {
+        return Err(invalid_input(format!(
+            "Question {} is not part of this table's questionnaire",
+            unknown.question_id
+        )));
+    }
+
+    let mut validated = Vec::with_capacity(questions.len());
+
+    for question in questions {
+        let mut matching = answers.iter().filter(|a| a.question_id == question.id);
+        let answer = matching.next();
+
+        if matching.next().is_some() {
+            return Err(invalid_input(format!(
+                "'{}' was answered more than once",
+                question.prompt
+            )));
+        }
+
+        let mut values: Vec<String> = Vec::new();
+        for value in answer.map(|a| a.values.as_slice()).unwrap_or_default() {
+            let value = value.trim().to_string();
+            if !value.is_empty() && !values.contains(&value) {
+                values.push(value);
+            }
+        }
+
+        if values.is_empty() {
+            if question.required {
+                return Err(invalid_input(format!("'{}' is required", question.prompt)));
+            }
+            continue;
+        }
+
+        match question.kind {
+            QuestionKind::Text => {
+                if values.len() > 1 || values[0].chars().count() > MAX_TEXT_ANSWER_LEN {
+                    return Err(invalid_input(format!(
+                        "'{}' takes a single answer of at most {} characters",
+                        question.prompt, MAX_TEXT_ANSWER_LEN
+                    )));
+                }
+            }
+            QuestionKind::SingleChoice | QuestionKind::MultiChoice => {
+                if question.kind == QuestionKind::SingleChoice && values.len() > 1 {
+                    return Err(invalid_input(format!(
+                        "'{}' takes a single choice",
+                        question.prompt
+                    )));
+                }
+
+                if let Some(value) = values.iter().find(|v| !question.options.contains(v)) {
+                    return Err(invalid_input(format!(
+                        "'{}' is not an option of '{}'",
+                        value, question.prompt
+                    )));
+                }
+            }
+        }
+
+        validated.push(ApplicationAnswer {
+            question_id: question.id,
+            prompt: question.prompt.clone(),
+            values,
+        });
+    }
+
+    Ok(validated)
+}
+
+fn invalid_input(message: String) -> Error {
+    Error::Application(ApplicationError::InvalidInput { message })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::slice;
+    use uuid::Uuid;
+
+    fn new_question(kind: QuestionKind, options: &[&str]) -> NewTableQuestion {
+        NewTableQuestion {
+            prompt: "  Which class?  ".to_string(),
+            kind,
+            options: options.iter().map(|option| option.to_string()).collect(),
+            required: true,
+        }
+    }
+
+    fn question(kind: QuestionKind, options: &[&str], required: bool) -> TableQuestion {
+        TableQuestion {
+            id: Uuid::now_v7(),
+            table_id: Uuid::now_v7(),
+            position: 0,
+            prompt: "Which class?".to_string(),
+            kind,
+            options: options.iter().map(|option| option.to_string()).collect(),
+            required,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn answer(question: &TableQuestion, values: &[&str]) -> ApplicationAnswer {
+        ApplicationAnswer {
+            question_id: question.id,
+            prompt: String::new(),
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_questions_trims_prompt_and_options() {
+        let normalized = normalize_questions(vec![new_question(
+            QuestionKind::SingleChoice,
+            &[" Fighter ", "Wizard"],
+        )])
+        .unwrap();
+
+        assert_eq!(normalized[0].prompt, "Which class?");
+        assert_eq!(normalized[0].options, vec!["Fighter", "Wizard"]);
+    }
+
+    #[test]
+    fn test_normalize_questions_rejects_too_many_questions() {
+        let questions = vec![new_question(QuestionKind::Text, &[]); MAX_QUESTIONS + 1];
+
+        assert!(normalize_questions(questions).is_err());
+    }
+
+    #[test]
+    fn test_normalize_questions_rejects_prompt_length() {
+        let mut blank = new_question(QuestionKind::Text, &[]);
+        blank.prompt = "   ".to_string();
+        let mut long = new_question(QuestionKind::Text, &[]);
+        long.prompt = "a".repeat(MAX_PROMPT_LEN + 1);
+
+        assert!(normalize_questions(vec![blank]).is_err());
+        assert!(normalize_questions(vec![long]).is_err());
+    }
+
+    #[test]
+    fn test_normalize_questions_rejects_bad_options() {
+        let long_option = "a".repeat(MAX_OPTION_LEN + 1);
+
+        for question in [
+            new_question(QuestionKind::Text, &["Yes"]),
+            new_question(QuestionKind::SingleChoice, &["Only"]),
+            new_question(QuestionKind::MultiChoice, &["Fighter", " Fighter"]),
+            new_question(QuestionKind::MultiChoice, &["Fighter", ""]),
+            new_question(QuestionKind::MultiChoice, &["Fighter", &long_option]),
+        ] {
+            assert!(normalize_questions(vec![question]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_answers_accepts_valid_answers_in_question_order() {
+        let text = question(QuestionKind::Text, &[], true);
+        let choice = question(QuestionKind::MultiChoice, &["Fighter", "Wizard"], true);
+
+        let validated = validate_answers(
+            &[text.clone(), choice.clone()],
+            &[
+                answer(&choice, &["Wizard", " Fighter", "Wizard"]),
+                answer(&text, &[" Hello "]),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(validated.len(), 2);
+        assert_eq!(validated[0].question_id, text.id);
+        assert_eq!(validated[0].prompt, text.prompt);
+        assert_eq!(validated[0].values, vec!["Hello"]);
+        assert_eq!(validated[1].values, vec!["Wizard", "Fighter"]);
+    }
+
+    #[test]
+    fn test_validate_answers_requires_required_questions() {
+        let required = question(QuestionKind::Text, &[], true);
+
+        assert!(validate_answers(slice::from_ref(&required), &[]).is_err());
+        assert!(
+            validate_answers(slice::from_ref(&required), &[answer(&required, &["  "])]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_answers_skips_blank_optional_answers() {
+        let optional = question(QuestionKind::Text, &[], false);
+
+        let validated =
+            validate_answers(slice::from_ref(&optional), &[answer(&optional, &[" "])]).unwrap();
+
+        assert!(validated.is_empty());
+    }
+
+    #[test]
+    fn test_validate_answers_rejects_unknown_question() {
+        let known = question(QuestionKind::Text, &[], false);
+        let unknown = question(QuestionKind::Text, &[], false);
+
+        assert!(validate_answers(&[known], &[answer(&unknown, &["Hello"])]).is_err());
+    }
+
+    #[test]
+    fn test_validate_answers_rejects_duplicate_answers() {
+        let text = question(QuestionKind::Text, &[], false);
+
+        assert!(
+            validate_answers(
+                slice::from_ref(&text),
+                &[answer(&text, &["One"]), answer(&text, &["Two"])]
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_answers_checks_choice_membership() {
+        let single = question(QuestionKind::SingleChoice, &["Fighter", "Wizard"], true);
+        let multi = question(QuestionKind::MultiChoice, &["Fighter", "Wizard"], true);
+
+        assert!(validate_answers(slice::from_ref(&single), &[answer(&single, &["Bard"])]).is_err());
+        assert!(
+            validate_answers(
+                slice::from_ref(&single),
+                &[answer(&single, &["Fighter", "Wizard"])]
+            )
+            .is_err()
+        );
+        assert!(
+            validate_answers(
+                slice::from_ref(&multi),
+                &[answer(&multi, &["Fighter", "Bard"])]
+            )
+            .is_err()
+        );
+        assert!(
+            validate_answers(slice::from_ref(&single), &[answer(&single, &["Wizard"])]).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_answers_limits_text_answers() {
+        let text = question(QuestionKind::Text, &[], true);
+        let long = "a".repeat(MAX_TEXT_ANSWER_LEN + 1);
+        let longest = "a".repeat(MAX_TEXT_ANSWER_LEN);
+
+        assert!(validate_answers(slice::from_ref(&text), &[answer(&text, &[&long])]).is_err());
+        assert!(
+            validate_answers(slice::from_ref(&text), &[answer(&text, &["One", "Two"])]).is_err()
+        );
+        assert!(validate_answers(slice::from_ref(&text), &[answer(&text, &[&longest])]).is_ok());
+    }
+}
@@ -0,0 +1,73 @@
+use domain::repositories::SearchRepository;
+use domain::search::*;
+use shared::Result;
+use shared::error::{ApplicationError, Error};
+use std::sync::Arc;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct SearchService {
+    search_repository: Arc<dyn SearchRepository>,
+}
+
+impl SearchService {
+    pub fn new(search_repository: Arc<dyn SearchRepository>) -> Self {
+        Self { search_repository }
+    }
+
+    #[instrument(name = "SearchService::search", level = "debug", skip_all)]
+    pub async fn search(&self, viewer_id: Uuid, query: SearchQuery) -> Result<SearchResponse> {
+        let terms = query.q.trim();
+
+        if terms.is_empty() {
+            return Err(invalid_input("Search query must not be empty"));
+        }
+
+        if terms.chars().count() > MAX_SEARCH_QUERY_LEN {
+            return Err(invalid_input(&format!(
+                "Search query must be at most {} characters",
+                MAX_SEARCH_QUERY_LEN
+            )));
+        }
+
+        let types = match query.r#type.as_deref() {
+            Some(value) => value
+                .split(',')
+                .filter(|part| !part.trim().is_empty())
+                .map(|part| part.parse::<SearchType>())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|message| invalid_input(&message))?,
+            None => Vec::new(),
+        };
+
+        let page = query.page.unwrap_or(1).max(1);
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_SEARCH_LIMIT)
+            .clamp(1, MAX_SEARCH_LIMIT);
+
+        let criteria = SearchCriteria {
+            terms: terms.to_string(),
+            viewer_id,
+            types,
+            limit,
+            offset: (page - 1).saturating_mul(limit),
+        };
+
+        let (results, total) = self.search_repository.search(criteria).await?;
+
+        Ok(SearchResponse {
+            results,
+            total,
+            page,
+            limit,
+        })
+    }
+}
+
+fn invalid_input(message: &str) -> Error {
+    Error::Application(ApplicationError::InvalidInput {
+        message: message.to_string(),
+    })
+}
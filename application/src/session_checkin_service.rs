@@ -3,6 +3,7 @@ use domain::repositories::SessionCheckinRepository;
 use shared::Result;
 use shared::error::{DomainError, Error};
 use std::sync::Arc;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -17,14 +18,17 @@ impl SessionCheckinService {
         }
     }
 
+    #[instrument(name = "SessionCheckinService::create", level = "debug", skip_all)]
     pub async fn create(&self, command: CreateSessionCheckinCommand) -> Result<SessionCheckin> {
         self.session_checkin_repository.create(command).await
     }
 
+    #[instrument(name = "SessionCheckinService::get", level = "debug", skip_all)]
     pub async fn get(&self, command: GetSessionCheckinCommand) -> Result<Vec<SessionCheckin>> {
         self.session_checkin_repository.read(command).await
     }
 
+    #[instrument(name = "SessionCheckinService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, id: &Uuid) -> Result<SessionCheckin> {
         let command = GetSessionCheckinCommand {
             id: Some(*id),
@@ -39,6 +43,11 @@ impl SessionCheckinService {
         })
     }
 
+    #[instrument(
+        name = "SessionCheckinService::find_by_session_intent_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_session_intent_id(
         &self,
         session_intent_id: &Uuid,
@@ -50,6 +59,11 @@ impl SessionCheckinService {
         self.session_checkin_repository.read(command).await
     }
 
+    #[instrument(
+        name = "SessionCheckinService::find_by_attendance",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_attendance(&self, attendance: bool) -> Result<Vec<SessionCheckin>> {
         let command = GetSessionCheckinCommand {
             attendance: Some(attendance),
@@ -58,10 +72,12 @@ impl SessionCheckinService {
         self.session_checkin_repository.read(command).await
     }
 
+    #[instrument(name = "SessionCheckinService::update", level = "debug", skip_all)]
     pub async fn update(&self, command: UpdateSessionCheckinCommand) -> Result<SessionCheckin> {
         self.session_checkin_repository.update(command).await
     }
 
+    #[instrument(name = "SessionCheckinService::delete", level = "debug", skip_all)]
     pub async fn delete(&self, command: DeleteSessionCheckinCommand) -> Result<SessionCheckin> {
         self.session_checkin_repository.delete(command).await
     }
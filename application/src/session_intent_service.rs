@@ -6,6 +6,7 @@ use shared::Result;
 use shared::error::DomainError;
 use shared::error::Error;
 use std::sync::Arc;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -31,6 +32,11 @@ impl SessionIntentService {
         }
     }
 
+    #[instrument(
+        name = "SessionIntentService::create_with_validation",
+        level = "debug",
+        skip_all
+    )]
     pub async fn create_with_validation(
         &self,
         user_id: Uuid,
@@ -69,10 +75,17 @@ impl SessionIntentService {
 
         let command = CreateSessionIntentCommand::new(user.id, session_id, status);
 
-        self.create(command).await?;
+        self.session_intent_repository
+            .create_with_event(command, table.id)
+            .await?;
         Ok(())
     }
 
+    #[instrument(
+        name = "SessionIntentService::get_for_session_with_validation",
+        level = "debug",
+        skip_all
+    )]
     pub async fn get_for_session_with_validation(
         &self,
         user_id: Uuid,
@@ -109,6 +122,11 @@ impl SessionIntentService {
         self.find_by_session_id(&session_id).await
     }
 
+    #[instrument(
+        name = "SessionIntentService::update_with_validation",
+        level = "debug",
+        skip_all
+    )]
     pub async fn update_with_validation(
         &self,
         user_id: Uuid,
@@ -137,23 +155,39 @@ impl SessionIntentService {
             }));
         }
 
+        // Only a changed status is worth telling the table about.
+        let notify_table = if status.is_some_and(|status| status != intent.intent_status) {
+            self.table_repository
+                .find_by_session_id(intent.session_id)
+                .await?
+                .map(|table| table.id)
+        } else {
+            None
+        };
+
         let command = UpdateSessionIntentCommand {
             id: intent_id,
             status,
         };
 
-        self.update(command).await?;
+        self.session_intent_repository
+            .update_with_event(command, notify_table)
+            .await?;
+
         Ok(())
     }
 
+    #[instrument(name = "SessionIntentService::create", level = "debug", skip_all)]
     pub async fn create(&self, command: CreateSessionIntentCommand) -> Result<SessionIntent> {
         self.session_intent_repository.create(command).await
     }
 
+    #[instrument(name = "SessionIntentService::get", level = "debug", skip_all)]
     pub async fn get(&self, command: GetSessionIntentCommand) -> Result<Vec<SessionIntent>> {
         self.session_intent_repository.read(command).await
     }
 
+    #[instrument(name = "SessionIntentService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, id: &Uuid) -> Result<Option<SessionIntent>> {
         let command = GetSessionIntentCommand {
             id: Some(*id),
@@ -163,10 +197,20 @@ impl SessionIntentService {
         Ok(session_intents.into_iter().next())
     }
 
+    #[instrument(
+        name = "SessionIntentService::find_intent_by_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_intent_by_id(&self, id: Uuid) -> Result<Option<SessionIntent>> {
         self.session_intent_repository.find_by_id(id).await
     }
 
+    #[instrument(
+        name = "SessionIntentService::find_by_user_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<SessionIntent>> {
         let command = GetSessionIntentCommand {
             user_id: Some(*user_id),
@@ -175,6 +219,11 @@ impl SessionIntentService {
         self.session_intent_repository.read(command).await
     }
 
+    #[instrument(
+        name = "SessionIntentService::find_by_session_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_session_id(&self, session_id: &Uuid) -> Result<Vec<SessionIntent>> {
         let command = GetSessionIntentCommand {
             session_id: Some(*session_id),
@@ -183,10 +232,12 @@ impl SessionIntentService {
         self.session_intent_repository.read(command).await
     }
 
+    #[instrument(name = "SessionIntentService::update", level = "debug", skip_all)]
     pub async fn update(&self, command: UpdateSessionIntentCommand) -> Result<SessionIntent> {
         self.session_intent_repository.update(command).await
     }
 
+    #[instrument(name = "SessionIntentService::delete", level = "debug", skip_all)]
     pub async fn delete(&self, command: DeleteSessionIntentCommand) -> Result<SessionIntent> {
         self.session_intent_repository.delete(command).await
     }
@@ -1,31 +1,48 @@
+use crate::pagination::ensure_cursor_supported;
 use chrono::{DateTime, Utc};
 use domain::entities::session_checkin::{
     SessionCheckinData, SessionFinalizationData, SessionFinalizationResult,
 };
 use domain::entities::*;
-use domain::repositories::{SessionRepository, TableRepository};
+use domain::repositories::{
+    Page, PaginationParams, SessionRepository, SortParams, TableRepository,
+};
+use metrics::counter;
 use shared::Result;
 use shared::error::{ApplicationError, DomainError, Error};
+use shared::metrics::SESSIONS_SCHEDULED_TOTAL;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct SessionService {
     session_repository: Arc<dyn SessionRepository>,
     table_repository: Arc<dyn TableRepository>,
+    deletion_grace_period: Duration,
 }
 
 impl SessionService {
     pub fn new(
         session_repository: Arc<dyn SessionRepository>,
         table_repository: Arc<dyn TableRepository>,
+        deletion_grace_period: Duration,
     ) -> Self {
         Self {
             session_repository,
             table_repository,
+            deletion_grace_period,
         }
     }
 
+    fn audit(gm_id: Uuid, action: AuditAction, before: &Session) -> CreateAuditLogCommand {
+        CreateAuditLogCommand::new(Some(gm_id), action, AuditEntityType::Session, before.id)
+            .with_table(before.table_id)
+            .with_before(before)
+    }
+
+    #[instrument(name = "SessionService::schedule_session", level = "debug", skip_all)]
     pub async fn schedule_session(
         &self,
         gm_id: Uuid,
@@ -44,9 +61,20 @@ impl SessionService {
             }));
         }
 
-        self.session_repository.create(command).await
+        let session = self
+            .session_repository
+            .create_with_event(command, UserEventKind::SessionScheduled)
+            .await?;
+        counter!(SESSIONS_SCHEDULED_TOTAL).increment(1);
+
+        Ok(session)
     }
 
+    #[instrument(
+        name = "SessionService::update_session_with_validation",
+        level = "debug",
+        skip_all
+    )]
     pub async fn update_session_with_validation(
         &self,
         gm_id: Uuid,
@@ -73,6 +101,19 @@ impl SessionService {
 
         let session = self.find_by_id(&session_id).await?;
 
+        // Decided up front so the event is recorded with the update.
+        let event = match status {
+            Some(status) if status != session.status => match status {
+                SessionStatus::InProgress => Some(UserEventKind::SessionStarted),
+                SessionStatus::Completed => Some(UserEventKind::SessionCompleted),
+                _ => None,
+            },
+            _ if scheduled_for.is_some_and(|at| Some(at) != session.scheduled_for) => {
+                Some(UserEventKind::SessionScheduled)
+            }
+            _ => None,
+        };
+
         let command = UpdateSessionCommand {
             id: session.id,
             title,
@@ -81,9 +122,18 @@ impl SessionService {
             status,
         };
 
-        self.update(command).await
+        let audit = Self::audit(gm_id, AuditAction::SessionUpdated, &session);
+
+        self.session_repository
+            .update_with_audit(command, audit, event)
+            .await
     }
 
+    #[instrument(
+        name = "SessionService::delete_session_with_validation",
+        level = "debug",
+        skip_all
+    )]
     pub async fn delete_session_with_validation(
         &self,
         gm_id: Uuid,
@@ -102,14 +152,69 @@ impl SessionService {
             return Err(Error::Application(ApplicationError::InvalidCredentials));
         }
 
-        self.delete(DeleteSessionCommand { id: session_id }).await?;
+        let session = self.find_by_id(&session_id).await?;
+        let audit = Self::audit(gm_id, AuditAction::SessionDeleted, &session);
+
+        self.session_repository
+            .soft_delete(session_id, audit)
+            .await?;
         Ok(())
     }
 
+    #[instrument(name = "SessionService::restore_session", level = "debug", skip_all)]
+    pub async fn restore_session(&self, gm_id: Uuid, session_id: Uuid) -> Result<Session> {
+        let deleted_since = Utc::now() - self.deletion_grace_period;
+
+        let session = self
+            .session_repository
+            .find_deleted_by_id(session_id, deleted_since)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "Session",
+                    entity_id: session_id.to_string(),
+                })
+            })?;
+
+        let table = self
+            .table_repository
+            .find_by_id(session.table_id)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::BusinessRuleViolation {
+                    message: "The table of this session was deleted; restore the table instead"
+                        .to_string(),
+                })
+            })?;
+
+        if table.gm_id != gm_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        let audit = CreateAuditLogCommand::new(
+            Some(gm_id),
+            AuditAction::SessionRestored,
+            AuditEntityType::Session,
+            session.id,
+        )
+        .with_table(session.table_id);
+
+        self.session_repository
+            .restore(session_id, deleted_since, audit)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::BusinessRuleViolation {
+                    message: "Session can no longer be restored".to_string(),
+                })
+            })
+    }
+
+    #[instrument(name = "SessionService::get", level = "debug", skip_all)]
     pub async fn get(&self, command: GetSessionCommand) -> Result<Vec<Session>> {
         self.session_repository.read(command).await
     }
 
+    #[instrument(name = "SessionService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, id: &Uuid) -> Result<Session> {
         let command = GetSessionCommand {
             id: Some(*id),
@@ -124,14 +229,17 @@ impl SessionService {
         })
     }
 
+    #[instrument(name = "SessionService::update", level = "debug", skip_all)]
     pub async fn update(&self, command: UpdateSessionCommand) -> Result<Session> {
         self.session_repository.update(command).await
     }
 
+    #[instrument(name = "SessionService::delete", level = "debug", skip_all)]
     pub async fn delete(&self, command: DeleteSessionCommand) -> Result<Session> {
         self.session_repository.delete(command).await
     }
 
+    #[instrument(name = "SessionService::start_session", level = "debug", skip_all)]
     pub async fn start_session(&self, gm_id: Uuid, session_id: Uuid) -> Result<Session> {
         let table = match self.table_repository.find_by_session_id(session_id).await? {
             Some(table) => table,
@@ -147,17 +255,16 @@ impl SessionService {
             return Err(Error::Application(ApplicationError::InvalidCredentials));
         }
 
-        if self
+        let session = self
             .session_repository
             .find_by_id(session_id)
             .await?
-            .is_none()
-        {
-            return Err(Error::Domain(DomainError::EntityNotFound {
-                entity_type: "session",
-                entity_id: session_id.to_string(),
-            }));
-        };
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "session",
+                    entity_id: session_id.to_string(),
+                })
+            })?;
 
         let update_command = UpdateSessionCommand {
             id: session_id,
@@ -167,9 +274,18 @@ impl SessionService {
             status: Some(SessionStatus::InProgress),
         };
 
-        self.session_repository.update(update_command).await
+        let audit = Self::audit(gm_id, AuditAction::SessionUpdated, &session);
+
+        self.session_repository
+            .update_with_audit(update_command, audit, Some(UserEventKind::SessionStarted))
+            .await
     }
 
+    #[instrument(
+        name = "SessionService::finalize_session_with_checkins",
+        level = "debug",
+        skip_all
+    )]
     pub async fn finalize_session_with_checkins(
         &self,
         gm_id: Uuid,
@@ -209,25 +325,21 @@ impl SessionService {
             checkins,
         };
 
-        let result = self
-            .session_repository
-            .finalize_session_with_checkins(finalization_data)
-            .await?;
-
-        let update_command = UpdateSessionCommand {
-            id: session_id,
-            title: None,
-            description: None,
-            scheduled_for: None,
-            status: Some(SessionStatus::Completed),
-        };
+        let audit = Self::audit(gm_id, AuditAction::SessionUpdated, &session);
 
-        self.session_repository.update(update_command).await?;
-
-        Ok(result)
+        self.session_repository
+            .finalize_session_with_checkins(finalization_data, audit)
+            .await
     }
 
-    pub async fn get_table_sessions(&self, table_id: Uuid, user_id: Uuid) -> Result<Vec<Session>> {
+    #[instrument(name = "SessionService::get_table_sessions", level = "debug", skip_all)]
+    pub async fn get_table_sessions(
+        &self,
+        table_id: Uuid,
+        user_id: Uuid,
+        sort: SortParams<SessionSortField>,
+        page: PaginationParams,
+    ) -> Result<Page<Session>> {
         let table = self
             .table_repository
             .find_by_id(table_id)
@@ -243,14 +355,29 @@ impl SessionService {
             return Err(Error::Application(ApplicationError::InvalidCredentials));
         }
 
+        let keyset = sort.field == SessionSortField::CreatedAt;
+        ensure_cursor_supported(&page, keyset)?;
+
         let command = GetSessionCommand {
             table_id: Some(table_id),
+            sort,
+            page: Some(page.clone()),
             ..Default::default()
         };
 
-        self.session_repository.read(command).await
+        let total = self.session_repository.count(command.clone()).await?;
+        let sessions = self.session_repository.read(command).await?;
+
+        Ok(Page::new(sessions, total, &page, keyset, |session| {
+            session.id
+        }))
     }
 
+    #[instrument(
+        name = "SessionService::create_session_for_table",
+        level = "debug",
+        skip_all
+    )]
     pub async fn create_session_for_table(
         &self,
         user_id: Uuid,
@@ -0,0 +1,44 @@
+use domain::entities::Table;
+use domain::repositories::{TableMemberRepository, TableRepository};
+use shared::Result;
+use shared::error::{ApplicationError, DomainError, Error};
+use uuid::Uuid;
+
+/// Looks up a table the user is allowed to know about. Private tables are
+/// reported as missing to outsiders.
+pub(crate) async fn find_visible_table(
+    table_repository: &dyn TableRepository,
+    user_id: Uuid,
+    table_id: Uuid,
+) -> Result<Table> {
+    table_repository
+        .find_visible_by_id(table_id, user_id)
+        .await?
+        .ok_or_else(|| {
+            Error::Domain(DomainError::EntityNotFound {
+                entity_type: "Table",
+                entity_id: table_id.to_string(),
+            })
+        })
+}
+
+/// Fails with `Forbidden` unless the user runs or plays at the table.
+pub(crate) async fn ensure_table_member(
+    table_member_repository: &dyn TableMemberRepository,
+    user_id: Uuid,
+    table: &Table,
+) -> Result<()> {
+    if table.gm_id == user_id {
+        return Ok(());
+    }
+
+    let membership = table_member_repository
+        .find_by_table_and_user(table.id, user_id)
+        .await?;
+
+    if membership.is_none() {
+        return Err(Error::Application(ApplicationError::Forbidden));
+    }
+
+    Ok(())
+}
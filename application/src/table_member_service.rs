@@ -1,30 +1,42 @@
+use crate::table_access::find_visible_table;
 use domain::entities::*;
-use domain::repositories::TableMemberRepository;
+use domain::repositories::{
+    Page, PaginationParams, SortOrder, TableMemberRepository, TableRepository,
+};
 use shared::Result;
 use shared::error::{DomainError, Error};
 use std::sync::Arc;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct TableMemberService {
     table_member_repository: Arc<dyn TableMemberRepository>,
+    table_repository: Arc<dyn TableRepository>,
 }
 
 impl TableMemberService {
-    pub fn new(table_member_repository: Arc<dyn TableMemberRepository>) -> Self {
+    pub fn new(
+        table_member_repository: Arc<dyn TableMemberRepository>,
+        table_repository: Arc<dyn TableRepository>,
+    ) -> Self {
         Self {
             table_member_repository,
+            table_repository,
         }
     }
 
+    #[instrument(name = "TableMemberService::create", level = "debug", skip_all)]
     pub async fn create(&self, command: CreateTableMemberCommand) -> Result<TableMember> {
         self.table_member_repository.create(command).await
     }
 
+    #[instrument(name = "TableMemberService::get", level = "debug", skip_all)]
     pub async fn get(&self, command: GetTableMemberCommand) -> Result<Vec<TableMember>> {
         self.table_member_repository.read(command).await
     }
 
+    #[instrument(name = "TableMemberService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, id: &Uuid) -> Result<TableMember> {
         let command = GetTableMemberCommand {
             id: Some(*id),
@@ -39,6 +51,11 @@ impl TableMemberService {
         })
     }
 
+    #[instrument(
+        name = "TableMemberService::find_by_table_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_table_id(&self, table_id: &Uuid) -> Result<Vec<TableMember>> {
         let command = GetTableMemberCommand {
             table_id: Some(*table_id),
@@ -47,6 +64,40 @@ impl TableMemberService {
         self.table_member_repository.read(command).await
     }
 
+    /// Members are ordered by id, so any page can be resumed with a cursor.
+    /// Private tables are reported as missing to outsiders.
+    #[instrument(
+        name = "TableMemberService::list_by_table_id",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn list_by_table_id(
+        &self,
+        viewer_id: Uuid,
+        table_id: Uuid,
+        sort_order: SortOrder,
+        page: PaginationParams,
+    ) -> Result<Page<TableMember>> {
+        find_visible_table(self.table_repository.as_ref(), viewer_id, table_id).await?;
+
+        let command = GetTableMemberCommand {
+            table_id: Some(table_id),
+            sort_order,
+            page: Some(page.clone()),
+            ..Default::default()
+        };
+
+        let total = self.table_member_repository.count(command.clone()).await?;
+        let members = self.table_member_repository.read(command).await?;
+
+        Ok(Page::new(members, total, &page, true, |member| member.id))
+    }
+
+    #[instrument(
+        name = "TableMemberService::find_by_user_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<TableMember>> {
         let command = GetTableMemberCommand {
             user_id: Some(*user_id),
@@ -55,10 +106,12 @@ impl TableMemberService {
         self.table_member_repository.read(command).await
     }
 
+    #[instrument(name = "TableMemberService::update", level = "debug", skip_all)]
     pub async fn update(&self, command: UpdateTableMemberCommand) -> Result<TableMember> {
         self.table_member_repository.update(command).await
     }
 
+    #[instrument(name = "TableMemberService::delete", level = "debug", skip_all)]
     pub async fn delete(&self, command: DeleteTableMemberCommand) -> Result<TableMember> {
         self.table_member_repository.delete(command).await
     }
@@ -1,10 +1,18 @@
+use crate::pagination::ensure_cursor_supported;
+use crate::questionnaire::validate_answers;
 use domain::entities::*;
 use domain::repositories::{
-    SessionRepository, TableMemberRepository, TableRepository, TableRequestRepository,
+    Page, PaginationParams, SessionRepository, SortParams, TableMemberRepository,
+    TableQuestionRepository, TableRepository, TableRequestRepository,
 };
+use metrics::counter;
 use shared::Result;
 use shared::error::{DomainError, Error};
+use shared::metrics::{
+    TABLE_REQUESTS_ACCEPTED_TOTAL, TABLE_REQUESTS_CREATED_TOTAL, TABLE_REQUESTS_REJECTED_TOTAL,
+};
 use std::sync::Arc;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -13,6 +21,7 @@ pub struct TableRequestService {
     table_repository: Arc<dyn TableRepository>,
     table_member_repository: Arc<dyn TableMemberRepository>,
     session_repository: Arc<dyn SessionRepository>,
+    table_question_repository: Arc<dyn TableQuestionRepository>,
 }
 
 impl TableRequestService {
@@ -21,17 +30,23 @@ impl TableRequestService {
         table_repository: Arc<dyn TableRepository>,
         table_member_repository: Arc<dyn TableMemberRepository>,
         session_repository: Arc<dyn SessionRepository>,
+        table_question_repository: Arc<dyn TableQuestionRepository>,
     ) -> Self {
         Self {
             table_request_repository,
             table_repository,
             table_member_repository,
             session_repository,
+            table_question_repository,
         }
     }
 
-    pub async fn create(&self, command: CreateTableRequestCommand) -> Result<TableRequest> {
-        let table = self.table_repository.find_by_id(command.table_id).await?;
+    #[instrument(name = "TableRequestService::create", level = "debug", skip_all)]
+    pub async fn create(&self, mut command: CreateTableRequestCommand) -> Result<TableRequest> {
+        let table = self
+            .table_repository
+            .find_visible_by_id(command.table_id, command.user_id)
+            .await?;
         if let Some(table) = table {
             if table.gm_id == command.user_id {
                 return Err(Error::Domain(DomainError::BusinessRuleViolation {
@@ -69,13 +84,23 @@ impl TableRequestService {
             }));
         }
 
-        self.table_request_repository.create(command).await
+        let questions = self
+            .table_question_repository
+            .find_by_table_id(command.table_id)
+            .await?;
+        command.answers = validate_answers(&questions, &command.answers)?;
+
+        let request = self.table_request_repository.create(command).await?;
+        counter!(TABLE_REQUESTS_CREATED_TOTAL).increment(1);
+        Ok(request)
     }
 
+    #[instrument(name = "TableRequestService::get", level = "debug", skip_all)]
     pub async fn get(&self, command: GetTableRequestCommand) -> Result<Vec<TableRequest>> {
         self.table_request_repository.read(command).await
     }
 
+    #[instrument(name = "TableRequestService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, id: &Uuid) -> Result<TableRequest> {
         let command = GetTableRequestCommand {
             id: Some(*id),
@@ -90,6 +115,11 @@ impl TableRequestService {
         })
     }
 
+    #[instrument(
+        name = "TableRequestService::find_by_user_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_user_id(&self, user_id: &Uuid) -> Result<Vec<TableRequest>> {
         let command = GetTableRequestCommand {
             user_id: Some(*user_id),
@@ -98,6 +128,11 @@ impl TableRequestService {
         self.table_request_repository.read(command).await
     }
 
+    #[instrument(
+        name = "TableRequestService::find_by_table_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_table_id(&self, table_id: &Uuid) -> Result<Vec<TableRequest>> {
         let command = GetTableRequestCommand {
             table_id: Some(*table_id),
@@ -106,6 +141,11 @@ impl TableRequestService {
         self.table_request_repository.read(command).await
     }
 
+    #[instrument(
+        name = "TableRequestService::find_by_status",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_by_status(&self, status: &TableRequestStatus) -> Result<Vec<TableRequest>> {
         let command = GetTableRequestCommand {
             status: Some(*status),
@@ -114,98 +154,98 @@ impl TableRequestService {
         self.table_request_repository.read(command).await
     }
 
+    #[instrument(name = "TableRequestService::update", level = "debug", skip_all)]
     pub async fn update(&self, command: UpdateTableRequestCommand) -> Result<TableRequest> {
         self.table_request_repository.update(command).await
     }
 
+    #[instrument(name = "TableRequestService::delete", level = "debug", skip_all)]
     pub async fn delete(&self, command: DeleteTableRequestCommand) -> Result<TableRequest> {
         self.table_request_repository.delete(command).await
     }
 
+    #[instrument(
+        name = "TableRequestService::accept_request",
+        level = "debug",
+        skip_all
+    )]
     pub async fn accept_request(&self, request_id: Uuid, requester_id: Uuid) -> Result<()> {
-        let session = self
-            .session_repository
-            .find_by_id(request_id)
-            .await?
-            .ok_or_else(|| {
-                Error::Domain(DomainError::EntityNotFound {
-                    entity_type: "Session",
-                    entity_id: request_id.to_string(),
-                })
-            })?;
-
+        let request = self.find_by_id(&request_id).await?;
         let table = self
-            .table_repository
-            .find_by_id(session.table_id)
-            .await?
-            .ok_or_else(|| {
-                Error::Domain(DomainError::EntityNotFound {
-                    entity_type: "Table",
-                    entity_id: session.table_id.to_string(),
-                })
-            })?;
+            .find_table_for_gm(request.table_id, requester_id)
+            .await?;
 
-        if table.gm_id != requester_id {
-            return Err(Error::Domain(DomainError::BusinessRuleViolation {
-                message: "invalid credentials".to_owned(),
-            }));
-        }
+        let member = CreateTableMemberCommand::new(table.id, request.user_id);
+        let audit = Self::audit(requester_id, AuditAction::RequestApproved, &request);
+        self.table_request_repository
+            .approve(request_id, member, audit)
+            .await?;
 
-        let request = self.find_by_id(&request_id).await?;
-        let requested_member_id = request.user_id;
+        counter!(TABLE_REQUESTS_ACCEPTED_TOTAL).increment(1);
+        Ok(())
+    }
 
-        let command = CreateTableMemberCommand::new(table.id, requested_member_id);
-        self.table_member_repository.create(command).await?;
+    #[instrument(
+        name = "TableRequestService::reject_request",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn reject_request(&self, request_id: Uuid, requester_id: Uuid) -> Result<()> {
+        let request = self.find_by_id(&request_id).await?;
+        self.find_table_for_gm(request.table_id, requester_id)
+            .await?;
 
         let command = UpdateTableRequestCommand {
             id: request_id,
-            status: Some(TableRequestStatus::Approved),
+            status: Some(TableRequestStatus::Rejected),
             message: None,
         };
 
-        self.table_request_repository.update(command).await?;
+        let audit = Self::audit(requester_id, AuditAction::RequestRejected, &request);
+        self.table_request_repository
+            .update_with_audit(command, audit)
+            .await?;
+        counter!(TABLE_REQUESTS_REJECTED_TOTAL).increment(1);
         Ok(())
     }
 
-    pub async fn reject_request(&self, request_id: Uuid, requester_id: Uuid) -> Result<()> {
-        let session = self
-            .session_repository
-            .find_by_id(request_id)
-            .await?
-            .ok_or_else(|| {
-                Error::Domain(DomainError::EntityNotFound {
-                    entity_type: "Session",
-                    entity_id: request_id.to_string(),
-                })
-            })?;
-
+    async fn find_table_for_gm(&self, table_id: Uuid, gm_id: Uuid) -> Result<Table> {
         let table = self
             .table_repository
-            .find_by_id(session.table_id)
+            .find_by_id(table_id)
             .await?
             .ok_or_else(|| {
                 Error::Domain(DomainError::EntityNotFound {
                     entity_type: "Table",
-                    entity_id: session.table_id.to_string(),
+                    entity_id: table_id.to_string(),
                 })
             })?;
 
-        if table.gm_id != requester_id {
+        if table.gm_id != gm_id {
             return Err(Error::Domain(DomainError::BusinessRuleViolation {
                 message: "invalid credentials".to_owned(),
             }));
         }
 
-        let command = UpdateTableRequestCommand {
-            id: request_id,
-            status: Some(TableRequestStatus::Rejected),
-            message: None,
-        };
+        Ok(table)
+    }
 
-        self.table_request_repository.update(command).await?;
-        Ok(())
+    fn audit(gm_id: Uuid, action: AuditAction, before: &TableRequest) -> CreateAuditLogCommand {
+        CreateAuditLogCommand::new(
+            Some(gm_id),
+            action,
+            AuditEntityType::TableRequest,
+            before.id,
+        )
+        .with_table(before.table_id)
+        .with_before(before)
     }
 
+    #[instrument(
+        name = "TableRequestService::cancel_request",
+        level = "debug",
+        skip_all
+    )]
     pub async fn cancel_request(&self, request_id: Uuid, requester_id: Uuid) -> Result<()> {
         let session = self
             .session_repository
@@ -238,11 +278,32 @@ impl TableRequestService {
         Ok(())
     }
 
-    pub async fn get_sent_requests(&self, user_id: Uuid) -> Result<Vec<TableRequest>> {
-        let requests = self.find_by_user_id(&user_id).await?;
-        Ok(requests
-            .into_iter()
-            .filter(|request| request.user_id == user_id)
-            .collect())
+    #[instrument(
+        name = "TableRequestService::get_sent_requests",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn get_sent_requests(
+        &self,
+        user_id: Uuid,
+        sort: SortParams<TableRequestSortField>,
+        page: PaginationParams,
+    ) -> Result<Page<TableRequest>> {
+        let keyset = sort.field == TableRequestSortField::CreatedAt;
+        ensure_cursor_supported(&page, keyset)?;
+
+        let command = GetTableRequestCommand {
+            user_id: Some(user_id),
+            sort,
+            page: Some(page.clone()),
+            ..Default::default()
+        };
+
+        let total = self.table_request_repository.count(command.clone()).await?;
+        let requests = self.table_request_repository.read(command).await?;
+
+        Ok(Page::new(requests, total, &page, keyset, |request| {
+            request.id
+        }))
     }
 }
@@ -1,28 +1,55 @@
+use crate::pagination::ensure_cursor_supported;
+use crate::questionnaire::normalize_questions;
+use crate::table_access::find_visible_table;
+use chrono::Utc;
 use domain::entities::*;
-use domain::repositories::{TableRepository, TableRequestRepository};
+use domain::repositories::{
+    AuditLogRepository, Page, PaginationParams, SortParams, TableQuestionRepository,
+    TableRepository, TableRequestRepository, UserRepository,
+};
 use shared::Result;
-use shared::error::DomainError;
-use shared::error::Error;
+use shared::error::{ApplicationError, DomainError, Error};
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::instrument;
 use uuid::Uuid;
 
+pub const MAX_TABLE_TAGS: usize = 10;
+pub const MAX_TABLE_TAG_LEN: usize = 30;
+pub const MAX_VENUE_ADDRESS_LEN: usize = 200;
+pub const DEFAULT_SEARCH_RADIUS_KM: f64 = 25.0;
+pub const MAX_SEARCH_RADIUS_KM: f64 = 500.0;
+
 #[derive(Clone)]
 pub struct TableService {
     table_repository: Arc<dyn TableRepository>,
     table_request_repository: Arc<dyn TableRequestRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    audit_log_repository: Arc<dyn AuditLogRepository>,
+    table_question_repository: Arc<dyn TableQuestionRepository>,
+    deletion_grace_period: Duration,
 }
 
 impl TableService {
     pub fn new(
         table_repository: Arc<dyn TableRepository>,
         table_request_repository: Arc<dyn TableRequestRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        audit_log_repository: Arc<dyn AuditLogRepository>,
+        table_question_repository: Arc<dyn TableQuestionRepository>,
+        deletion_grace_period: Duration,
     ) -> Self {
         Self {
             table_repository,
             table_request_repository,
+            user_repository,
+            audit_log_repository,
+            table_question_repository,
+            deletion_grace_period,
         }
     }
 
+    #[instrument(name = "TableService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, table_id: Uuid) -> Result<Table> {
         let table = self.table_repository.find_by_id(table_id).await?;
         table.ok_or_else(|| {
@@ -33,18 +60,76 @@ impl TableService {
         })
     }
 
+    #[instrument(
+        name = "TableService::find_table_by_user_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_table_by_user_id(&self, user_id: Uuid) -> Result<Vec<Table>> {
         self.table_repository.find_by_user_id(user_id).await
     }
 
+    #[instrument(
+        name = "TableService::find_table_by_session_id",
+        level = "debug",
+        skip_all
+    )]
     pub async fn find_table_by_session_id(&self, session_id: Uuid) -> Result<Option<Table>> {
         self.table_repository.find_by_session_id(session_id).await
     }
 
-    pub async fn get_all_tables(&self) -> Result<Vec<Table>> {
-        self.table_repository.read(GetTableCommand::default()).await
+    #[instrument(name = "TableService::list_tables", level = "debug", skip_all)]
+    pub async fn list_tables(
+        &self,
+        viewer_id: Uuid,
+        mut command: GetTableCommand,
+    ) -> Result<Page<TableListing>> {
+        let page = command.page.clone().unwrap_or_default();
+        let keyset = command.sort.field == TableSortField::CreatedAt;
+        ensure_cursor_supported(&page, keyset)?;
+
+        command.page = Some(page.clone());
+        command.visible_to = Some(viewer_id);
+        command.tags = match command.tags {
+            Some(tags) => Some(normalize_tags(tags)?).filter(|tags| !tags.is_empty()),
+            None => None,
+        };
+        command.language = normalize_language(command.language)?;
+
+        match command.near {
+            Some(point) => {
+                validate_coordinates(point.latitude, point.longitude)?;
+
+                let radius = command.radius_km.unwrap_or(DEFAULT_SEARCH_RADIUS_KM);
+                if !(radius > 0.0 && radius <= MAX_SEARCH_RADIUS_KM) {
+                    return Err(Error::Application(ApplicationError::InvalidInput {
+                        message: format!(
+                            "Search radius must be between 0 and {} km",
+                            MAX_SEARCH_RADIUS_KM
+                        ),
+                    }));
+                }
+                command.radius_km = Some(radius);
+            }
+            None if command.radius_km.is_some()
+                || command.sort.field == TableSortField::Distance =>
+            {
+                return Err(Error::Application(ApplicationError::InvalidInput {
+                    message: "Searching by distance requires a latitude and longitude".to_string(),
+                }));
+            }
+            None => {}
+        }
+
+        let total = self.table_repository.count(command.clone()).await?;
+        let listings = self.table_repository.list(command).await?;
+
+        Ok(Page::new(listings, total, &page, keyset, |listing| {
+            listing.table.id
+        }))
     }
 
+    #[instrument(name = "TableService::get_table_details", level = "debug", skip_all)]
     pub async fn get_table_details(
         &self,
         table_id: Uuid,
@@ -52,33 +137,294 @@ impl TableService {
     ) -> Result<Option<TableDetails>> {
         let table_details = self.table_repository.find_details_by_id(table_id).await?;
 
-        Ok(table_details)
+        Ok(table_details.filter(|details| {
+            details.visibility != TableVisibility::Private
+                || details.gm_id == user_id
+                || details.players.iter().any(|player| player.id == user_id)
+        }))
     }
 
-    pub async fn update_table(&self, command: UpdateTableCommand) -> Result<Table> {
-        self.table_repository.update(command).await
+    #[instrument(name = "TableService::update_table", level = "debug", skip_all)]
+    pub async fn update_table(
+        &self,
+        gm_id: Uuid,
+        mut command: UpdateTableCommand,
+    ) -> Result<Table> {
+        command.tags = command.tags.map(normalize_tags).transpose()?;
+        command.language = command.language.map(normalize_language).transpose()?;
+        command.location = command
+            .location
+            .map(|location| location.map(normalize_location).transpose())
+            .transpose()?;
+
+        let before = self.find_by_id(command.id).await?;
+
+        if before.gm_id != gm_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        let audit = CreateAuditLogCommand::new(
+            Some(gm_id),
+            AuditAction::TableUpdated,
+            AuditEntityType::Table,
+            before.id,
+        )
+        .with_table(before.id)
+        .with_before(&before);
+
+        self.table_repository
+            .update_with_audit(command, audit)
+            .await
     }
 
-    pub async fn get_table_requests(&self, table_id: Uuid) -> Result<Vec<TableRequest>> {
-        let requests = self
-            .table_request_repository
+    #[instrument(name = "TableService::get_table_requests", level = "debug", skip_all)]
+    pub async fn get_table_requests(
+        &self,
+        viewer_id: Uuid,
+        table_id: Uuid,
+        sort: SortParams<TableRequestSortField>,
+        page: PaginationParams,
+    ) -> Result<Page<TableRequest>> {
+        // Requests carry applicants' messages and questionnaire answers, which
+        // only the GM reviewing them may read.
+        let table = find_visible_table(self.table_repository.as_ref(), viewer_id, table_id).await?;
+
+        if table.gm_id != viewer_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        let keyset = sort.field == TableRequestSortField::CreatedAt;
+        ensure_cursor_supported(&page, keyset)?;
+
+        let command = GetTableRequestCommand {
+            table_id: Some(table_id),
+            sort,
+            page: Some(page.clone()),
+            ..Default::default()
+        };
+
+        let total = self.table_request_repository.count(command.clone()).await?;
+        let requests = self.table_request_repository.read(command).await?;
+
+        Ok(Page::new(requests, total, &page, keyset, |request| {
+            request.id
+        }))
+    }
+
+    #[instrument(name = "TableService::get_questionnaire", level = "debug", skip_all)]
+    pub async fn get_questionnaire(
+        &self,
+        viewer_id: Uuid,
+        table_id: Uuid,
+    ) -> Result<Vec<TableQuestion>> {
+        find_visible_table(self.table_repository.as_ref(), viewer_id, table_id).await?;
+
+        self.table_question_repository
             .find_by_table_id(table_id)
-            .await?;
+            .await
+    }
+
+    #[instrument(
+        name = "TableService::replace_questionnaire",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn replace_questionnaire(
+        &self,
+        gm_id: Uuid,
+        mut command: ReplaceTableQuestionsCommand,
+    ) -> Result<Vec<TableQuestion>> {
+        let table = self.find_by_id(command.table_id).await?;
+
+        if table.gm_id != gm_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
 
-        Ok(requests)
+        command.questions = normalize_questions(command.questions)?;
+
+        self.table_question_repository.replace(command).await
     }
 
-    pub async fn create_table(&self, command: CreateTableCommand) -> Result<Table> {
+    #[instrument(name = "TableService::create_table", level = "debug", skip_all)]
+    pub async fn create_table(&self, mut command: CreateTableCommand) -> Result<Table> {
+        command.tags = normalize_tags(command.tags)?;
+        command.language = normalize_language(command.language)?;
+        command.location = command.location.map(normalize_location).transpose()?;
+
         self.table_repository.create(command).await
     }
 
+    #[instrument(name = "TableService::delete_table", level = "debug", skip_all)]
     pub async fn delete_table(&self, table_id: Uuid, user_id: Uuid) -> Result<()> {
-        let command = DeleteTableCommand {
-            id: table_id,
-            gm_id: user_id,
-        };
+        let table = self.find_by_id(table_id).await?;
+
+        if table.gm_id != user_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        let audit = CreateAuditLogCommand::new(
+            Some(user_id),
+            AuditAction::TableDeleted,
+            AuditEntityType::Table,
+            table.id,
+        )
+        .with_table(table.id)
+        .with_before(&table);
+        self.table_repository.soft_delete(table_id, audit).await?;
 
-        self.table_repository.delete(command).await?;
         Ok(())
     }
+
+    /// Restores a table deleted within the grace period, together with the
+    /// sessions that were deleted along with it.
+    #[instrument(name = "TableService::restore_table", level = "debug", skip_all)]
+    pub async fn restore_table(&self, user_id: Uuid, table_id: Uuid) -> Result<Table> {
+        let deleted_since = Utc::now() - self.deletion_grace_period;
+
+        let table = self
+            .table_repository
+            .find_deleted_by_id(table_id, deleted_since)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::EntityNotFound {
+                    entity_type: "Table",
+                    entity_id: table_id.to_string(),
+                })
+            })?;
+
+        if table.gm_id != user_id {
+            return Err(Error::Application(ApplicationError::Forbidden));
+        }
+
+        let audit = CreateAuditLogCommand::new(
+            Some(user_id),
+            AuditAction::TableRestored,
+            AuditEntityType::Table,
+            table_id,
+        )
+        .with_table(table_id);
+
+        self.table_repository
+            .restore(table_id, deleted_since, audit)
+            .await?
+            .ok_or_else(|| {
+                Error::Domain(DomainError::BusinessRuleViolation {
+                    message: "Table can no longer be restored".to_string(),
+                })
+            })
+    }
+
+    /// Returns the audit history of a table. Only its GM and platform
+    /// administrators may read it.
+    #[instrument(name = "TableService::get_table_audit_log", level = "debug", skip_all)]
+    pub async fn get_table_audit_log(
+        &self,
+        user_id: Uuid,
+        table_id: Uuid,
+        command: GetAuditLogCommand,
+    ) -> Result<Vec<AuditLog>> {
+        let table = self.find_by_id(table_id).await?;
+
+        if table.gm_id != user_id {
+            let is_admin = self
+                .user_repository
+                .find_by_id(user_id)
+                .await?
+                .is_some_and(|user| user.is_admin());
+
+            if !is_admin {
+                return Err(Error::Application(ApplicationError::Forbidden));
+            }
+        }
+
+        let command = GetAuditLogCommand {
+            table_id: Some(table_id),
+            ..command
+        };
+
+        self.audit_log_repository.read(command).await
+    }
+}
+
+/// Lowercases, trims and de-duplicates tags so filtering is case-insensitive.
+fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+
+        if tag.is_empty() || normalized.contains(&tag) {
+            continue;
+        }
+
+        if tag.chars().count() > MAX_TABLE_TAG_LEN {
+            return Err(Error::Application(ApplicationError::InvalidInput {
+                message: format!("Tags must be at most {} characters", MAX_TABLE_TAG_LEN),
+            }));
+        }
+
+        normalized.push(tag);
+    }
+
+    if normalized.len() > MAX_TABLE_TAGS {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!("A table can have at most {} tags", MAX_TABLE_TAGS),
+        }));
+    }
+
+    Ok(normalized)
+}
+
+/// Accepts language tags such as `en` or `pt-br`.
+fn normalize_language(language: Option<String>) -> Result<Option<String>> {
+    let Some(language) = language.map(|l| l.trim().to_lowercase()) else {
+        return Ok(None);
+    };
+
+    if language.is_empty() {
+        return Ok(None);
+    }
+
+    let valid = (2..=10).contains(&language.len())
+        && language
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || c == '-');
+
+    if !valid {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!("'{}' is not a valid language code", language),
+        }));
+    }
+
+    Ok(Some(language))
+}
+
+fn normalize_location(location: TableLocation) -> Result<TableLocation> {
+    let address = location.address.trim().to_string();
+
+    if address.is_empty() || address.chars().count() > MAX_VENUE_ADDRESS_LEN {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: format!(
+                "Venue address must be between 1 and {} characters",
+                MAX_VENUE_ADDRESS_LEN
+            ),
+        }));
+    }
+
+    validate_coordinates(location.latitude, location.longitude)?;
+
+    Ok(TableLocation {
+        address,
+        ..location
+    })
+}
+
+fn validate_coordinates(latitude: f64, longitude: f64) -> Result<()> {
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(Error::Application(ApplicationError::InvalidInput {
+            message: "Latitude must be within ±90 and longitude within ±180".to_string(),
+        }));
+    }
+
+    Ok(())
 }
@@ -0,0 +1,63 @@
+use domain::entities::UserEvent;
+use domain::repositories::UserEventRepository;
+use shared::Result;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::instrument;
+use uuid::Uuid;
+
+pub const USER_EVENT_PAGE_SIZE: u32 = 100;
+const LOCAL_WAKEUP_BUFFER: usize = 1_024;
+
+/// Reads a user's event log for live streams. Events are written by the
+/// services where they happen; this instance learns about new ones through
+/// [`deliver`].
+///
+/// [`deliver`]: UserEventService::deliver
+#[derive(Clone)]
+pub struct UserEventService {
+    user_event_repository: Arc<dyn UserEventRepository>,
+    local_wakeups: broadcast::Sender<Uuid>,
+}
+
+impl UserEventService {
+    pub fn new(user_event_repository: Arc<dyn UserEventRepository>) -> Self {
+        let (local_wakeups, _) = broadcast::channel(LOCAL_WAKEUP_BUFFER);
+
+        Self {
+            user_event_repository,
+            local_wakeups,
+        }
+    }
+
+    /// The id a new stream starts after when the client has nothing to
+    /// resume from, so it only sees events from now on.
+    #[instrument(
+        name = "UserEventService::get_latest_event_id",
+        level = "debug",
+        skip_all
+    )]
+    pub async fn get_latest_event_id(&self, user_id: Uuid) -> Result<i64> {
+        let latest = self.user_event_repository.find_latest_id(user_id).await?;
+
+        Ok(latest.unwrap_or(0))
+    }
+
+    /// The next page of events after `after`, oldest first.
+    #[instrument(name = "UserEventService::get_events_after", level = "debug", skip_all)]
+    pub async fn get_events_after(&self, user_id: Uuid, after: i64) -> Result<Vec<UserEvent>> {
+        self.user_event_repository
+            .find_after(user_id, after, USER_EVENT_PAGE_SIZE)
+            .await
+    }
+
+    /// Yields the id of every user with new events; streams filter their own.
+    pub fn subscribe(&self) -> broadcast::Receiver<Uuid> {
+        self.local_wakeups.subscribe()
+    }
+
+    pub fn deliver(&self, user_id: Uuid) {
+        // Sending only fails when nobody is connected.
+        let _ = self.local_wakeups.send(user_id);
+    }
+}
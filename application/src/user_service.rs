@@ -1,3 +1,4 @@
+use chrono::Utc;
 use domain::auth::PasswordProvider;
 use domain::entities::*;
 use domain::repositories::UserRepository;
@@ -5,6 +6,8 @@ use shared::Result;
 use shared::error::Error;
 use shared::error::{ApplicationError, DomainError};
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Debug, Clone)]
@@ -25,31 +28,38 @@ pub struct ProfileResponse {
 pub struct UserService {
     user_repository: Arc<dyn UserRepository>,
     password_provider: Arc<dyn PasswordProvider>,
+    deletion_grace_period: Duration,
 }
 
 impl UserService {
     pub fn new(
         user_repository: Arc<dyn UserRepository>,
         password_provider: Arc<dyn PasswordProvider>,
+        deletion_grace_period: Duration,
     ) -> Self {
         Self {
             user_repository,
             password_provider,
+            deletion_grace_period,
         }
     }
 
+    #[instrument(name = "UserService::create", level = "debug", skip_all)]
     pub async fn create(&self, command: CreateUserCommand) -> Result<User> {
         self.user_repository.create(command).await
     }
 
+    #[instrument(name = "UserService::get", level = "debug", skip_all)]
     pub async fn get(&self, command: GetUserCommand) -> Result<Vec<User>> {
         self.user_repository.read(command).await
     }
 
+    #[instrument(name = "UserService::find_by_id", level = "debug", skip_all)]
     pub async fn find_by_id(&self, id: Uuid) -> Result<Option<User>> {
         self.user_repository.find_by_id(id).await
     }
 
+    #[instrument(name = "UserService::get_by_id", level = "debug", skip_all)]
     pub async fn get_by_id(&self, id: &Uuid) -> Result<User> {
         let users = self.user_repository.find_by_id(*id).await?;
         users.ok_or_else(|| {
@@ -60,14 +70,17 @@ impl UserService {
         })
     }
 
+    #[instrument(name = "UserService::update", level = "debug", skip_all)]
     pub async fn update(&self, command: UpdateUserCommand) -> Result<User> {
         self.user_repository.update(command).await
     }
 
+    #[instrument(name = "UserService::delete", level = "debug", skip_all)]
     pub async fn delete(&self, command: DeleteUserCommand) -> Result<User> {
         self.user_repository.delete(command).await
     }
 
+    #[instrument(name = "UserService::delete_account", level = "debug", skip_all)]
     pub async fn delete_account(&self, command: DeleteAccountCommand) -> Result<()> {
         let user = self
             .user_repository
@@ -88,15 +101,51 @@ impl UserService {
             return Err(Error::Application(ApplicationError::IncorrectPassword));
         }
 
-        let delete_command = DeleteUserCommand {
-            id: command.user_id,
-        };
-
-        self.user_repository.delete(delete_command).await?;
+        let audit = CreateAuditLogCommand::new(
+            Some(user.id),
+            AuditAction::AccountDeleted,
+            AuditEntityType::User,
+            user.id,
+        );
+        self.user_repository.soft_delete(user.id, audit).await?;
 
         Ok(())
     }
 
+    /// Brings back an account deleted within the grace period, together with
+    /// the tables and sessions that were hidden along with it.
+    #[instrument(name = "UserService::restore_account", level = "debug", skip_all)]
+    pub async fn restore_account(&self, email: &str, password: &str) -> Result<User> {
+        let deleted_since = Utc::now() - self.deletion_grace_period;
+
+        let user = self
+            .user_repository
+            .find_deleted_by_email(email, deleted_since)
+            .await?
+            .ok_or(Error::Application(ApplicationError::InvalidCredentials))?;
+
+        if !self
+            .password_provider
+            .verify_hash(password.to_string(), user.password.clone())
+            .await?
+        {
+            return Err(Error::Application(ApplicationError::InvalidCredentials));
+        }
+
+        let audit = CreateAuditLogCommand::new(
+            Some(user.id),
+            AuditAction::AccountRestored,
+            AuditEntityType::User,
+            user.id,
+        );
+
+        self.user_repository
+            .restore(user.id, deleted_since, audit)
+            .await?
+            .ok_or(Error::Application(ApplicationError::InvalidCredentials))
+    }
+
+    #[instrument(name = "UserService::get_user_profile", level = "debug", skip_all)]
     pub async fn get_user_profile(&self, user_id: Uuid) -> Result<ProfileResponse> {
         let user = self.find_by_id(user_id).await?.ok_or_else(|| {
             Error::Domain(DomainError::EntityNotFound {
@@ -113,6 +162,7 @@ impl UserService {
         })
     }
 
+    #[instrument(name = "UserService::update_profile", level = "debug", skip_all)]
     pub async fn update_profile(
         &self,
         user_id: Uuid,
@@ -123,6 +173,7 @@ impl UserService {
             username: command.username,
             email: command.email,
             password: None,
+            role: None,
         };
 
         let updated_user = self.update(update_command).await?;
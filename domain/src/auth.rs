@@ -48,6 +48,16 @@ pub struct RegisterCommand {
     pub password: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct MagicLinkRequestCommand {
+    pub email: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MagicLinkLoginCommand {
+    pub token: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct ChangePasswordCommand {
     pub current_password: String,
@@ -59,6 +69,8 @@ pub struct ChangePasswordCommand {
 pub trait AuthenticationService: Send + Sync {
     async fn login(&self, command: LoginCommand) -> Result<LoginResponse>;
     async fn register(&self, command: RegisterCommand) -> Result<LoginResponse>;
+    async fn request_magic_link(&self, command: MagicLinkRequestCommand) -> Result<()>;
+    async fn login_with_magic_link(&self, command: MagicLinkLoginCommand) -> Result<LoginResponse>;
     async fn refresh_token(&self, command: RefreshTokenCommand) -> Result<RefreshResponse>;
     async fn logout(&self, command: LogoutCommand) -> Result<()>;
     async fn validate_token(&self, token: &str) -> Result<Claims>;
@@ -0,0 +1,28 @@
+use crate::entities::ChatMessage;
+use serde::{Deserialize, Serialize};
+use shared::Result;
+use uuid::Uuid;
+
+/// Something that happened in a table's chat and must reach every
+/// connected member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatEvent {
+    Message(ChatMessage),
+    Typing { table_id: Uuid, user_id: Uuid },
+}
+
+impl ChatEvent {
+    pub fn table_id(&self) -> Uuid {
+        match self {
+            ChatEvent::Message(message) => message.table_id,
+            ChatEvent::Typing { table_id, .. } => *table_id,
+        }
+    }
+}
+
+/// Fans chat events out to every server instance, including this one.
+#[async_trait::async_trait]
+pub trait ChatPublisher: Send + Sync {
+    async fn publish(&self, event: &ChatEvent) -> Result<()>;
+}
@@ -0,0 +1,72 @@
+use crate::entities::{
+    SessionCheckin, SessionIntent, Table, TableMember, TableRequest, User, UserRole,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default)]
+pub enum ExportStatus {
+    #[default]
+    Pending,
+    Ready,
+    Failed,
+}
+
+/// A personal data export job. `payload` holds the serialized
+/// [`PersonalDataArchive`] once the job is ready.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AccountExport {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: ExportStatus,
+    pub payload: Option<Value>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl AccountExport {
+    pub fn is_ready(&self) -> bool {
+        self.status == ExportStatus::Ready
+    }
+}
+
+/// Profile data as exported to its owner. The password hash is never part
+/// of an export.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct PersonalProfile {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub role: UserRole,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<User> for PersonalProfile {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            username: user.username,
+            email: user.email,
+            role: user.role,
+            suspended_at: user.suspended_at,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PersonalDataArchive {
+    pub generated_at: DateTime<Utc>,
+    pub profile: PersonalProfile,
+    pub tables: Vec<Table>,
+    pub memberships: Vec<TableMember>,
+    pub table_requests: Vec<TableRequest>,
+    pub session_intents: Vec<SessionIntent>,
+    pub session_checkins: Vec<SessionCheckin>,
+}
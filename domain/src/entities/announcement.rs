@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A table-wide notice posted by the GM.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Announcement {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// An announcement as seen by one member of the table.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct AnnouncementListing {
+    pub announcement: Announcement,
+    pub comment_count: u32,
+    pub read_count: u32,
+    /// Whether the member asking has marked it as read.
+    pub read: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct AnnouncementReadReceipt {
+    pub user_id: Uuid,
+    pub username: String,
+    pub read_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct AnnouncementComment {
+    pub id: Uuid,
+    pub announcement_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
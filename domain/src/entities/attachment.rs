@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A file shared with a table. Attachments without a session are handouts
+/// for the whole table.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: u64,
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Storage used by a table's attachments against its quota.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct AttachmentUsage {
+    pub used_bytes: u64,
+    pub quota_bytes: u64,
+}
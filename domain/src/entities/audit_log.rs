@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, ToSchema)]
+pub enum AuditAction {
+    UserRegistered,
+    LoginSucceeded,
+    LoginFailed,
+    Logout,
+    PasswordChanged,
+    UserSuspended,
+    UserUnsuspended,
+    UserRoleChanged,
+    TableUpdated,
+    TableDeleted,
+    SessionUpdated,
+    SessionDeleted,
+    RequestApproved,
+    RequestRejected,
+    AccountDeleted,
+    AccountRestored,
+    TableRestored,
+    SessionRestored,
+    MagicLinkRequested,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, ToSchema)]
+pub enum AuditEntityType {
+    User,
+    Table,
+    Session,
+    TableRequest,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct AuditLog {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub entity_type: AuditEntityType,
+    pub entity_id: Uuid,
+    pub table_id: Option<Uuid>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
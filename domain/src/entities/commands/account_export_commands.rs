@@ -0,0 +1,27 @@
+use crate::entities::ExportStatus;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAccountExportCommand {
+    pub id: Uuid,
+    pub user_id: Uuid,
+}
+
+impl CreateAccountExportCommand {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            user_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompleteAccountExportCommand {
+    pub id: Uuid,
+    pub status: ExportStatus,
+    pub payload: Option<Value>,
+    pub error: Option<String>,
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAnnouncementCommand {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+}
+
+impl CreateAnnouncementCommand {
+    pub fn new(table_id: Uuid, author_id: Uuid, title: String, body: String) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            table_id,
+            author_id,
+            title,
+            body,
+            pinned: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAnnouncementCommand {
+    pub id: Uuid,
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub pinned: Option<bool>,
+}
+
+impl UpdateAnnouncementCommand {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            title: None,
+            body: None,
+            pinned: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAnnouncementCommentCommand {
+    pub id: Uuid,
+    pub announcement_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+}
+
+impl CreateAnnouncementCommentCommand {
+    pub fn new(announcement_id: Uuid, author_id: Uuid, body: String) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            announcement_id,
+            author_id,
+            body,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAttachmentCommand {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: u64,
+    pub storage_key: String,
+}
@@ -0,0 +1,65 @@
+use crate::entities::{AuditAction, AuditEntityType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAuditLogCommand {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub entity_type: AuditEntityType,
+    pub entity_id: Uuid,
+    pub table_id: Option<Uuid>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl CreateAuditLogCommand {
+    pub fn new(
+        actor_id: Option<Uuid>,
+        action: AuditAction,
+        entity_type: AuditEntityType,
+        entity_id: Uuid,
+    ) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            actor_id,
+            action,
+            entity_type,
+            entity_id,
+            table_id: None,
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn with_table(mut self, table_id: Uuid) -> Self {
+        self.table_id = Some(table_id);
+        self
+    }
+
+    pub fn with_before<T: Serialize>(mut self, before: &T) -> Self {
+        self.before = serde_json::to_value(before).ok();
+        self
+    }
+
+    pub fn with_after<T: Serialize>(mut self, after: &T) -> Self {
+        self.after = serde_json::to_value(after).ok();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GetAuditLogCommand {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+    pub entity_type: Option<AuditEntityType>,
+    pub entity_id: Option<Uuid>,
+    pub table_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
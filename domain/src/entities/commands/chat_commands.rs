@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateChatMessageCommand {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+}
+
+impl CreateChatMessageCommand {
+    pub fn new(table_id: Uuid, author_id: Uuid, body: String) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            table_id,
+            author_id,
+            body,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::entities::ImageKind;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Replaces any existing image of the same kind for the subject.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateImageCommand {
+    pub id: Uuid,
+    pub kind: ImageKind,
+    pub subject_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub content_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub size_bytes: u64,
+    pub storage_key: String,
+    pub thumbnail_key: String,
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateJournalEntryCommand {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    /// Filled in by the service before the entry is stored.
+    pub body_html: String,
+}
+
+impl CreateJournalEntryCommand {
+    pub fn new(
+        table_id: Uuid,
+        session_id: Option<Uuid>,
+        author_id: Uuid,
+        title: String,
+        body: String,
+    ) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            table_id,
+            session_id,
+            author_id,
+            title,
+            body,
+            body_html: String::new(),
+        }
+    }
+}
+
+/// Every update records a new revision, even if only the session link
+/// changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateJournalEntryCommand {
+    pub id: Uuid,
+    pub edited_by: Uuid,
+    pub title: Option<String>,
+    pub body: Option<String>,
+    /// Set by the service whenever `body` is.
+    pub body_html: Option<String>,
+    /// `Some(None)` unlinks the entry from its session.
+    pub session_id: Option<Option<Uuid>>,
+}
+
+impl UpdateJournalEntryCommand {
+    pub fn new(id: Uuid, edited_by: Uuid) -> Self {
+        Self {
+            id,
+            edited_by,
+            title: None,
+            body: None,
+            body_html: None,
+            session_id: None,
+        }
+    }
+}
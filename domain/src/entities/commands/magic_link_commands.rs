@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateMagicLinkTokenCommand {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl CreateMagicLinkTokenCommand {
+    pub fn new(user_id: Uuid, token_hash: String, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            user_id,
+            token_hash,
+            expires_at,
+        }
+    }
+}
@@ -1,19 +1,39 @@
+pub mod account_export_commands;
+pub mod announcement_commands;
+pub mod attachment_commands;
+pub mod audit_log_commands;
+pub mod chat_commands;
 pub mod game_system_commands;
+pub mod image_commands;
+pub mod journal_commands;
+pub mod magic_link_commands;
 pub mod refresh_token_commands;
 pub mod session_checkin_commands;
 pub mod session_commands;
 pub mod session_intent_commands;
 pub mod table_commands;
 pub mod table_member_commands;
+pub mod table_question_commands;
 pub mod table_request_commands;
 pub mod user_commands;
+pub mod user_event_commands;
 
+pub use account_export_commands::*;
+pub use announcement_commands::*;
+pub use attachment_commands::*;
+pub use audit_log_commands::*;
+pub use chat_commands::*;
 pub use game_system_commands::*;
+pub use image_commands::*;
+pub use journal_commands::*;
+pub use magic_link_commands::*;
 pub use refresh_token_commands::*;
 pub use session_checkin_commands::*;
 pub use session_commands::*;
 pub use session_intent_commands::*;
 pub use table_commands::*;
 pub use table_member_commands::*;
+pub use table_question_commands::*;
 pub use table_request_commands::*;
 pub use user_commands::*;
+pub use user_event_commands::*;
@@ -1,4 +1,5 @@
 use crate::entities::{SessionStatus, session_checkin::SessionCheckinData};
+use crate::repositories::{PaginationParams, SortParams};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -29,6 +30,28 @@ pub struct GetSessionCommand {
     pub status: Option<SessionStatus>,
     pub scheduled_before: Option<DateTime<Utc>>,
     pub scheduled_after: Option<DateTime<Utc>>,
+    pub sort: SortParams<SessionSortField>,
+    /// `None` returns every match and is meant for internal callers.
+    pub page: Option<PaginationParams>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SessionSortField {
+    /// Ordered by id, which follows creation time and allows cursors.
+    #[default]
+    CreatedAt,
+    ScheduledFor,
+    Title,
+}
+
+impl SessionSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionSortField::CreatedAt => "created_at",
+            SessionSortField::ScheduledFor => "scheduled_for",
+            SessionSortField::Title => "title",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -1,4 +1,7 @@
-use crate::entities::TableStatus;
+use crate::entities::{
+    AgeRating, GeoPoint, TableFormat, TableLocation, TableStatus, TableVisibility,
+};
+use crate::repositories::{PaginationParams, SortParams};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -11,6 +14,13 @@ pub struct CreateTableCommand {
     pub status: TableStatus,
     pub slots: u32,
     pub game_system_id: Uuid,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: TableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: AgeRating,
+    pub location: Option<TableLocation>,
+    pub visibility: TableVisibility,
 }
 
 impl CreateTableCommand {
@@ -29,6 +39,13 @@ impl CreateTableCommand {
             slots,
             status: TableStatus::default(),
             game_system_id,
+            tags: Vec::new(),
+            language: None,
+            format: TableFormat::default(),
+            beginner_friendly: false,
+            age_rating: AgeRating::default(),
+            location: None,
+            visibility: TableVisibility::default(),
         }
     }
 }
@@ -41,6 +58,56 @@ pub struct UpdateTableCommand {
     pub slots: Option<u32>,
     pub game_system_id: Option<Uuid>,
     pub status: Option<TableStatus>,
+    pub tags: Option<Vec<String>>,
+    pub language: Option<Option<String>>,
+    pub format: Option<TableFormat>,
+    pub beginner_friendly: Option<bool>,
+    pub age_rating: Option<AgeRating>,
+    /// `Some(None)` removes the location.
+    pub location: Option<Option<TableLocation>>,
+    pub visibility: Option<TableVisibility>,
+}
+
+impl UpdateTableCommand {
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            title: None,
+            description: None,
+            slots: None,
+            game_system_id: None,
+            status: None,
+            tags: None,
+            language: None,
+            format: None,
+            beginner_friendly: None,
+            age_rating: None,
+            location: None,
+            visibility: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TableSortField {
+    /// Ordered by id, which follows creation time and allows cursors.
+    #[default]
+    CreatedAt,
+    Title,
+    OpenSlots,
+    /// Only meaningful together with `GetTableCommand::near`.
+    Distance,
+}
+
+impl TableSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TableSortField::CreatedAt => "created_at",
+            TableSortField::Title => "title",
+            TableSortField::OpenSlots => "open_slots",
+            TableSortField::Distance => "distance",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -49,6 +116,22 @@ pub struct GetTableCommand {
     pub gm_id: Option<Uuid>,
     pub status: Option<TableStatus>,
     pub game_system_id: Option<Uuid>,
+    /// Tables must carry every listed tag.
+    pub tags: Option<Vec<String>>,
+    pub language: Option<String>,
+    pub format: Option<TableFormat>,
+    pub beginner_friendly: Option<bool>,
+    pub age_rating: Option<AgeRating>,
+    pub min_open_slots: Option<u32>,
+    /// Restricts the listing to tables within `radius_km` of this point.
+    pub near: Option<GeoPoint>,
+    pub radius_km: Option<f64>,
+    /// Restricts the listing to public tables plus those this user runs or
+    /// plays in. `None` skips the check and is meant for internal callers.
+    pub visible_to: Option<Uuid>,
+    pub sort: SortParams<TableSortField>,
+    /// `None` returns every match and is meant for internal callers.
+    pub page: Option<PaginationParams>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,3 +1,4 @@
+use crate::repositories::{PaginationParams, SortOrder};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -30,6 +31,10 @@ pub struct GetTableMemberCommand {
     pub id: Option<Uuid>,
     pub table_id: Option<Uuid>,
     pub user_id: Option<Uuid>,
+    /// Members are always listed in joining order.
+    pub sort_order: SortOrder,
+    /// `None` returns every match and is meant for internal callers.
+    pub page: Option<PaginationParams>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
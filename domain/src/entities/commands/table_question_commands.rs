@@ -0,0 +1,18 @@
+use crate::entities::QuestionKind;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewTableQuestion {
+    pub prompt: String,
+    pub kind: QuestionKind,
+    pub options: Vec<String>,
+    pub required: bool,
+}
+
+/// Replaces the whole questionnaire of a table; questions keep the order given.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplaceTableQuestionsCommand {
+    pub table_id: Uuid,
+    pub questions: Vec<NewTableQuestion>,
+}
@@ -1,4 +1,5 @@
-use crate::entities::TableRequestStatus;
+use crate::entities::{ApplicationAnswer, TableRequestStatus};
+use crate::repositories::{PaginationParams, SortParams};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -8,16 +9,24 @@ pub struct CreateTableRequestCommand {
     pub user_id: Uuid,
     pub table_id: Uuid,
     pub message: Option<String>,
+    /// Checked against the table's questionnaire before the request is stored.
+    pub answers: Vec<ApplicationAnswer>,
     pub status: TableRequestStatus,
 }
 
 impl CreateTableRequestCommand {
-    pub fn new(user_id: Uuid, table_id: Uuid, message: Option<String>) -> Self {
+    pub fn new(
+        user_id: Uuid,
+        table_id: Uuid,
+        message: Option<String>,
+        answers: Vec<ApplicationAnswer>,
+    ) -> Self {
         Self {
             id: Uuid::now_v7(),
             user_id,
             table_id,
             message,
+            answers,
             status: TableRequestStatus::Pending,
         }
     }
@@ -36,6 +45,26 @@ pub struct GetTableRequestCommand {
     pub user_id: Option<Uuid>,
     pub table_id: Option<Uuid>,
     pub status: Option<TableRequestStatus>,
+    pub sort: SortParams<TableRequestSortField>,
+    /// `None` returns every match and is meant for internal callers.
+    pub page: Option<PaginationParams>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TableRequestSortField {
+    /// Ordered by id, which follows creation time and allows cursors.
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl TableRequestSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TableRequestSortField::CreatedAt => "created_at",
+            TableRequestSortField::UpdatedAt => "updated_at",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
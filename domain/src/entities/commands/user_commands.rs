@@ -1,3 +1,5 @@
+use crate::entities::UserRole;
+use crate::repositories::PaginationParams;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -15,13 +17,19 @@ pub struct UpdateUserCommand {
     pub username: Option<String>,
     pub email: Option<String>,
     pub password: Option<String>,
+    pub role: Option<UserRole>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GetUserCommand {
     pub id: Option<Uuid>,
     pub username: Option<String>,
     pub email: Option<String>,
+    pub role: Option<UserRole>,
+    pub suspended: Option<bool>,
+    /// Users are listed newest first. `None` returns every match and is
+    /// meant for internal callers.
+    pub page: Option<PaginationParams>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
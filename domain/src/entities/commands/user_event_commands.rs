@@ -0,0 +1,36 @@
+use crate::entities::UserEventKind;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum UserEventRecipients {
+    User(Uuid),
+    /// The GM and every member of the table.
+    Table(Uuid),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUserEventCommand {
+    pub recipients: UserEventRecipients,
+    pub kind: UserEventKind,
+    pub payload: Value,
+}
+
+impl CreateUserEventCommand {
+    pub fn for_user<T: Serialize>(user_id: Uuid, kind: UserEventKind, payload: &T) -> Self {
+        Self {
+            recipients: UserEventRecipients::User(user_id),
+            kind,
+            payload: serde_json::to_value(payload).unwrap_or_default(),
+        }
+    }
+
+    pub fn for_table<T: Serialize>(table_id: Uuid, kind: UserEventKind, payload: &T) -> Self {
+        Self {
+            recipients: UserEventRecipients::Table(table_id),
+            kind,
+            payload: serde_json::to_value(payload).unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum ImageKind {
+    /// `subject_id` is a table id.
+    TableCover,
+    /// `subject_id` is a user id.
+    Avatar,
+}
+
+impl ImageKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageKind::TableCover => "covers",
+            ImageKind::Avatar => "avatars",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default)]
+pub enum ImageVariant {
+    #[default]
+    Original,
+    Thumbnail,
+}
+
+/// A processed upload. Each subject has at most one image per kind; the
+/// files themselves live in object storage under the two keys.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Image {
+    pub id: Uuid,
+    pub kind: ImageKind,
+    pub subject_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub content_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub size_bytes: u64,
+    pub storage_key: String,
+    pub thumbnail_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Image {
+    pub fn key(&self, variant: ImageVariant) -> &str {
+        match variant {
+            ImageVariant::Original => &self.storage_key,
+            ImageVariant::Thumbnail => &self.thumbnail_key,
+        }
+    }
+}
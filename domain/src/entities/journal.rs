@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A markdown note in a table's campaign journal, such as a session recap.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    /// Sanitized HTML rendered from `body`.
+    pub body_html: String,
+    pub revision: u32,
+    /// When the entry happened in the campaign: the linked session's
+    /// scheduled date if there is one, otherwise when it was written.
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct JournalEntryRevision {
+    pub entry_id: Uuid,
+    pub revision: u32,
+    pub title: String,
+    pub body: String,
+    pub edited_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// One-time sign-in token. Only the SHA-256 hash of the token is stored; the
+/// raw value exists solely in the link sent to the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MagicLinkToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub consumed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
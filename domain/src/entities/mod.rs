@@ -1,23 +1,45 @@
+pub mod account_export;
+pub mod announcement;
+pub mod attachment;
+pub mod audit_log;
+pub mod chat_message;
 pub mod commands;
 pub mod game_system;
+pub mod image;
+pub mod journal;
+pub mod magic_link;
 pub mod refresh_token;
 pub mod session;
 pub mod session_checkin;
 pub mod session_intent;
 pub mod table;
 pub mod table_member;
+pub mod table_question;
 pub mod table_request;
 
 pub mod user;
+pub mod user_event;
 
+pub use account_export::{AccountExport, ExportStatus, PersonalDataArchive, PersonalProfile};
+pub use announcement::{
+    Announcement, AnnouncementComment, AnnouncementListing, AnnouncementReadReceipt,
+};
+pub use attachment::{Attachment, AttachmentUsage};
+pub use audit_log::{AuditAction, AuditEntityType, AuditLog};
+pub use chat_message::ChatMessage;
 pub use commands::*;
 pub use game_system::GameSystem;
+pub use image::{Image, ImageKind, ImageVariant};
+pub use journal::{JournalEntry, JournalEntryRevision};
+pub use magic_link::MagicLinkToken;
 pub use refresh_token::RefreshToken;
 pub use session::{Session, SessionStatus};
 pub use session_checkin::SessionCheckin;
 pub use session_intent::{IntentStatus, SessionIntent};
 pub use table::*;
 pub use table_member::TableMember;
-pub use table_request::{TableRequest, TableRequestStatus};
+pub use table_question::{QuestionKind, TableQuestion};
+pub use table_request::{ApplicationAnswer, TableRequest, TableRequestStatus};
 
-pub use user::User;
+pub use user::{User, UserRole};
+pub use user_event::{UserEvent, UserEventKind};
@@ -11,7 +11,49 @@ pub enum TableStatus {
     Inactive,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, ToSchema, Default)]
+pub enum TableFormat {
+    #[default]
+    Online,
+    InPerson,
+    Hybrid,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, ToSchema, Default)]
+pub enum AgeRating {
+    #[default]
+    AllAges,
+    Teen,
+    Mature,
+}
+
+/// Who can find and open a table. Members and the GM can always see it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, ToSchema, Default)]
+pub enum TableVisibility {
+    /// Listed, searchable and open to everyone.
+    #[default]
+    Public,
+    /// Hidden from listings and search but reachable by id.
+    Unlisted,
+    /// Only visible to its members.
+    Private,
+}
+
+/// Where an in-person table meets.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TableLocation {
+    pub address: String,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct GeoPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Table {
     pub id: Uuid,
     pub gm_id: Uuid,
@@ -20,11 +62,27 @@ pub struct Table {
     pub player_slots: u32,
     pub status: TableStatus,
     pub game_system_id: Uuid,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: TableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: AgeRating,
+    pub location: Option<TableLocation>,
+    pub visibility: TableVisibility,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+/// A table as shown while browsing, with the number of seats still free and,
+/// for location searches, how far away it is.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TableListing {
+    pub table: Table,
+    pub open_slots: u32,
+    pub distance_km: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct TableDetails {
     pub id: Uuid,
     pub gm_id: Uuid,
@@ -35,6 +93,13 @@ pub struct TableDetails {
     pub sessions: Vec<Session>,
     pub status: TableStatus,
     pub game_system_id: Uuid,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: TableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: AgeRating,
+    pub location: Option<TableLocation>,
+    pub visibility: TableVisibility,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, ToSchema)]
+pub enum QuestionKind {
+    Text,
+    SingleChoice,
+    MultiChoice,
+}
+
+/// One entry of the questionnaire players fill in when asking to join a table.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct TableQuestion {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub position: i32,
+    pub prompt: String,
+    pub kind: QuestionKind,
+    /// Empty for `Text` questions.
+    pub options: Vec<String>,
+    pub required: bool,
+    pub created_at: DateTime<Utc>,
+}
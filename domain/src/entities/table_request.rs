@@ -8,11 +8,22 @@ pub struct TableRequest {
     pub user_id: Uuid,
     pub table_id: Uuid,
     pub message: Option<String>,
+    pub answers: Vec<ApplicationAnswer>,
     pub status: TableRequestStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// A player's answer to one questionnaire question. `values` holds a single
+/// entry for text and single-choice questions.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ApplicationAnswer {
+    pub question_id: Uuid,
+    /// Copied from the question when the request is created.
+    pub prompt: String,
+    pub values: Vec<String>,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Default)]
 pub enum TableRequestStatus {
     #[default]
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserEventKind {
+    SessionScheduled,
+    SessionStarted,
+    SessionCompleted,
+    IntentChanged,
+    RequestAccepted,
+}
+
+impl UserEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserEventKind::SessionScheduled => "session_scheduled",
+            UserEventKind::SessionStarted => "session_started",
+            UserEventKind::SessionCompleted => "session_completed",
+            UserEventKind::IntentChanged => "intent_changed",
+            UserEventKind::RequestAccepted => "request_accepted",
+        }
+    }
+}
+
+/// Something that happened which a user should hear about live. Each
+/// recipient gets their own copy with a sequential id.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct UserEvent {
+    pub id: i64,
+    pub user_id: Uuid,
+    pub kind: UserEventKind,
+    pub payload: Value,
+    pub created_at: DateTime<Utc>,
+}
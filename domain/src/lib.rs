@@ -1,6 +1,9 @@
 pub mod auth;
+pub mod chat;
 pub mod entities;
 pub mod error;
+pub mod mail;
 pub mod repositories;
 pub mod search;
 pub mod services;
+pub mod storage;
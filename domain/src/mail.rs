@@ -0,0 +1,13 @@
+use shared::Result;
+
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait::async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: EmailMessage) -> Result<()>;
+}
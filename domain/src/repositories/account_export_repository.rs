@@ -0,0 +1,24 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AccountExportRepository: Send + Sync {
+    /// Creates a pending export, or returns the one the user already has
+    /// waiting.
+    async fn find_or_create_pending(
+        &self,
+        command: CreateAccountExportCommand,
+    ) -> Result<AccountExport>;
+    /// Claims the oldest pending export that is unclaimed or whose claim was
+    /// taken before `stale_before`.
+    async fn claim_next(&self, stale_before: DateTime<Utc>) -> Result<Option<AccountExport>>;
+    /// Hands a claimed export that is still pending back to the queue.
+    async fn release_claim(&self, id: Uuid) -> Result<()>;
+    async fn complete(&self, command: CompleteAccountExportCommand) -> Result<AccountExport>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountExport>>;
+    /// Number of rows across all exported tables that belong to the user.
+    async fn count_personal_records(&self, user_id: Uuid) -> Result<i64>;
+}
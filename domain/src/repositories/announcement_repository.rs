@@ -0,0 +1,34 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AnnouncementRepository: Send + Sync {
+    async fn create(&self, command: CreateAnnouncementCommand) -> Result<Announcement>;
+    /// Returns `None` if the announcement does not exist.
+    async fn update(&self, command: UpdateAnnouncementCommand) -> Result<Option<Announcement>>;
+    async fn delete(&self, id: Uuid) -> Result<()>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Announcement>>;
+    /// Pinned announcements first, then newest first, with `viewer_id`'s
+    /// read state.
+    async fn find_by_table_id(
+        &self,
+        table_id: Uuid,
+        viewer_id: Uuid,
+    ) -> Result<Vec<AnnouncementListing>>;
+    /// Marking an announcement read twice keeps the first timestamp.
+    async fn mark_read(&self, announcement_id: Uuid, user_id: Uuid) -> Result<()>;
+    async fn find_read_receipts(
+        &self,
+        announcement_id: Uuid,
+    ) -> Result<Vec<AnnouncementReadReceipt>>;
+    async fn create_comment(
+        &self,
+        command: CreateAnnouncementCommentCommand,
+    ) -> Result<AnnouncementComment>;
+    /// Oldest first.
+    async fn find_comments(&self, announcement_id: Uuid) -> Result<Vec<AnnouncementComment>>;
+    async fn find_comment_by_id(&self, id: Uuid) -> Result<Option<AnnouncementComment>>;
+    async fn delete_comment(&self, id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,27 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// Inserts the attachment unless it would push the table past
+    /// `quota_bytes`, in which case `None` is returned. The check and the
+    /// insert happen atomically per table.
+    async fn create_within_quota(
+        &self,
+        command: CreateAttachmentCommand,
+        quota_bytes: u64,
+    ) -> Result<Option<Attachment>>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Attachment>>;
+    /// Table-wide handouts only, not the attachments of its sessions.
+    async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<Attachment>>;
+    async fn find_by_session_id(&self, session_id: Uuid) -> Result<Vec<Attachment>>;
+    async fn used_bytes_by_table_id(&self, table_id: Uuid) -> Result<u64>;
+    async fn delete(&self, id: Uuid) -> Result<Option<Attachment>>;
+    /// Removes attachments whose table, session or uploader was deleted
+    /// before `deleted_before`, returning them so their files can be deleted
+    /// as well.
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<Vec<Attachment>>;
+}
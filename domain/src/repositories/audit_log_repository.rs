@@ -0,0 +1,11 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use shared::Result;
+
+/// Audit entries are append-only, so this repository deliberately does not
+/// extend the generic CRUD `Repository`.
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn create(&self, command: CreateAuditLogCommand) -> Result<AuditLog>;
+    async fn read(&self, command: GetAuditLogCommand) -> Result<Vec<AuditLog>>;
+}
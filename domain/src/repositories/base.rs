@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use shared::Result;
 use uuid::Uuid;
 
@@ -22,47 +23,104 @@ pub trait AuditableEntity {
     fn updated_at(&self) -> DateTime<Utc>;
 }
 
-#[derive(Debug, Clone)]
-pub struct PaginationParams {
-    pub limit: Option<i64>,
-    pub offset: Option<i64>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
 }
 
-impl Default for PaginationParams {
-    fn default() -> Self {
-        Self {
-            limit: Some(DEFAULT_PAGINATION_LIMIT),
-            offset: Some(DEFAULT_OFFSET),
+impl SortOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
         }
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum SortDirection {
-    Asc,
-    Desc,
+/// Which slice of a listing to return. With a `cursor` the listing resumes
+/// right after that item and `offset` is ignored; cursors are item ids, so
+/// they only apply to listings ordered by id (uuidv7, hence creation time).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaginationParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub cursor: Option<Uuid>,
 }
 
-impl SortDirection {
-    pub fn as_sql(&self) -> &'static str {
-        match self {
-            SortDirection::Asc => "ASC",
-            SortDirection::Desc => "DESC",
+impl PaginationParams {
+    pub fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGINATION_LIMIT)
+            .clamp(1, MAX_PAGINATION_LIMIT)
+    }
+
+    pub fn offset(&self) -> i64 {
+        match self.cursor {
+            Some(_) => DEFAULT_OFFSET,
+            None => self.offset.unwrap_or(DEFAULT_OFFSET).max(0),
         }
     }
 }
 
+/// A sort field picked from a per-listing whitelist, plus its direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct SortParams<F> {
+    pub field: F,
+    pub order: SortOrder,
+}
+
+/// One page of a listing. `total` counts every matching item, not just the
+/// ones on this page.
 #[derive(Debug, Clone)]
-pub struct SortParams {
-    pub field: String,
-    pub direction: SortDirection,
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    pub next_cursor: Option<Uuid>,
 }
 
-impl Default for SortParams {
-    fn default() -> Self {
+impl<T> Page<T> {
+    /// `keyset` tells whether the listing is ordered by id, which is the only
+    /// case where the last item can serve as the cursor of the next page.
+    pub fn new(
+        items: Vec<T>,
+        total: i64,
+        params: &PaginationParams,
+        keyset: bool,
+        id_of: impl Fn(&T) -> Uuid,
+    ) -> Self {
+        let limit = params.limit();
+        let offset = params.offset();
+
+        let has_more = match params.cursor {
+            Some(_) => items.len() as i64 == limit,
+            None => offset + (items.len() as i64) < total,
+        };
+        let next_cursor = if keyset && has_more {
+            items.last().map(id_of)
+        } else {
+            None
+        };
+
         Self {
-            field: "created_at".to_string(),
-            direction: SortDirection::Desc,
+            items,
+            total,
+            limit,
+            offset,
+            next_cursor,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            limit: self.limit,
+            offset: self.offset,
+            next_cursor: self.next_cursor,
         }
     }
 }
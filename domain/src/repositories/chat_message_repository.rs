@@ -0,0 +1,16 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ChatMessageRepository: Send + Sync {
+    async fn create(&self, command: CreateChatMessageCommand) -> Result<ChatMessage>;
+    /// Newest first, starting strictly before the `before` message when given.
+    async fn find_by_table_id(
+        &self,
+        table_id: Uuid,
+        before: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<ChatMessage>>;
+}
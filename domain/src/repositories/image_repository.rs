@@ -0,0 +1,17 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ImageRepository: Send + Sync {
+    async fn upsert(&self, command: CreateImageCommand) -> Result<Image>;
+    async fn find(&self, kind: ImageKind, subject_id: Uuid) -> Result<Option<Image>>;
+    /// Returns the removed image so its files can be deleted as well.
+    async fn delete(&self, kind: ImageKind, subject_id: Uuid) -> Result<Option<Image>>;
+    /// Removes images whose table or user is gone or was deleted before
+    /// `deleted_before`, and those uploaded by such users, returning them so
+    /// their files can be deleted as well.
+    async fn purge_orphaned(&self, deleted_before: DateTime<Utc>) -> Result<Vec<Image>>;
+}
@@ -0,0 +1,23 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait JournalRepository: Send + Sync {
+    /// Stores the entry together with its first revision.
+    async fn create(&self, command: CreateJournalEntryCommand) -> Result<JournalEntry>;
+    /// Applies the changes and records them as the next revision. Returns
+    /// `None` if the entry does not exist.
+    async fn update(&self, command: UpdateJournalEntryCommand) -> Result<Option<JournalEntry>>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<JournalEntry>>;
+    /// Entries of a table in campaign order, oldest first.
+    async fn find_by_table_id(
+        &self,
+        table_id: Uuid,
+        session_id: Option<Uuid>,
+    ) -> Result<Vec<JournalEntry>>;
+    /// Newest revision first.
+    async fn find_revisions(&self, entry_id: Uuid) -> Result<Vec<JournalEntryRevision>>;
+    async fn delete(&self, id: Uuid) -> Result<Option<JournalEntry>>;
+}
@@ -0,0 +1,15 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait MagicLinkRepository: Send + Sync {
+    async fn create(&self, command: CreateMagicLinkTokenCommand) -> Result<MagicLinkToken>;
+    /// Marks an unexpired, unused token as consumed and returns it. Returns
+    /// `None` when the token is unknown, expired or was already redeemed.
+    async fn consume(&self, token_hash: &str) -> Result<Option<MagicLinkToken>>;
+    async fn count_created_since(&self, user_id: Uuid, since: DateTime<Utc>) -> Result<i64>;
+    async fn purge_expired(&self, expired_before: DateTime<Utc>) -> Result<u64>;
+}
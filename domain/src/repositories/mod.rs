@@ -1,21 +1,43 @@
+pub mod account_export_repository;
+pub mod announcement_repository;
+pub mod attachment_repository;
+pub mod audit_log_repository;
 pub mod base;
+pub mod chat_message_repository;
 pub mod game_system_repository;
+pub mod image_repository;
+pub mod journal_repository;
+pub mod magic_link_repository;
 pub mod refresh_token_repository;
+pub mod search_repository;
 pub mod session_checkin_repository;
 pub mod session_intent_repository;
 pub mod session_repository;
 pub mod table_member_repository;
+pub mod table_question_repository;
 pub mod table_repository;
 pub mod table_request_repository;
+pub mod user_event_repository;
 pub mod user_repository;
 
+pub use account_export_repository::AccountExportRepository;
+pub use announcement_repository::AnnouncementRepository;
+pub use attachment_repository::AttachmentRepository;
+pub use audit_log_repository::AuditLogRepository;
 pub use base::*;
+pub use chat_message_repository::ChatMessageRepository;
 pub use game_system_repository::GameSystemRepository;
+pub use image_repository::ImageRepository;
+pub use journal_repository::JournalRepository;
+pub use magic_link_repository::MagicLinkRepository;
 pub use refresh_token_repository::RefreshTokenRepository;
+pub use search_repository::SearchRepository;
 pub use session_checkin_repository::SessionCheckinRepository;
 pub use session_intent_repository::SessionIntentRepository;
 pub use session_repository::SessionRepository;
 pub use table_member_repository::TableMemberRepository;
+pub use table_question_repository::TableQuestionRepository;
 pub use table_repository::TableRepository;
 pub use table_request_repository::TableRequestRepository;
+pub use user_event_repository::UserEventRepository;
 pub use user_repository::UserRepository;
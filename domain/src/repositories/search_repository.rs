@@ -0,0 +1,9 @@
+use crate::search::{SearchCriteria, SearchResult};
+use async_trait::async_trait;
+use shared::Result;
+
+#[async_trait]
+pub trait SearchRepository: Send + Sync {
+    /// Returns one page of ranked results along with the total number of hits.
+    async fn search(&self, criteria: SearchCriteria) -> Result<(Vec<SearchResult>, u64)>;
+}
@@ -18,4 +18,5 @@ pub trait SessionCheckinRepository:
         session_intent_id: Uuid,
     ) -> Result<Vec<SessionCheckin>>;
     async fn find_by_attendance(&self, attendance: bool) -> Result<Vec<SessionCheckin>>;
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<SessionCheckin>>;
 }
@@ -13,6 +13,20 @@ pub trait SessionIntentRepository:
     > + Send
     + Sync
 {
+    /// Creates the intent and tells the GM and members of `table_id` in the
+    /// same transaction.
+    async fn create_with_event(
+        &self,
+        command: CreateSessionIntentCommand,
+        table_id: Uuid,
+    ) -> Result<SessionIntent>;
+    /// Updates the intent, telling the GM and members of `table_id` in the
+    /// same transaction when given.
+    async fn update_with_event(
+        &self,
+        command: UpdateSessionIntentCommand,
+        table_id: Option<Uuid>,
+    ) -> Result<SessionIntent>;
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<SessionIntent>>;
     async fn find_by_session_id(&self, session_id: Uuid) -> Result<Vec<SessionIntent>>;
 }
@@ -1,6 +1,7 @@
 use crate::entities::session_checkin::{SessionFinalizationData, SessionFinalizationResult};
 pub use crate::entities::*;
 use crate::repositories::base::Repository;
+use chrono::{DateTime, Utc};
 use shared::Result;
 use uuid::Uuid;
 
@@ -16,8 +17,48 @@ pub trait SessionRepository:
     + Sync
 {
     async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<Session>>;
+    /// Counts the sessions matching `read`'s filters, ignoring pagination.
+    async fn count(&self, command: GetSessionCommand) -> Result<i64>;
+    /// Creates the session and records `kind` for the GM and members of its
+    /// table in the same transaction.
+    async fn create_with_event(
+        &self,
+        command: CreateSessionCommand,
+        kind: UserEventKind,
+    ) -> Result<Session>;
+    /// Updates the session and writes `audit` with the updated session as its
+    /// `after`, recording `event` for the GM and members of its table in the
+    /// same transaction when given.
+    async fn update_with_audit(
+        &self,
+        command: UpdateSessionCommand,
+        audit: CreateAuditLogCommand,
+        event: Option<UserEventKind>,
+    ) -> Result<Session>;
+    /// Also marks the session completed, records
+    /// [`UserEventKind::SessionCompleted`] and writes `audit` with the
+    /// completed session as its `after`.
     async fn finalize_session_with_checkins(
         &self,
         finalization_data: SessionFinalizationData,
+        audit: CreateAuditLogCommand,
     ) -> Result<SessionFinalizationResult>;
+    /// Marks the session deleted, writing `audit` in the same transaction.
+    async fn soft_delete(&self, session_id: Uuid, audit: CreateAuditLogCommand) -> Result<Session>;
+    /// Looks up a session deleted at or after `deleted_since`.
+    async fn find_deleted_by_id(
+        &self,
+        session_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> Result<Option<Session>>;
+    /// Reverts a `soft_delete` while the table is still there. `audit` gets
+    /// the restored session as its `after`.
+    async fn restore(
+        &self,
+        session_id: Uuid,
+        deleted_since: DateTime<Utc>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Option<Session>>;
+    /// Permanently removes sessions deleted before `deleted_before`.
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64>;
 }
@@ -14,6 +14,8 @@ pub trait TableMemberRepository:
     + Sync
 {
     async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<TableMember>>;
+    /// Counts the members matching `read`'s filters, ignoring pagination.
+    async fn count(&self, command: GetTableMemberCommand) -> Result<i64>;
     async fn find_by_table_and_user(
         &self,
         table_id: Uuid,
@@ -0,0 +1,11 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use shared::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait TableQuestionRepository: Send + Sync {
+    /// Questions ordered by position.
+    async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<TableQuestion>>;
+    async fn replace(&self, command: ReplaceTableQuestionsCommand) -> Result<Vec<TableQuestion>>;
+}
@@ -1,6 +1,7 @@
 use crate::entities::Table;
 use crate::entities::*;
 use crate::repositories::base::Repository;
+use chrono::{DateTime, Utc};
 use shared::Result;
 use uuid::Uuid;
 
@@ -14,4 +15,38 @@ pub trait TableRepository:
     async fn find_by_session_id(&self, session_id: Uuid) -> Result<Option<Table>>;
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Table>>;
     async fn find_details_by_id(&self, table_id: Uuid) -> Result<Option<TableDetails>>;
+    /// Returns the table unless it is private and `viewer_id` neither runs nor
+    /// plays in it.
+    async fn find_visible_by_id(&self, table_id: Uuid, viewer_id: Uuid) -> Result<Option<Table>>;
+    /// Browsing query honouring every filter and the sort order of the command.
+    async fn list(&self, command: GetTableCommand) -> Result<Vec<TableListing>>;
+    /// Counts the tables `list` would return without pagination.
+    async fn count(&self, command: GetTableCommand) -> Result<i64>;
+    /// Updates the table, writing `audit` with the updated table as its
+    /// `after` in the same transaction.
+    async fn update_with_audit(
+        &self,
+        command: UpdateTableCommand,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Table>;
+    /// Marks the table and its sessions as deleted with a single shared
+    /// timestamp, writing `audit` in the same transaction.
+    async fn soft_delete(&self, table_id: Uuid, audit: CreateAuditLogCommand) -> Result<Table>;
+    /// Looks up a table deleted at or after `deleted_since`.
+    async fn find_deleted_by_id(
+        &self,
+        table_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> Result<Option<Table>>;
+    /// Reverts a `soft_delete`, bringing back only the rows it hid. `audit`
+    /// gets the restored table as its `after` and is written in the same
+    /// transaction.
+    async fn restore(
+        &self,
+        table_id: Uuid,
+        deleted_since: DateTime<Utc>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Option<Table>>;
+    /// Permanently removes tables deleted before `deleted_before`.
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64>;
 }
@@ -15,8 +15,26 @@ pub trait TableRequestRepository:
     > + Send
     + Sync
 {
+    /// Adds `member` to the table, marks the request approved, tells the
+    /// requester and writes `audit` with the approved request as its
+    /// `after`, all in one transaction.
+    async fn approve(
+        &self,
+        request_id: Uuid,
+        member: CreateTableMemberCommand,
+        audit: CreateAuditLogCommand,
+    ) -> Result<TableRequest>;
+    /// Updates the request, writing `audit` with the updated request as its
+    /// `after` in the same transaction.
+    async fn update_with_audit(
+        &self,
+        command: UpdateTableRequestCommand,
+        audit: CreateAuditLogCommand,
+    ) -> Result<TableRequest>;
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<TableRequest>>;
     async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<TableRequest>>;
+    /// Counts the requests matching `read`'s filters, ignoring pagination.
+    async fn count(&self, command: GetTableRequestCommand) -> Result<i64>;
     async fn find_by_status(&self, status: TableRequestStatus) -> Result<Vec<TableRequest>>;
     async fn find_by_user_and_table(
         &self,
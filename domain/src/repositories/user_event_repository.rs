@@ -0,0 +1,15 @@
+use crate::entities::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::Result;
+use uuid::Uuid;
+
+/// Events are appended by the repositories of the changes they describe,
+/// in the same transaction.
+#[async_trait]
+pub trait UserEventRepository: Send + Sync {
+    /// Oldest first, starting strictly after the `after` event id.
+    async fn find_after(&self, user_id: Uuid, after: i64, limit: u32) -> Result<Vec<UserEvent>>;
+    async fn find_latest_id(&self, user_id: Uuid) -> Result<Option<i64>>;
+    async fn purge_older_than(&self, created_before: DateTime<Utc>) -> Result<u64>;
+}
@@ -1,6 +1,8 @@
 use crate::entities::*;
 use crate::repositories::base::Repository;
+use chrono::{DateTime, Utc};
 use shared::Result;
+use uuid::Uuid;
 
 #[async_trait::async_trait]
 pub trait UserRepository:
@@ -9,4 +11,41 @@ pub trait UserRepository:
     + Sync
 {
     async fn find_by_email(&self, email: &str) -> Result<Option<User>>;
+    /// Counts the users matching `read`'s filters, ignoring pagination.
+    async fn count(&self, command: GetUserCommand) -> Result<i64>;
+    /// Suspending also revokes the user's refresh tokens. `audit` is written
+    /// in the same transaction.
+    async fn set_suspended_at(
+        &self,
+        user_id: Uuid,
+        suspended_at: Option<DateTime<Utc>>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<User>;
+    /// Changes the platform role, writing `audit` in the same transaction.
+    async fn set_role(
+        &self,
+        user_id: Uuid,
+        role: UserRole,
+        audit: CreateAuditLogCommand,
+    ) -> Result<User>;
+    /// Marks the user, the tables they GM and those tables' sessions as
+    /// deleted with a single shared timestamp and revokes the user's refresh
+    /// tokens. `audit` is written in the same transaction.
+    async fn soft_delete(&self, user_id: Uuid, audit: CreateAuditLogCommand) -> Result<User>;
+    /// Looks up an account deleted at or after `deleted_since`.
+    async fn find_deleted_by_email(
+        &self,
+        email: &str,
+        deleted_since: DateTime<Utc>,
+    ) -> Result<Option<User>>;
+    /// Reverts a `soft_delete`, bringing back only the rows it hid. `audit` is
+    /// written in the same transaction.
+    async fn restore(
+        &self,
+        user_id: Uuid,
+        deleted_since: DateTime<Utc>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Option<User>>;
+    /// Permanently removes accounts deleted before `deleted_before`.
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64>;
 }
@@ -1,23 +1,83 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
-#[derive(Debug, Deserialize)]
+pub const DEFAULT_SEARCH_LIMIT: u32 = 20;
+pub const MAX_SEARCH_LIMIT: u32 = 100;
+pub const MAX_SEARCH_QUERY_LEN: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchType {
+    Table,
+    Session,
+    User,
+    GameSystem,
+}
+
+impl SearchType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Table => "table",
+            SearchType::Session => "session",
+            SearchType::User => "user",
+            SearchType::GameSystem => "game_system",
+        }
+    }
+}
+
+impl FromStr for SearchType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "table" => Ok(SearchType::Table),
+            "session" => Ok(SearchType::Session),
+            "user" => Ok(SearchType::User),
+            "game_system" => Ok(SearchType::GameSystem),
+            other => Err(format!("Unknown search type '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct SearchQuery {
+    /// Search terms. Supports quoted phrases, `or` and `-excluded` words.
     pub q: String,
+    /// Comma-separated result types: `table`, `session`, `user`, `game_system`.
     pub r#type: Option<String>,
+    /// Page number, starting at 1.
     pub page: Option<u32>,
     pub limit: Option<u32>,
 }
 
-#[derive(Debug, Serialize)]
+/// Validated search parameters handed to the repository.
+#[derive(Debug, Clone)]
+pub struct SearchCriteria {
+    pub terms: String,
+    /// Tables and sessions are limited to what this user may see.
+    pub viewer_id: Uuid,
+    /// Empty means every type.
+    pub types: Vec<SearchType>,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SearchResult {
     pub id: String,
     pub title: String,
     pub description: Option<String>,
     pub r#type: String,
     pub created_at: String,
+    pub rank: f32,
+    /// HTML-escaped matching fragment with hits wrapped in `<mark>` tags.
+    pub highlight: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SearchResponse {
     pub results: Vec<SearchResult>,
     pub total: u64,
@@ -0,0 +1,20 @@
+use shared::Result;
+use tracing::warn;
+
+/// Blob storage for uploaded files, addressed by slash-separated keys.
+#[async_trait::async_trait]
+pub trait ObjectStorage: Send + Sync {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<()>;
+    /// Returns `None` when nothing is stored under `key`.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>>;
+    /// Deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// Deletes `key` without failing the caller. A file left behind is never
+/// read again, so a failed delete is only logged.
+pub async fn delete_best_effort(storage: &dyn ObjectStorage, key: &str) {
+    if let Err(err) = storage.delete(key).await {
+        warn!("Failed to delete stored file {}: {}", key, err);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod postgres;
+
+pub use postgres::*;
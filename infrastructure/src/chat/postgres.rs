@@ -0,0 +1,42 @@
+use crate::persistence::Db;
+use domain::chat::{ChatEvent, ChatPublisher};
+use shared::Result;
+use shared::error::{ApplicationError, Error};
+use tracing::error;
+
+/// The Postgres channel every instance listens on for chat events.
+pub const CHAT_CHANNEL: &str = "table_chat";
+
+/// Publishes chat events with `NOTIFY`, so every instance connected to the
+/// same database receives them.
+#[derive(Clone)]
+pub struct PostgresChatPublisher {
+    pool: Db,
+}
+
+impl PostgresChatPublisher {
+    pub fn new(pool: Db) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatPublisher for PostgresChatPublisher {
+    async fn publish(&self, event: &ChatEvent) -> Result<()> {
+        let payload = serde_json::to_string(event).map_err(|_| Error::InternalServerError)?;
+
+        sqlx::query("SELECT pg_notify($1, $2)")
+            .bind(CHAT_CHANNEL)
+            .bind(payload)
+            .execute(&self.pool)
+            .await
+            .map_err(|err| {
+                error!("Failed to publish chat event: {}", err);
+                Error::Application(ApplicationError::ServiceUnavailable {
+                    service: "chat".to_string(),
+                })
+            })?;
+
+        Ok(())
+    }
+}
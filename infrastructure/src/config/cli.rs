@@ -0,0 +1,106 @@
+use crate::config::layer::*;
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+/// Command-line flags, the highest-precedence configuration layer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Parser)]
+#[command(name = "jos", version, about = "JOS (Join Our Session) API server")]
+pub struct Cli {
+    /// TOML config file [default: jos.toml when present, or JOS_CONFIG]
+    #[arg(short, long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// `development` or `production`
+    #[arg(long)]
+    pub environment: Option<String>,
+
+    /// Address to bind to
+    #[arg(long)]
+    pub host: Option<String>,
+
+    /// Port to listen on
+    #[arg(short, long)]
+    pub port: Option<u16>,
+
+    #[arg(long, value_name = "URL")]
+    pub database_url: Option<String>,
+
+    /// Largest number of pooled database connections
+    #[arg(long, value_name = "N")]
+    pub db_max_connections: Option<u32>,
+
+    /// Connections kept open even when idle
+    #[arg(long, value_name = "N")]
+    pub db_min_connections: Option<u32>,
+
+    /// `pretty` or `json`
+    #[arg(long, value_name = "FORMAT")]
+    pub log_format: Option<String>,
+
+    /// Origin allowed to call the API cross-origin; repeat for several
+    #[arg(long = "cors-origin", value_name = "ORIGIN")]
+    pub cors_origins: Vec<String>,
+
+    /// Turn an optional feature on; repeat for several
+    #[arg(long, value_name = "FEATURE")]
+    pub enable: Vec<Feature>,
+
+    /// Turn an optional feature off; repeat for several
+    #[arg(long, value_name = "FEATURE")]
+    pub disable: Vec<Feature>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Feature {
+    /// Swagger UI and the OpenAPI document
+    ApiDocs,
+    /// Prometheus endpoint at /metrics
+    Metrics,
+    /// Periodic removal of expired soft-deleted data
+    PurgeJob,
+}
+
+impl Cli {
+    pub fn to_layer(&self) -> ConfigLayer {
+        let toggle = |feature: Feature| {
+            if self.disable.contains(&feature) {
+                Some(false)
+            } else if self.enable.contains(&feature) {
+                Some(true)
+            } else {
+                None
+            }
+        };
+
+        ConfigLayer {
+            environment: self.environment.clone(),
+            server: ServerLayer {
+                host: self.host.clone(),
+                port: self.port,
+                ..Default::default()
+            },
+            database: DatabaseLayer {
+                url: self.database_url.clone(),
+                max_connections: self.db_max_connections,
+                min_connections: self.db_min_connections,
+                ..Default::default()
+            },
+            auth: AuthLayer::default(),
+            cors: CorsLayer {
+                allowed_origins: (!self.cors_origins.is_empty()).then(|| self.cors_origins.clone()),
+                ..Default::default()
+            },
+            logging: LoggingLayer {
+                format: self.log_format.clone(),
+            },
+            telemetry: TelemetryLayer::default(),
+            features: FeaturesLayer {
+                api_docs: toggle(Feature::ApiDocs),
+                metrics: toggle(Feature::Metrics),
+                purge_job: toggle(Feature::PurgeJob),
+            },
+            purge: PurgeLayer::default(),
+            uploads: UploadsLayer::default(),
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use serde::Deserialize;
+use std::path::Path;
+use std::str::FromStr;
+
+/// One source of settings: the config file, the environment or the command
+/// line. Anything left unset falls through to the layer below it.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigLayer {
+    pub environment: Option<String>,
+    pub server: ServerLayer,
+    pub database: DatabaseLayer,
+    pub auth: AuthLayer,
+    pub cors: CorsLayer,
+    pub logging: LoggingLayer,
+    pub telemetry: TelemetryLayer,
+    pub features: FeaturesLayer,
+    pub purge: PurgeLayer,
+    pub uploads: UploadsLayer,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerLayer {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub readiness_timeout_ms: Option<u64>,
+    pub shutdown_drain_timeout_secs: Option<u64>,
+    pub shutdown_grace_period_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseLayer {
+    pub url: Option<String>,
+    pub max_connections: Option<u32>,
+    pub min_connections: Option<u32>,
+    pub acquire_timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthLayer {
+    pub jwt_secret: Option<String>,
+    pub jwt_expiration_hours: Option<u64>,
+    pub refresh_token_expiration_hours: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsLayer {
+    pub allowed_origins: Option<Vec<String>>,
+    pub allowed_methods: Option<Vec<String>>,
+    pub allowed_headers: Option<Vec<String>>,
+    pub allow_credentials: Option<bool>,
+    pub max_age_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggingLayer {
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryLayer {
+    pub enabled: Option<bool>,
+    pub otlp_endpoint: Option<String>,
+    pub service_name: Option<String>,
+    pub traces_filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeaturesLayer {
+    pub api_docs: Option<bool>,
+    pub metrics: Option<bool>,
+    pub purge_job: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PurgeLayer {
+    pub deletion_grace_period_days: Option<u64>,
+    pub interval_minutes: Option<u64>,
+    pub user_event_retention_days: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UploadsLayer {
+    pub max_bytes: Option<usize>,
+    pub attachment_max_bytes: Option<usize>,
+    pub table_attachment_quota_bytes: Option<u64>,
+}
+
+impl ConfigLayer {
+    /// Reads a TOML file. Problems are added to `problems` and leave the
+    /// layer empty, so the remaining sources are still checked.
+    pub fn from_file(path: &Path, problems: &mut Vec<String>) -> Self {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) => {
+                problems.push(format!("{}: {}", path.display(), err));
+                return Self::default();
+            }
+        };
+
+        toml::from_str(&contents).unwrap_or_else(|err| {
+            problems.push(format!(
+                "{}: {}",
+                path.display(),
+                err.to_string().trim_end()
+            ));
+            Self::default()
+        })
+    }
+
+    pub fn from_env(problems: &mut Vec<String>) -> Self {
+        let mut env = EnvReader { problems };
+
+        Self {
+            environment: env.string("ENVIRONMENT"),
+            server: ServerLayer {
+                host: env.string("HOST"),
+                port: env.parse("PORT"),
+                readiness_timeout_ms: env.parse("READINESS_TIMEOUT_MS"),
+                shutdown_drain_timeout_secs: env.parse("SHUTDOWN_DRAIN_TIMEOUT_SECS"),
+                shutdown_grace_period_secs: env.parse("SHUTDOWN_GRACE_PERIOD_SECS"),
+            },
+            database: DatabaseLayer {
+                url: env.string("DATABASE_URL"),
+                max_connections: env.parse("DATABASE_MAX_CONNECTIONS"),
+                min_connections: env.parse("DATABASE_MIN_CONNECTIONS"),
+                acquire_timeout_secs: env.parse("DATABASE_ACQUIRE_TIMEOUT_SECS"),
+            },
+            auth: AuthLayer {
+                jwt_secret: env.string("JWT_SECRET"),
+                jwt_expiration_hours: env.parse("JWT_EXPIRATION_DURATION"),
+                refresh_token_expiration_hours: env.parse("REFRESH_TOKEN_EXPIRATION_HOURS"),
+            },
+            cors: CorsLayer {
+                allowed_origins: env.list("CORS_ALLOWED_ORIGINS"),
+                allowed_methods: env.list("CORS_ALLOWED_METHODS"),
+                allowed_headers: env.list("CORS_ALLOWED_HEADERS"),
+                allow_credentials: env.flag("CORS_ALLOW_CREDENTIALS"),
+                max_age_secs: env.parse("CORS_MAX_AGE_SECS"),
+            },
+            logging: LoggingLayer {
+                format: env.string("LOG_FORMAT"),
+            },
+            telemetry: TelemetryLayer {
+                enabled: env.flag("OTEL_ENABLED"),
+                otlp_endpoint: env.string("OTEL_EXPORTER_OTLP_ENDPOINT"),
+                service_name: env.string("OTEL_SERVICE_NAME"),
+                traces_filter: env.string("OTEL_TRACES_FILTER"),
+            },
+            features: FeaturesLayer {
+                api_docs: env.flag("FEATURE_API_DOCS"),
+                metrics: env.flag("FEATURE_METRICS"),
+                purge_job: env.flag("FEATURE_PURGE_JOB"),
+            },
+            purge: PurgeLayer {
+                deletion_grace_period_days: env.parse("DELETION_GRACE_PERIOD_DAYS"),
+                interval_minutes: env.parse("PURGE_INTERVAL_MINUTES"),
+                user_event_retention_days: env.parse("USER_EVENT_RETENTION_DAYS"),
+            },
+            uploads: UploadsLayer {
+                max_bytes: env.parse("UPLOAD_MAX_BYTES"),
+                attachment_max_bytes: env.parse("ATTACHMENT_MAX_BYTES"),
+                table_attachment_quota_bytes: env.parse("TABLE_ATTACHMENT_QUOTA_BYTES"),
+            },
+        }
+    }
+
+    /// Returns this layer with every setting of `over` taking precedence.
+    pub fn merge(self, over: Self) -> Self {
+        Self {
+            environment: over.environment.or(self.environment),
+            server: ServerLayer {
+                host: over.server.host.or(self.server.host),
+                port: over.server.port.or(self.server.port),
+                readiness_timeout_ms: over
+                    .server
+                    .readiness_timeout_ms
+                    .or(self.server.readiness_timeout_ms),
+                shutdown_drain_timeout_secs: over
+                    .server
+                    .shutdown_drain_timeout_secs
+                    .or(self.server.shutdown_drain_timeout_secs),
+                shutdown_grace_period_secs: over
+                    .server
+                    .shutdown_grace_period_secs
+                    .or(self.server.shutdown_grace_period_secs),
+            },
+            database: DatabaseLayer {
+                url: over.database.url.or(self.database.url),
+                max_connections: over
+                    .database
+                    .max_connections
+                    .or(self.database.max_connections),
+                min_connections: over
+                    .database
+                    .min_connections
+                    .or(self.database.min_connections),
+                acquire_timeout_secs: over
+                    .database
+                    .acquire_timeout_secs
+                    .or(self.database.acquire_timeout_secs),
+            },
+            auth: AuthLayer {
+                jwt_secret: over.auth.jwt_secret.or(self.auth.jwt_secret),
+                jwt_expiration_hours: over
+                    .auth
+                    .jwt_expiration_hours
+                    .or(self.auth.jwt_expiration_hours),
+                refresh_token_expiration_hours: over
+                    .auth
+                    .refresh_token_expiration_hours
+                    .or(self.auth.refresh_token_expiration_hours),
+            },
+            cors: CorsLayer {
+                allowed_origins: over.cors.allowed_origins.or(self.cors.allowed_origins),
+                allowed_methods: over.cors.allowed_methods.or(self.cors.allowed_methods),
+                allowed_headers: over.cors.allowed_headers.or(self.cors.allowed_headers),
+                allow_credentials: over.cors.allow_credentials.or(self.cors.allow_credentials),
+                max_age_secs: over.cors.max_age_secs.or(self.cors.max_age_secs),
+            },
+            logging: LoggingLayer {
+                format: over.logging.format.or(self.logging.format),
+            },
+            telemetry: TelemetryLayer {
+                enabled: over.telemetry.enabled.or(self.telemetry.enabled),
+                otlp_endpoint: over
+                    .telemetry
+                    .otlp_endpoint
+                    .or(self.telemetry.otlp_endpoint),
+                service_name: over.telemetry.service_name.or(self.telemetry.service_name),
+                traces_filter: over
+                    .telemetry
+                    .traces_filter
+                    .or(self.telemetry.traces_filter),
+            },
+            features: FeaturesLayer {
+                api_docs: over.features.api_docs.or(self.features.api_docs),
+                metrics: over.features.metrics.or(self.features.metrics),
+                purge_job: over.features.purge_job.or(self.features.purge_job),
+            },
+            purge: PurgeLayer {
+                deletion_grace_period_days: over
+                    .purge
+                    .deletion_grace_period_days
+                    .or(self.purge.deletion_grace_period_days),
+                interval_minutes: over.purge.interval_minutes.or(self.purge.interval_minutes),
+                user_event_retention_days: over
+                    .purge
+                    .user_event_retention_days
+                    .or(self.purge.user_event_retention_days),
+            },
+            uploads: UploadsLayer {
+                max_bytes: over.uploads.max_bytes.or(self.uploads.max_bytes),
+                attachment_max_bytes: over
+                    .uploads
+                    .attachment_max_bytes
+                    .or(self.uploads.attachment_max_bytes),
+                table_attachment_quota_bytes: over
+                    .uploads
+                    .table_attachment_quota_bytes
+                    .or(self.uploads.table_attachment_quota_bytes),
+            },
+        }
+    }
+}
+
+/// Reads variables, recording the ones that are set but do not parse.
+struct EnvReader<'a> {
+    problems: &'a mut Vec<String>,
+}
+
+impl EnvReader<'_> {
+    fn string(&mut self, name: &str) -> Option<String> {
+        std::env::var(name)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+    }
+
+    fn parse<T: FromStr>(&mut self, name: &str) -> Option<T> {
+        let value = self.string(name)?;
+
+        match value.trim().parse() {
+            Ok(parsed) => Some(parsed),
+            Err(_) => {
+                self.problems
+                    .push(format!("{name}: '{value}' is not a valid value"));
+                None
+            }
+        }
+    }
+
+    fn flag(&mut self, name: &str) -> Option<bool> {
+        let value = self.string(name)?;
+
+        match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => {
+                self.problems
+                    .push(format!("{name}: '{value}' is not 'true' or 'false'"));
+                None
+            }
+        }
+    }
+
+    /// Comma-separated, ignoring blank entries.
+    fn list(&mut self, name: &str) -> Option<Vec<String>> {
+        self.string(name).map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Cli;
+    use clap::Parser;
+
+    fn file_layer() -> ConfigLayer {
+        toml::from_str(
+            r#"
+            environment = "development"
+
+            [server]
+            host = "127.0.0.1"
+            port = 4000
+
+            [database]
+            url = "postgres://file/jos"
+            max_connections = 4
+            min_connections = 1
+
+            [auth]
+            jwt_expiration_hours = 48
+            refresh_token_expiration_hours = 240
+
+            [cors]
+            allowed_origins = ["https://file.example.com"]
+            max_age_secs = 60
+
+            [logging]
+            format = "pretty"
+
+            [telemetry]
+            service_name = "jos-file"
+            traces_filter = "info"
+
+            [features]
+            metrics = true
+            purge_job = true
+
+            [purge]
+            interval_minutes = 10
+            deletion_grace_period_days = 7
+
+            [uploads]
+            max_bytes = 1000
+            attachment_max_bytes = 2000
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn env_layer() -> ConfigLayer {
+        ConfigLayer {
+            environment: Some("production".to_string()),
+            server: ServerLayer {
+                port: Some(5000),
+                ..Default::default()
+            },
+            database: DatabaseLayer {
+                max_connections: Some(8),
+                ..Default::default()
+            },
+            auth: AuthLayer {
+                jwt_expiration_hours: Some(72),
+                ..Default::default()
+            },
+            cors: CorsLayer {
+                allowed_origins: Some(vec!["https://env.example.com".to_string()]),
+                ..Default::default()
+            },
+            logging: LoggingLayer {
+                format: Some("json".to_string()),
+            },
+            telemetry: TelemetryLayer {
+                service_name: Some("jos-env".to_string()),
+                ..Default::default()
+            },
+            features: FeaturesLayer {
+                metrics: Some(true),
+                ..Default::default()
+            },
+            purge: PurgeLayer {
+                interval_minutes: Some(20),
+                ..Default::default()
+            },
+            uploads: UploadsLayer {
+                max_bytes: Some(3000),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn cli_layer() -> ConfigLayer {
+        Cli::try_parse_from([
+            "jos",
+            "--environment",
+            "development",
+            "--port",
+            "6000",
+            "--db-max-connections",
+            "12",
+            "--cors-origin",
+            "https://cli.example.com",
+            "--log-format",
+            "pretty",
+            "--disable",
+            "metrics",
+        ])
+        .unwrap()
+        .to_layer()
+    }
+
+    #[test]
+    fn test_parses_every_section_from_file() {
+        let file = file_layer();
+
+        assert_eq!(file.environment.as_deref(), Some("development"));
+        assert_eq!(file.server.port, Some(4000));
+        assert_eq!(file.database.max_connections, Some(4));
+        assert_eq!(file.auth.jwt_expiration_hours, Some(48));
+        assert_eq!(file.cors.max_age_secs, Some(60));
+        assert_eq!(file.logging.format.as_deref(), Some("pretty"));
+        assert_eq!(file.telemetry.service_name.as_deref(), Some("jos-file"));
+        assert_eq!(file.features.metrics, Some(true));
+        assert_eq!(file.purge.interval_minutes, Some(10));
+        assert_eq!(file.uploads.max_bytes, Some(1000));
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys_in_file() {
+        let result = toml::from_str::<ConfigLayer>("[server]\nprot = 4000\n");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let layer = file_layer().merge(env_layer());
+
+        assert_eq!(layer.environment.as_deref(), Some("production"));
+        assert_eq!(layer.server.port, Some(5000));
+        assert_eq!(layer.database.max_connections, Some(8));
+        assert_eq!(layer.auth.jwt_expiration_hours, Some(72));
+        assert_eq!(
+            layer.cors.allowed_origins,
+            Some(vec!["https://env.example.com".to_string()])
+        );
+        assert_eq!(layer.logging.format.as_deref(), Some("json"));
+        assert_eq!(layer.telemetry.service_name.as_deref(), Some("jos-env"));
+        assert_eq!(layer.features.metrics, Some(true));
+        assert_eq!(layer.purge.interval_minutes, Some(20));
+        assert_eq!(layer.uploads.max_bytes, Some(3000));
+    }
+
+    #[test]
+    fn test_cli_overrides_env_and_file() {
+        let layer = file_layer().merge(env_layer()).merge(cli_layer());
+
+        assert_eq!(layer.environment.as_deref(), Some("development"));
+        assert_eq!(layer.server.port, Some(6000));
+        assert_eq!(layer.database.max_connections, Some(12));
+        assert_eq!(
+            layer.cors.allowed_origins,
+            Some(vec!["https://cli.example.com".to_string()])
+        );
+        assert_eq!(layer.logging.format.as_deref(), Some("pretty"));
+        assert_eq!(layer.features.metrics, Some(false));
+    }
+
+    #[test]
+    fn test_unset_keys_fall_through_to_lower_layers() {
+        let layer = file_layer().merge(env_layer()).merge(cli_layer());
+
+        assert_eq!(layer.server.host.as_deref(), Some("127.0.0.1"));
+        assert_eq!(layer.database.url.as_deref(), Some("postgres://file/jos"));
+        assert_eq!(layer.database.min_connections, Some(1));
+        assert_eq!(layer.auth.jwt_expiration_hours, Some(72));
+        assert_eq!(layer.auth.refresh_token_expiration_hours, Some(240));
+        assert_eq!(layer.cors.max_age_secs, Some(60));
+        assert_eq!(layer.telemetry.service_name.as_deref(), Some("jos-env"));
+        assert_eq!(layer.telemetry.traces_filter.as_deref(), Some("info"));
+        assert_eq!(layer.features.purge_job, Some(true));
+        assert_eq!(layer.features.api_docs, None);
+        assert_eq!(layer.purge.interval_minutes, Some(20));
+        assert_eq!(layer.purge.deletion_grace_period_days, Some(7));
+        assert_eq!(layer.uploads.max_bytes, Some(3000));
+        assert_eq!(layer.uploads.attachment_max_bytes, Some(2000));
+    }
+}
@@ -0,0 +1,909 @@
+use crate::constants::*;
+use crate::setup::environment::Environment;
+use crate::setup::logging::LogFormat;
+use crate::setup::telemetry::TelemetryConfig;
+use axum::http::{HeaderName, HeaderValue, Method};
+use shared::Result;
+use shared::error::Error;
+use shared::error::SetupError;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{info, warn};
+use url::Url;
+
+pub mod cli;
+pub mod layer;
+
+pub use cli::Cli;
+use layer::ConfigLayer;
+
+/// Read from the working directory when neither `--config` nor
+/// `JOS_CONFIG` names a file. Running without one is fine.
+pub const DEFAULT_CONFIG_FILE: &str = "jos.toml";
+pub const DEFAULT_JWT_EXPIRATION_DURATION: Duration = Duration::from_hours(24);
+pub const DEFAULT_DELETION_GRACE_PERIOD: Duration = Duration::from_hours(24 * 30);
+pub const DEFAULT_PURGE_INTERVAL: Duration = Duration::from_hours(1);
+pub const DEFAULT_USER_EVENT_RETENTION: Duration = Duration::from_hours(24 * 7);
+pub const DEFAULT_READINESS_TIMEOUT: Duration = Duration::from_secs(2);
+pub const DEFAULT_SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+pub const DEFAULT_PRODUCTION_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+pub const DEFAULT_DB_MAX_CONNECTIONS: u32 = 10;
+pub const DEFAULT_DB_MIN_CONNECTIONS: u32 = 0;
+pub const DEFAULT_DB_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+pub const DEFAULT_MAIL_FROM: &str = "JOS <no-reply@localhost>";
+pub const DEFAULT_MAGIC_LINK_URL: &str = "http://localhost:3000/auth/magic-link";
+pub const DEFAULT_STORAGE_LOCAL_PATH: &str = "./data/uploads";
+pub const DEFAULT_S3_REGION: &str = "us-east-1";
+pub const DEFAULT_MAX_UPLOAD_BYTES: usize = 5 * 1024 * 1024;
+pub const DEFAULT_MAX_ATTACHMENT_BYTES: usize = 20 * 1024 * 1024;
+pub const DEFAULT_TABLE_ATTACHMENT_QUOTA_BYTES: u64 = 200 * 1024 * 1024;
+pub const DEFAULT_CORS_METHODS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"];
+pub const DEFAULT_CORS_HEADERS: &[&str] =
+    &["authorization", "content-type", "accept", "last-event-id"];
+pub const DEFAULT_CORS_MAX_AGE: Duration = Duration::from_mins(10);
+/// Matches any origin, method or header in the CORS lists.
+pub const CORS_WILDCARD: &str = "*";
+
+/// Where uploaded files are kept.
+#[derive(Debug, Clone)]
+pub enum StorageBackend {
+    Local {
+        path: String,
+    },
+    S3 {
+        bucket: String,
+        /// Custom endpoint for S3-compatible services such as MinIO.
+        endpoint: Option<String>,
+        region: String,
+        access_key_id: String,
+        secret_access_key: String,
+    },
+}
+
+impl Default for StorageBackend {
+    fn default() -> Self {
+        StorageBackend::Local {
+            path: DEFAULT_STORAGE_LOCAL_PATH.to_string(),
+        }
+    }
+}
+
+/// Database connection pool sizing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabasePoolConfig {
+    pub max_connections: u32,
+    /// Connections kept open even when idle.
+    pub min_connections: u32,
+    /// How long a query waits for a free connection before failing.
+    pub acquire_timeout: Duration,
+}
+
+impl Default for DatabasePoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: DEFAULT_DB_MAX_CONNECTIONS,
+            min_connections: DEFAULT_DB_MIN_CONNECTIONS,
+            acquire_timeout: DEFAULT_DB_ACQUIRE_TIMEOUT,
+        }
+    }
+}
+
+/// Optional parts of the server, all on by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureToggles {
+    /// Swagger UI at `/docs` and the OpenAPI document.
+    pub api_docs: bool,
+    /// Prometheus endpoint at `/metrics`.
+    pub metrics: bool,
+    /// Only one instance of a deployment needs to run the purge job.
+    pub purge_job: bool,
+}
+
+impl Default for FeatureToggles {
+    fn default() -> Self {
+        Self {
+            api_docs: true,
+            metrics: true,
+            purge_job: true,
+        }
+    }
+}
+
+/// Cross-origin access to the API. Any list may be `*` to allow everything,
+/// except when credentials are allowed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub allow_credentials: bool,
+    /// How long browsers may cache a preflight response.
+    pub max_age: Duration,
+}
+
+impl CorsConfig {
+    /// Development accepts any origin so local frontends work out of the
+    /// box. Production allows no cross-origin access until origins are set.
+    pub fn for_environment(environment: Environment) -> Self {
+        let allowed_origins = match environment {
+            Environment::Development => vec![CORS_WILDCARD.to_string()],
+            Environment::Production => Vec::new(),
+        };
+
+        Self {
+            allowed_origins,
+            allowed_methods: DEFAULT_CORS_METHODS.iter().map(|m| m.to_string()).collect(),
+            allowed_headers: DEFAULT_CORS_HEADERS.iter().map(|h| h.to_string()).collect(),
+            allow_credentials: false,
+            max_age: DEFAULT_CORS_MAX_AGE,
+        }
+    }
+
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|origin| origin == CORS_WILDCARD)
+    }
+
+    pub fn allows_any_method(&self) -> bool {
+        self.allowed_methods
+            .iter()
+            .any(|method| method == CORS_WILDCARD)
+    }
+
+    pub fn allows_any_header(&self) -> bool {
+        self.allowed_headers
+            .iter()
+            .any(|header| header == CORS_WILDCARD)
+    }
+
+    /// Lists every problem with the policy. Browsers refuse credentialed
+    /// responses to wildcards, so that combination is rejected outright.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.allow_credentials {
+            let wildcards = [
+                ("cors.allowed_origins", self.allows_any_origin()),
+                ("cors.allowed_methods", self.allows_any_method()),
+                ("cors.allowed_headers", self.allows_any_header()),
+            ];
+            for (name, wildcard) in wildcards {
+                if wildcard {
+                    problems.push(format!(
+                        "{name}: cannot be '{CORS_WILDCARD}' when cors.allow_credentials is enabled"
+                    ));
+                }
+            }
+        }
+
+        for origin in self.allowed_origins.iter().filter(|o| *o != CORS_WILDCARD) {
+            if HeaderValue::from_str(origin).is_err() || !origin.contains("://") {
+                problems.push(format!("cors.allowed_origins: invalid origin '{origin}'"));
+            }
+        }
+        for method in self.allowed_methods.iter().filter(|m| *m != CORS_WILDCARD) {
+            if Method::from_str(method).is_err() {
+                problems.push(format!("cors.allowed_methods: invalid method '{method}'"));
+            }
+        }
+        for header in self.allowed_headers.iter().filter(|h| *h != CORS_WILDCARD) {
+            if HeaderName::from_str(header).is_err() {
+                problems.push(format!("cors.allowed_headers: invalid header '{header}'"));
+            }
+        }
+
+        problems
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub addr: SocketAddr,
+    pub database_url: String,
+    pub database_pool: DatabasePoolConfig,
+    pub jwt_secret: String,
+    pub jwt_expiration_duration: Duration,
+    pub refresh_token_expiration_duration: Duration,
+    pub deletion_grace_period: Duration,
+    pub purge_interval: Duration,
+    /// How long live-update events stay available for stream resumption.
+    pub user_event_retention: Duration,
+    /// Upper bound for each dependency check behind `/health/ready`.
+    pub readiness_timeout: Duration,
+    /// How long in-flight requests and background tasks get to finish once a
+    /// shutdown signal arrives.
+    pub shutdown_drain_timeout: Duration,
+    /// How long the server keeps accepting connections while reporting not
+    /// ready, before it starts draining.
+    pub shutdown_grace_period: Duration,
+    pub smtp_url: Option<String>,
+    pub mail_from: String,
+    pub magic_link_url: Url,
+    pub storage: StorageBackend,
+    pub cors: CorsConfig,
+    pub features: FeatureToggles,
+    pub max_upload_bytes: usize,
+    pub max_attachment_bytes: usize,
+    pub table_attachment_quota_bytes: u64,
+    pub environment: Environment,
+    pub log_format: LogFormat,
+    pub telemetry: TelemetryConfig,
+    /// Where settings came from and fallbacks worth a warning. Loading
+    /// happens before logging is set up, so they are logged afterwards.
+    sources: Vec<String>,
+    notices: Vec<String>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            addr: SocketAddr::from_str("127.0.0.1:8080").unwrap(),
+            database_url: "".to_string(),
+            database_pool: DatabasePoolConfig::default(),
+            jwt_secret: "secret".to_string(),
+            jwt_expiration_duration: DEFAULT_JWT_EXPIRATION_DURATION,
+            refresh_token_expiration_duration: DEFAULT_JWT_EXPIRATION_DURATION,
+            deletion_grace_period: DEFAULT_DELETION_GRACE_PERIOD,
+            purge_interval: DEFAULT_PURGE_INTERVAL,
+            user_event_retention: DEFAULT_USER_EVENT_RETENTION,
+            readiness_timeout: DEFAULT_READINESS_TIMEOUT,
+            shutdown_drain_timeout: DEFAULT_SHUTDOWN_DRAIN_TIMEOUT,
+            shutdown_grace_period: Duration::ZERO,
+            smtp_url: None,
+            mail_from: DEFAULT_MAIL_FROM.to_string(),
+            magic_link_url: Url::parse(DEFAULT_MAGIC_LINK_URL).unwrap(),
+            storage: StorageBackend::default(),
+            cors: CorsConfig::for_environment(Environment::Development),
+            features: FeatureToggles::default(),
+            max_upload_bytes: DEFAULT_MAX_UPLOAD_BYTES,
+            max_attachment_bytes: DEFAULT_MAX_ATTACHMENT_BYTES,
+            table_attachment_quota_bytes: DEFAULT_TABLE_ATTACHMENT_QUOTA_BYTES,
+            environment: Environment::Development,
+            log_format: LogFormat::default(),
+            telemetry: TelemetryConfig::default(),
+            sources: Vec::new(),
+            notices: Vec::new(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Loads the configuration from, in increasing precedence: built-in
+    /// defaults, the TOML config file, environment variables (including
+    /// `.env`) and command-line flags. Every invalid setting is reported in
+    /// one error rather than failing on the first.
+    pub fn load(cli: &Cli) -> Result<Self> {
+        let env_file_loaded = dotenvy::dotenv().is_ok();
+        let mut problems = Vec::new();
+        let mut sources = vec!["defaults".to_string()];
+
+        let file = match Self::config_file_path(cli) {
+            Some(path) => {
+                sources.push(path.display().to_string());
+                ConfigLayer::from_file(&path, &mut problems)
+            }
+            None => ConfigLayer::default(),
+        };
+
+        sources.push(if env_file_loaded {
+            "environment (with .env)".to_string()
+        } else {
+            "environment".to_string()
+        });
+        let env = ConfigLayer::from_env(&mut problems);
+
+        // `--config` only picks the file, it does not override anything.
+        let overrides = Cli {
+            config: None,
+            ..cli.clone()
+        };
+        if overrides != Cli::default() {
+            sources.push("command line".to_string());
+        }
+
+        let layer = file.merge(env).merge(cli.to_layer());
+        let mut config = Self::resolve(layer, &mut problems);
+
+        if !problems.is_empty() {
+            return Err(Error::Setup(SetupError::InvalidConfiguration(format!(
+                "{} problem(s)\n  - {}",
+                problems.len(),
+                problems.join("\n  - ")
+            ))));
+        }
+
+        config.sources = sources;
+        Ok(config)
+    }
+
+    /// An explicitly named file must exist; the default one is optional.
+    fn config_file_path(cli: &Cli) -> Option<PathBuf> {
+        cli.config
+            .clone()
+            .or_else(|| std::env::var("JOS_CONFIG").ok().map(PathBuf::from))
+            .or_else(|| {
+                let path = PathBuf::from(DEFAULT_CONFIG_FILE);
+                path.exists().then_some(path)
+            })
+    }
+
+    fn resolve(layer: ConfigLayer, problems: &mut Vec<String>) -> Self {
+        let mut notices = Vec::new();
+
+        let environment = match layer.environment.as_deref() {
+            Some(value) => value.parse().unwrap_or_else(|_| {
+                problems.push(format!(
+                    "environment: '{value}' is not 'development' or 'production'"
+                ));
+                Environment::default()
+            }),
+            None => Environment::default(),
+        };
+
+        let host = layer.server.host.as_deref().unwrap_or(DEFAULT_HOST);
+        let port = layer.server.port.unwrap_or(DEFAULT_PORT);
+        let addr = match IpAddr::from_str(host) {
+            Ok(ip) => SocketAddr::new(ip, port),
+            Err(_) => {
+                problems.push(format!("server.host: '{host}' is not an IP address"));
+                SocketAddr::new(IpAddr::from_str(DEFAULT_HOST).unwrap(), port)
+            }
+        };
+
+        let readiness_timeout = layer
+            .server
+            .readiness_timeout_ms
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_READINESS_TIMEOUT);
+        if readiness_timeout.is_zero() {
+            problems.push("server.readiness_timeout_ms: must be greater than 0".to_string());
+        }
+
+        let shutdown_drain_timeout = layer
+            .server
+            .shutdown_drain_timeout_secs
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_SHUTDOWN_DRAIN_TIMEOUT);
+
+        // Only deployments behind a load balancer need time to stop routing.
+        let shutdown_grace_period = layer
+            .server
+            .shutdown_grace_period_secs
+            .map(Duration::from_secs)
+            .unwrap_or(match environment {
+                Environment::Production => DEFAULT_PRODUCTION_SHUTDOWN_GRACE_PERIOD,
+                Environment::Development => Duration::ZERO,
+            });
+
+        let database_url = layer.database.url.unwrap_or_else(|| {
+            problems.push("database.url: required (or set DATABASE_URL)".to_string());
+            String::new()
+        });
+
+        let database_pool = DatabasePoolConfig {
+            max_connections: layer
+                .database
+                .max_connections
+                .unwrap_or(DEFAULT_DB_MAX_CONNECTIONS),
+            min_connections: layer
+                .database
+                .min_connections
+                .unwrap_or(DEFAULT_DB_MIN_CONNECTIONS),
+            acquire_timeout: layer
+                .database
+                .acquire_timeout_secs
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_DB_ACQUIRE_TIMEOUT),
+        };
+        if database_pool.max_connections == 0 {
+            problems.push("database.max_connections: must be greater than 0".to_string());
+        }
+        if database_pool.min_connections > database_pool.max_connections {
+            problems.push(format!(
+                "database.min_connections: {} exceeds database.max_connections ({})",
+                database_pool.min_connections, database_pool.max_connections
+            ));
+        }
+        if database_pool.acquire_timeout.is_zero() {
+            problems.push("database.acquire_timeout_secs: must be greater than 0".to_string());
+        }
+
+        let jwt_secret = match (layer.auth.jwt_secret, environment) {
+            (Some(secret), Environment::Production) if secret.len() < MIN_JWT_SECRET_LEN => {
+                problems.push(format!(
+                    "auth.jwt_secret: must be at least {MIN_JWT_SECRET_LEN} characters in production"
+                ));
+                secret
+            }
+            (Some(secret), _) => secret,
+            (None, Environment::Production) => {
+                problems.push(
+                    "auth.jwt_secret: required in production (or set JWT_SECRET)".to_string(),
+                );
+                String::new()
+            }
+            (None, Environment::Development) => {
+                notices.push("JWT_SECRET not set, using the development default".to_string());
+                DEFAULT_JWT_SECRET.into()
+            }
+        };
+
+        let mut jwt_expiration_duration = layer
+            .auth
+            .jwt_expiration_hours
+            .map(Duration::from_hours)
+            .unwrap_or(DEFAULT_JWT_EXPIRATION_DURATION);
+
+        if jwt_expiration_duration < Duration::from_hours(24) {
+            notices.push("JWT expiration is less than 1 day, setting to 1 day".to_string());
+            jwt_expiration_duration = DEFAULT_JWT_EXPIRATION_DURATION;
+        }
+
+        // Refresh tokens used to share the access token lifetime, which
+        // remains the default.
+        let refresh_token_expiration_duration = layer
+            .auth
+            .refresh_token_expiration_hours
+            .map(Duration::from_hours)
+            .unwrap_or(jwt_expiration_duration);
+        if refresh_token_expiration_duration.is_zero() {
+            problems
+                .push("auth.refresh_token_expiration_hours: must be greater than 0".to_string());
+        }
+
+        let cors_defaults = CorsConfig::for_environment(environment);
+        let cors = CorsConfig {
+            allowed_origins: layer
+                .cors
+                .allowed_origins
+                .unwrap_or(cors_defaults.allowed_origins),
+            allowed_methods: layer
+                .cors
+                .allowed_methods
+                .map(|methods| methods.iter().map(|m| m.to_ascii_uppercase()).collect())
+                .unwrap_or(cors_defaults.allowed_methods),
+            allowed_headers: layer
+                .cors
+                .allowed_headers
+                .unwrap_or(cors_defaults.allowed_headers),
+            allow_credentials: layer
+                .cors
+                .allow_credentials
+                .unwrap_or(cors_defaults.allow_credentials),
+            max_age: layer
+                .cors
+                .max_age_secs
+                .map(Duration::from_secs)
+                .unwrap_or(cors_defaults.max_age),
+        };
+        problems.extend(cors.validate());
+
+        if environment == Environment::Production && cors.allowed_origins.is_empty() {
+            notices.push(
+                "No CORS origins configured, cross-origin requests will be refused".to_string(),
+            );
+        }
+
+        let log_format = match layer.logging.format.as_deref() {
+            Some(value) => value.parse().unwrap_or_else(|_| {
+                problems.push(format!(
+                    "logging.format: '{value}' is not 'pretty' or 'json'"
+                ));
+                LogFormat::for_environment(environment)
+            }),
+            None => LogFormat::for_environment(environment),
+        };
+
+        let feature_defaults = FeatureToggles::default();
+        let features = FeatureToggles {
+            api_docs: layer.features.api_docs.unwrap_or(feature_defaults.api_docs),
+            metrics: layer.features.metrics.unwrap_or(feature_defaults.metrics),
+            purge_job: layer
+                .features
+                .purge_job
+                .unwrap_or(feature_defaults.purge_job),
+        };
+
+        let telemetry_defaults = TelemetryConfig::default();
+        let telemetry = TelemetryConfig {
+            enabled: layer
+                .telemetry
+                .enabled
+                .unwrap_or(telemetry_defaults.enabled),
+            otlp_endpoint: layer
+                .telemetry
+                .otlp_endpoint
+                .unwrap_or(telemetry_defaults.otlp_endpoint),
+            service_name: layer
+                .telemetry
+                .service_name
+                .unwrap_or(telemetry_defaults.service_name),
+            traces_filter: layer
+                .telemetry
+                .traces_filter
+                .unwrap_or(telemetry_defaults.traces_filter),
+        };
+        problems.extend(telemetry.validate());
+
+        // A grace period of zero purges deleted data on the next run.
+        let deletion_grace_period = layer
+            .purge
+            .deletion_grace_period_days
+            .map(|days| Duration::from_hours(days * 24))
+            .unwrap_or(DEFAULT_DELETION_GRACE_PERIOD);
+
+        let purge_interval = layer
+            .purge
+            .interval_minutes
+            .map(Duration::from_mins)
+            .unwrap_or(DEFAULT_PURGE_INTERVAL);
+        if purge_interval.is_zero() {
+            problems.push("purge.interval_minutes: must be greater than 0".to_string());
+        }
+
+        let user_event_retention = layer
+            .purge
+            .user_event_retention_days
+            .map(|days| Duration::from_hours(days * 24))
+            .unwrap_or(DEFAULT_USER_EVENT_RETENTION);
+        if user_event_retention.is_zero() {
+            problems.push("purge.user_event_retention_days: must be greater than 0".to_string());
+        }
+
+        let max_upload_bytes = layer.uploads.max_bytes.unwrap_or(DEFAULT_MAX_UPLOAD_BYTES);
+        if max_upload_bytes == 0 {
+            problems.push("uploads.max_bytes: must be greater than 0".to_string());
+        }
+
+        let max_attachment_bytes = layer
+            .uploads
+            .attachment_max_bytes
+            .unwrap_or(DEFAULT_MAX_ATTACHMENT_BYTES);
+        if max_attachment_bytes == 0 {
+            problems.push("uploads.attachment_max_bytes: must be greater than 0".to_string());
+        }
+
+        let table_attachment_quota_bytes = layer
+            .uploads
+            .table_attachment_quota_bytes
+            .unwrap_or(DEFAULT_TABLE_ATTACHMENT_QUOTA_BYTES);
+        if table_attachment_quota_bytes == 0 {
+            problems
+                .push("uploads.table_attachment_quota_bytes: must be greater than 0".to_string());
+        }
+
+        // The settings below are only read from the environment.
+
+        let smtp_url = std::env::var("SMTP_URL").ok();
+
+        if smtp_url.is_none() {
+            notices.push("SMTP_URL not set, outgoing emails will only be logged".to_string());
+        }
+
+        let mail_from = std::env::var("MAIL_FROM").unwrap_or_else(|_| DEFAULT_MAIL_FROM.into());
+        let magic_link_url = std::env::var("MAGIC_LINK_URL")
+            .ok()
+            .and_then(|value| {
+                Url::parse(&value)
+                    .map_err(|_| problems.push(format!("MAGIC_LINK_URL: invalid URL '{value}'")))
+                    .ok()
+            })
+            .unwrap_or_else(|| Url::parse(DEFAULT_MAGIC_LINK_URL).unwrap());
+
+        let storage = Self::storage_from_env(problems);
+
+        Self {
+            database_url,
+            database_pool,
+            addr,
+            jwt_secret,
+            jwt_expiration_duration,
+            refresh_token_expiration_duration,
+            deletion_grace_period,
+            purge_interval,
+            user_event_retention,
+            readiness_timeout,
+            shutdown_drain_timeout,
+            shutdown_grace_period,
+            smtp_url,
+            mail_from,
+            magic_link_url,
+            storage,
+            cors,
+            features,
+            max_upload_bytes,
+            max_attachment_bytes,
+            table_attachment_quota_bytes,
+            environment,
+            log_format,
+            telemetry,
+            sources: Vec::new(),
+            notices,
+        }
+    }
+
+    fn storage_from_env(problems: &mut Vec<String>) -> StorageBackend {
+        let backend = std::env::var("STORAGE_BACKEND").unwrap_or_else(|_| "local".into());
+
+        match backend.to_ascii_lowercase().as_str() {
+            "local" => StorageBackend::Local {
+                path: std::env::var("STORAGE_LOCAL_PATH")
+                    .unwrap_or_else(|_| DEFAULT_STORAGE_LOCAL_PATH.into()),
+            },
+            "s3" => {
+                let mut required = |name: &str| {
+                    std::env::var(name).unwrap_or_else(|_| {
+                        problems.push(format!("{name}: required when STORAGE_BACKEND is 's3'"));
+                        String::new()
+                    })
+                };
+
+                StorageBackend::S3 {
+                    bucket: required("S3_BUCKET"),
+                    endpoint: std::env::var("S3_ENDPOINT").ok(),
+                    region: std::env::var("S3_REGION").unwrap_or_else(|_| DEFAULT_S3_REGION.into()),
+                    access_key_id: required("S3_ACCESS_KEY_ID"),
+                    secret_access_key: required("S3_SECRET_ACCESS_KEY"),
+                }
+            }
+            other => {
+                problems.push(format!(
+                    "STORAGE_BACKEND: unknown backend '{}', expected 'local' or 's3'",
+                    other
+                ));
+                StorageBackend::default()
+            }
+        }
+    }
+
+    /// Logs where the configuration came from and any fallbacks applied
+    /// while loading it.
+    pub fn log_notices(&self) {
+        info!("⚙️  Configuration loaded from {}", self.sources.join(" < "));
+        info!("⚠ Running as {:?}", self.environment);
+
+        for notice in &self.notices {
+            warn!("⚠ {}", notice);
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        format!(
+            "Server: {}, Database: {}, JWT Expiration: {} days",
+            self.addr,
+            self.database_url
+                .split('@')
+                .next_back()
+                .unwrap_or("unknown"),
+            (self.jwt_expiration_duration.as_secs() / (60 * 60 * 24))
+        )
+    }
+
+    pub fn display_startup_info(&self) {
+        info!("🚀 Starting JOS (Join Our Session) API");
+        info!(
+            "📊 Environment: {}",
+            if cfg!(debug_assertions) {
+                "Development"
+            } else {
+                "Production"
+            }
+        );
+        info!("🌐 Server will bind to: {}", self.addr);
+        info!(
+            "🗄️  Database: {}",
+            self.database_url
+                .split('@')
+                .next_back()
+                .unwrap_or("unknown")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use layer::*;
+
+    fn cors(origins: &[&str]) -> CorsConfig {
+        CorsConfig {
+            allowed_origins: origins.iter().map(|origin| origin.to_string()).collect(),
+            ..CorsConfig::for_environment(Environment::Production)
+        }
+    }
+
+    #[test]
+    fn test_cors_accepts_valid_origins() {
+        let config = cors(&["https://app.example.com", "http://localhost:3000"]);
+
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn test_cors_rejects_malformed_origins() {
+        let config = cors(&["app.example.com", "https://bad\norigin"]);
+
+        assert_eq!(
+            config.validate(),
+            vec![
+                "cors.allowed_origins: invalid origin 'app.example.com'",
+                "cors.allowed_origins: invalid origin 'https://bad\norigin'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cors_rejects_malformed_methods_and_headers() {
+        let config = CorsConfig {
+            allowed_methods: vec!["GET".to_string(), "NOT A METHOD".to_string()],
+            allowed_headers: vec!["x-request-id".to_string(), "bad header".to_string()],
+            ..cors(&[])
+        };
+
+        assert_eq!(
+            config.validate(),
+            vec![
+                "cors.allowed_methods: invalid method 'NOT A METHOD'",
+                "cors.allowed_headers: invalid header 'bad header'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cors_wildcard_origin_without_credentials() {
+        let config = cors(&[CORS_WILDCARD]);
+
+        assert!(config.allows_any_origin());
+        assert!(config.validate().is_empty());
+    }
+
+    #[test]
+    fn test_cors_rejects_wildcards_with_credentials() {
+        let config = CorsConfig {
+            allowed_methods: vec![CORS_WILDCARD.to_string()],
+            allow_credentials: true,
+            ..cors(&[CORS_WILDCARD])
+        };
+
+        assert_eq!(
+            config.validate(),
+            vec![
+                "cors.allowed_origins: cannot be '*' when cors.allow_credentials is enabled",
+                "cors.allowed_methods: cannot be '*' when cors.allow_credentials is enabled",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cors_allows_credentials_with_explicit_origins() {
+        let config = CorsConfig {
+            allow_credentials: true,
+            ..cors(&["https://app.example.com"])
+        };
+
+        assert!(config.validate().is_empty());
+    }
+
+    fn with_database(layer: ConfigLayer) -> ConfigLayer {
+        ConfigLayer {
+            database: DatabaseLayer {
+                url: Some("postgres://localhost/jos".to_string()),
+                ..layer.database
+            },
+            ..layer
+        }
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_defaults() {
+        let mut problems = Vec::new();
+        let config = AppConfig::resolve(with_database(ConfigLayer::default()), &mut problems);
+
+        assert!(problems.is_empty(), "{problems:?}");
+        assert_eq!(config.environment, Environment::Development);
+        assert_eq!(
+            config.addr.to_string(),
+            format!("{DEFAULT_HOST}:{DEFAULT_PORT}")
+        );
+        assert_eq!(
+            config.database_pool.max_connections,
+            DEFAULT_DB_MAX_CONNECTIONS
+        );
+        assert_eq!(
+            config.jwt_expiration_duration,
+            DEFAULT_JWT_EXPIRATION_DURATION
+        );
+        assert_eq!(config.cors.max_age, DEFAULT_CORS_MAX_AGE);
+        assert_eq!(config.log_format, LogFormat::Pretty);
+        assert_eq!(
+            config.telemetry.service_name,
+            TelemetryConfig::default().service_name
+        );
+        assert_eq!(config.features, FeatureToggles::default());
+        assert_eq!(config.purge_interval, DEFAULT_PURGE_INTERVAL);
+        assert_eq!(config.max_upload_bytes, DEFAULT_MAX_UPLOAD_BYTES);
+    }
+
+    #[test]
+    fn test_resolve_applies_layers_in_precedence_order() {
+        let file: ConfigLayer = toml::from_str(
+            r#"
+            [server]
+            host = "127.0.0.1"
+            port = 4000
+
+            [database]
+            max_connections = 4
+
+            [auth]
+            jwt_expiration_hours = 48
+
+            [cors]
+            max_age_secs = 60
+
+            [logging]
+            format = "json"
+
+            [telemetry]
+            service_name = "jos-file"
+
+            [features]
+            metrics = false
+
+            [purge]
+            interval_minutes = 10
+
+            [uploads]
+            max_bytes = 1000
+            "#,
+        )
+        .unwrap();
+        let env = ConfigLayer {
+            server: ServerLayer {
+                port: Some(5000),
+                ..Default::default()
+            },
+            auth: AuthLayer {
+                jwt_expiration_hours: Some(72),
+                ..Default::default()
+            },
+            telemetry: TelemetryLayer {
+                service_name: Some("jos-env".to_string()),
+                ..Default::default()
+            },
+            features: FeaturesLayer {
+                metrics: Some(true),
+                ..Default::default()
+            },
+            purge: PurgeLayer {
+                interval_minutes: Some(20),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let cli = Cli::try_parse_from(["jos", "--port", "6000", "--disable", "metrics"])
+            .unwrap()
+            .to_layer();
+
+        let mut problems = Vec::new();
+        let config = AppConfig::resolve(with_database(file.merge(env).merge(cli)), &mut problems);
+
+        assert!(problems.is_empty(), "{problems:?}");
+        // CLI over environment over file.
+        assert_eq!(config.addr.to_string(), "127.0.0.1:6000");
+        assert!(!config.features.metrics);
+        // Environment over file.
+        assert_eq!(config.jwt_expiration_duration, Duration::from_hours(72));
+        assert_eq!(config.telemetry.service_name, "jos-env");
+        assert_eq!(config.purge_interval, Duration::from_mins(20));
+        // File over defaults.
+        assert_eq!(config.database_pool.max_connections, 4);
+        assert_eq!(config.cors.max_age, Duration::from_secs(60));
+        assert_eq!(config.log_format, LogFormat::Json);
+        assert_eq!(config.max_upload_bytes, 1000);
+    }
+}
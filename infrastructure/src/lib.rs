@@ -1,6 +1,9 @@
+pub mod chat;
 pub mod config;
 pub mod constants;
+pub mod mail;
 pub mod persistence;
 pub mod security;
 pub mod setup;
 pub mod state;
+pub mod storage;
@@ -0,0 +1,19 @@
+use domain::mail::{EmailMessage, Mailer};
+use shared::Result;
+use tracing::info;
+
+/// Development mailer used when no SMTP server is configured. Messages are
+/// written to the log instead of being delivered.
+#[derive(Clone, Default)]
+pub struct LogMailer;
+
+#[async_trait::async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: EmailMessage) -> Result<()> {
+        info!(
+            "📧 Email to {} — {}\n{}",
+            message.to, message.subject, message.body
+        );
+        Ok(())
+    }
+}
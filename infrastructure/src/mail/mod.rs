@@ -0,0 +1,5 @@
+pub mod log;
+pub mod smtp;
+
+pub use log::*;
+pub use smtp::*;
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{AccountExport, ExportStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[sqlx(type_name = "export_status", rename_all = "lowercase")]
+pub enum EExportStatus {
+    Pending,
+    Ready,
+    Failed,
+}
+
+impl From<ExportStatus> for EExportStatus {
+    fn from(status: ExportStatus) -> Self {
+        match status {
+            ExportStatus::Pending => EExportStatus::Pending,
+            ExportStatus::Ready => EExportStatus::Ready,
+            ExportStatus::Failed => EExportStatus::Failed,
+        }
+    }
+}
+
+impl From<EExportStatus> for ExportStatus {
+    fn from(status: EExportStatus) -> Self {
+        match status {
+            EExportStatus::Pending => ExportStatus::Pending,
+            EExportStatus::Ready => ExportStatus::Ready,
+            EExportStatus::Failed => ExportStatus::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountExportModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub status: EExportStatus,
+    pub payload: Option<Value>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<AccountExportModel> for AccountExport {
+    fn from(model: AccountExportModel) -> Self {
+        AccountExport {
+            id: model.id,
+            user_id: model.user_id,
+            status: model.status.into(),
+            payload: model.payload,
+            error: model.error,
+            created_at: model.created_at,
+            completed_at: model.completed_at,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{
+    Announcement, AnnouncementComment, AnnouncementListing, AnnouncementReadReceipt,
+};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnouncementModel {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AnnouncementModel> for Announcement {
+    fn from(model: AnnouncementModel) -> Self {
+        Announcement {
+            id: model.id,
+            table_id: model.table_id,
+            author_id: model.author_id,
+            title: model.title,
+            body: model.body,
+            pinned: model.pinned,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnouncementListingModel {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub pinned: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub comment_count: i64,
+    pub read_count: i64,
+    pub read: bool,
+}
+
+impl From<AnnouncementListingModel> for AnnouncementListing {
+    fn from(model: AnnouncementListingModel) -> Self {
+        AnnouncementListing {
+            announcement: Announcement {
+                id: model.id,
+                table_id: model.table_id,
+                author_id: model.author_id,
+                title: model.title,
+                body: model.body,
+                pinned: model.pinned,
+                created_at: model.created_at,
+                updated_at: model.updated_at,
+            },
+            comment_count: model.comment_count as u32,
+            read_count: model.read_count as u32,
+            read: model.read,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnouncementReadReceiptModel {
+    pub user_id: Uuid,
+    pub username: String,
+    pub read_at: DateTime<Utc>,
+}
+
+impl From<AnnouncementReadReceiptModel> for AnnouncementReadReceipt {
+    fn from(model: AnnouncementReadReceiptModel) -> Self {
+        AnnouncementReadReceipt {
+            user_id: model.user_id,
+            username: model.username,
+            read_at: model.read_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnouncementCommentModel {
+    pub id: Uuid,
+    pub announcement_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AnnouncementCommentModel> for AnnouncementComment {
+    fn from(model: AnnouncementCommentModel) -> Self {
+        AnnouncementComment {
+            id: model.id,
+            announcement_id: model.announcement_id,
+            author_id: model.author_id,
+            body: model.body,
+            created_at: model.created_at,
+        }
+    }
+}
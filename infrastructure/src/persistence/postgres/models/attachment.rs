@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use domain::entities::Attachment;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttachmentModel {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub uploaded_by: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AttachmentModel> for Attachment {
+    fn from(model: AttachmentModel) -> Self {
+        Attachment {
+            id: model.id,
+            table_id: model.table_id,
+            session_id: model.session_id,
+            uploaded_by: model.uploaded_by,
+            file_name: model.file_name,
+            content_type: model.content_type,
+            size_bytes: model.size_bytes as u64,
+            storage_key: model.storage_key,
+            created_at: model.created_at,
+        }
+    }
+}
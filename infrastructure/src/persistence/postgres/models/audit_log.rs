@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{AuditAction, AuditEntityType, AuditLog};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[sqlx(type_name = "audit_action", rename_all = "snake_case")]
+pub enum EAuditAction {
+    UserRegistered,
+    LoginSucceeded,
+    LoginFailed,
+    Logout,
+    PasswordChanged,
+    UserSuspended,
+    UserUnsuspended,
+    UserRoleChanged,
+    TableUpdated,
+    TableDeleted,
+    SessionUpdated,
+    SessionDeleted,
+    RequestApproved,
+    RequestRejected,
+    AccountDeleted,
+    AccountRestored,
+    TableRestored,
+    SessionRestored,
+    MagicLinkRequested,
+}
+
+impl From<AuditAction> for EAuditAction {
+    fn from(action: AuditAction) -> Self {
+        match action {
+            AuditAction::UserRegistered => EAuditAction::UserRegistered,
+            AuditAction::LoginSucceeded => EAuditAction::LoginSucceeded,
+            AuditAction::LoginFailed => EAuditAction::LoginFailed,
+            AuditAction::Logout => EAuditAction::Logout,
+            AuditAction::PasswordChanged => EAuditAction::PasswordChanged,
+            AuditAction::UserSuspended => EAuditAction::UserSuspended,
+            AuditAction::UserUnsuspended => EAuditAction::UserUnsuspended,
+            AuditAction::UserRoleChanged => EAuditAction::UserRoleChanged,
+            AuditAction::TableUpdated => EAuditAction::TableUpdated,
+            AuditAction::TableDeleted => EAuditAction::TableDeleted,
+            AuditAction::SessionUpdated => EAuditAction::SessionUpdated,
+            AuditAction::SessionDeleted => EAuditAction::SessionDeleted,
+            AuditAction::RequestApproved => EAuditAction::RequestApproved,
+            AuditAction::RequestRejected => EAuditAction::RequestRejected,
+            AuditAction::AccountDeleted => EAuditAction::AccountDeleted,
+            AuditAction::AccountRestored => EAuditAction::AccountRestored,
+            AuditAction::TableRestored => EAuditAction::TableRestored,
+            AuditAction::SessionRestored => EAuditAction::SessionRestored,
+            AuditAction::MagicLinkRequested => EAuditAction::MagicLinkRequested,
+        }
+    }
+}
+
+impl From<EAuditAction> for AuditAction {
+    fn from(action: EAuditAction) -> Self {
+        match action {
+            EAuditAction::UserRegistered => AuditAction::UserRegistered,
+            EAuditAction::LoginSucceeded => AuditAction::LoginSucceeded,
+            EAuditAction::LoginFailed => AuditAction::LoginFailed,
+            EAuditAction::Logout => AuditAction::Logout,
+            EAuditAction::PasswordChanged => AuditAction::PasswordChanged,
+            EAuditAction::UserSuspended => AuditAction::UserSuspended,
+            EAuditAction::UserUnsuspended => AuditAction::UserUnsuspended,
+            EAuditAction::UserRoleChanged => AuditAction::UserRoleChanged,
+            EAuditAction::TableUpdated => AuditAction::TableUpdated,
+            EAuditAction::TableDeleted => AuditAction::TableDeleted,
+            EAuditAction::SessionUpdated => AuditAction::SessionUpdated,
+            EAuditAction::SessionDeleted => AuditAction::SessionDeleted,
+            EAuditAction::RequestApproved => AuditAction::RequestApproved,
+            EAuditAction::RequestRejected => AuditAction::RequestRejected,
+            EAuditAction::AccountDeleted => AuditAction::AccountDeleted,
+            EAuditAction::AccountRestored => AuditAction::AccountRestored,
+            EAuditAction::TableRestored => AuditAction::TableRestored,
+            EAuditAction::SessionRestored => AuditAction::SessionRestored,
+            EAuditAction::MagicLinkRequested => AuditAction::MagicLinkRequested,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[sqlx(type_name = "audit_entity", rename_all = "snake_case")]
+pub enum EAuditEntity {
+    User,
+    Table,
+    Session,
+    TableRequest,
+}
+
+impl From<AuditEntityType> for EAuditEntity {
+    fn from(entity: AuditEntityType) -> Self {
+        match entity {
+            AuditEntityType::User => EAuditEntity::User,
+            AuditEntityType::Table => EAuditEntity::Table,
+            AuditEntityType::Session => EAuditEntity::Session,
+            AuditEntityType::TableRequest => EAuditEntity::TableRequest,
+        }
+    }
+}
+
+impl From<EAuditEntity> for AuditEntityType {
+    fn from(entity: EAuditEntity) -> Self {
+        match entity {
+            EAuditEntity::User => AuditEntityType::User,
+            EAuditEntity::Table => AuditEntityType::Table,
+            EAuditEntity::Session => AuditEntityType::Session,
+            EAuditEntity::TableRequest => AuditEntityType::TableRequest,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogModel {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: EAuditAction,
+    pub entity_type: EAuditEntity,
+    pub entity_id: Uuid,
+    pub table_id: Option<Uuid>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditLogModel> for AuditLog {
+    fn from(model: AuditLogModel) -> Self {
+        AuditLog {
+            id: model.id,
+            actor_id: model.actor_id,
+            action: model.action.into(),
+            entity_type: model.entity_type.into(),
+            entity_id: model.entity_id,
+            table_id: model.table_id,
+            before: model.before,
+            after: model.after,
+            created_at: model.created_at,
+        }
+    }
+}
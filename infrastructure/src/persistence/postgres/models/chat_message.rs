@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use domain::entities::ChatMessage;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChatMessageModel {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub author_id: Uuid,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ChatMessageModel> for ChatMessage {
+    fn from(model: ChatMessageModel) -> Self {
+        ChatMessage {
+            id: model.id,
+            table_id: model.table_id,
+            author_id: model.author_id,
+            body: model.body,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{Image, ImageKind};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "image_kind", rename_all = "snake_case")]
+pub enum EImageKind {
+    TableCover,
+    Avatar,
+}
+
+impl From<ImageKind> for EImageKind {
+    fn from(kind: ImageKind) -> Self {
+        match kind {
+            ImageKind::TableCover => EImageKind::TableCover,
+            ImageKind::Avatar => EImageKind::Avatar,
+        }
+    }
+}
+
+impl From<EImageKind> for ImageKind {
+    fn from(kind: EImageKind) -> Self {
+        match kind {
+            EImageKind::TableCover => ImageKind::TableCover,
+            EImageKind::Avatar => ImageKind::Avatar,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageModel {
+    pub id: Uuid,
+    pub kind: EImageKind,
+    pub subject_id: Uuid,
+    pub uploaded_by: Uuid,
+    pub content_type: String,
+    pub width: i32,
+    pub height: i32,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub thumbnail_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ImageModel> for Image {
+    fn from(model: ImageModel) -> Self {
+        Image {
+            id: model.id,
+            kind: model.kind.into(),
+            subject_id: model.subject_id,
+            uploaded_by: model.uploaded_by,
+            content_type: model.content_type,
+            width: model.width as u32,
+            height: model.height as u32,
+            size_bytes: model.size_bytes as u64,
+            storage_key: model.storage_key,
+            thumbnail_key: model.thumbnail_key,
+            created_at: model.created_at,
+        }
+    }
+}
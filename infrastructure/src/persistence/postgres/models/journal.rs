@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{JournalEntry, JournalEntryRevision};
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JournalEntryModel {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub session_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub title: String,
+    pub body: String,
+    pub body_html: String,
+    pub revision: i32,
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<JournalEntryModel> for JournalEntry {
+    fn from(model: JournalEntryModel) -> Self {
+        JournalEntry {
+            id: model.id,
+            table_id: model.table_id,
+            session_id: model.session_id,
+            author_id: model.author_id,
+            title: model.title,
+            body: model.body,
+            body_html: model.body_html,
+            revision: model.revision as u32,
+            occurred_at: model.occurred_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JournalEntryRevisionModel {
+    pub entry_id: Uuid,
+    pub revision: i32,
+    pub title: String,
+    pub body: String,
+    pub edited_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<JournalEntryRevisionModel> for JournalEntryRevision {
+    fn from(model: JournalEntryRevisionModel) -> Self {
+        JournalEntryRevision {
+            entry_id: model.entry_id,
+            revision: model.revision as u32,
+            title: model.title,
+            body: model.body,
+            edited_by: model.edited_by,
+            created_at: model.created_at,
+        }
+    }
+}
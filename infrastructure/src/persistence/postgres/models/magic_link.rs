@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use domain::entities::MagicLinkToken;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MagicLinkTokenModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub consumed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<MagicLinkTokenModel> for MagicLinkToken {
+    fn from(model: MagicLinkTokenModel) -> Self {
+        MagicLinkToken {
+            id: model.id,
+            user_id: model.user_id,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at,
+            consumed_at: model.consumed_at,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use domain::search::SearchResult;
+use uuid::Uuid;
+
+/// One row of the search query. Every column except `total` is `NULL` when the
+/// requested page is past the last hit.
+#[derive(Debug, Clone)]
+pub struct SearchHitModel {
+    pub id: Option<Uuid>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub kind: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub rank: Option<f32>,
+    pub highlight: Option<String>,
+    pub total: i64,
+}
+
+impl SearchHitModel {
+    pub fn into_result(self) -> Option<SearchResult> {
+        Some(SearchResult {
+            id: self.id?.to_string(),
+            title: self.title?,
+            description: self.description,
+            r#type: self.kind?,
+            created_at: self
+                .created_at?
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            rank: self.rank.unwrap_or_default(),
+            highlight: self.highlight,
+        })
+    }
+}
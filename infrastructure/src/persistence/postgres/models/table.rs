@@ -1,5 +1,8 @@
 use chrono::{DateTime, Utc};
-use domain::entities::{Session, Table, TableDetails, TableStatus, User};
+use domain::entities::{
+    AgeRating, Session, Table, TableDetails, TableFormat, TableListing, TableLocation, TableStatus,
+    TableVisibility, User,
+};
 use uuid::Uuid;
 
 use crate::persistence::models::{SessionModel, UserModel};
@@ -29,7 +32,91 @@ impl From<ETableStatus> for TableStatus {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "table_format", rename_all = "snake_case")]
+pub enum ETableFormat {
+    Online,
+    InPerson,
+    Hybrid,
+}
+
+impl From<TableFormat> for ETableFormat {
+    fn from(format: TableFormat) -> Self {
+        match format {
+            TableFormat::Online => ETableFormat::Online,
+            TableFormat::InPerson => ETableFormat::InPerson,
+            TableFormat::Hybrid => ETableFormat::Hybrid,
+        }
+    }
+}
+
+impl From<ETableFormat> for TableFormat {
+    fn from(format: ETableFormat) -> Self {
+        match format {
+            ETableFormat::Online => TableFormat::Online,
+            ETableFormat::InPerson => TableFormat::InPerson,
+            ETableFormat::Hybrid => TableFormat::Hybrid,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "age_rating", rename_all = "snake_case")]
+pub enum EAgeRating {
+    AllAges,
+    Teen,
+    Mature,
+}
+
+impl From<AgeRating> for EAgeRating {
+    fn from(rating: AgeRating) -> Self {
+        match rating {
+            AgeRating::AllAges => EAgeRating::AllAges,
+            AgeRating::Teen => EAgeRating::Teen,
+            AgeRating::Mature => EAgeRating::Mature,
+        }
+    }
+}
+
+impl From<EAgeRating> for AgeRating {
+    fn from(rating: EAgeRating) -> Self {
+        match rating {
+            EAgeRating::AllAges => AgeRating::AllAges,
+            EAgeRating::Teen => AgeRating::Teen,
+            EAgeRating::Mature => AgeRating::Mature,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "table_visibility", rename_all = "lowercase")]
+pub enum ETableVisibility {
+    Public,
+    Unlisted,
+    Private,
+}
+
+impl From<TableVisibility> for ETableVisibility {
+    fn from(visibility: TableVisibility) -> Self {
+        match visibility {
+            TableVisibility::Public => ETableVisibility::Public,
+            TableVisibility::Unlisted => ETableVisibility::Unlisted,
+            TableVisibility::Private => ETableVisibility::Private,
+        }
+    }
+}
+
+impl From<ETableVisibility> for TableVisibility {
+    fn from(visibility: ETableVisibility) -> Self {
+        match visibility {
+            ETableVisibility::Public => TableVisibility::Public,
+            ETableVisibility::Unlisted => TableVisibility::Unlisted,
+            ETableVisibility::Private => TableVisibility::Private,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct TableModel {
     pub id: Uuid,
     pub gm_id: Uuid,
@@ -38,6 +125,15 @@ pub struct TableModel {
     pub status: ETableStatus,
     pub slots: i32,
     pub game_system_id: Uuid,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: ETableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: EAgeRating,
+    pub venue_address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub visibility: ETableVisibility,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -52,13 +148,76 @@ impl From<TableModel> for Table {
             player_slots: model.slots as u32,
             status: model.status.into(),
             game_system_id: model.game_system_id,
+            tags: model.tags,
+            language: model.language,
+            format: model.format.into(),
+            beginner_friendly: model.beginner_friendly,
+            age_rating: model.age_rating.into(),
+            location: location_from_columns(model.venue_address, model.latitude, model.longitude),
+            visibility: model.visibility.into(),
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableListingModel {
+    pub id: Uuid,
+    pub gm_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub status: ETableStatus,
+    pub slots: i32,
+    pub game_system_id: Uuid,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: ETableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: EAgeRating,
+    pub venue_address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub visibility: ETableVisibility,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub open_slots: i32,
+    pub distance_km: Option<f64>,
+}
+
+impl From<TableListingModel> for TableListing {
+    fn from(model: TableListingModel) -> Self {
+        let open_slots = model.open_slots.max(0) as u32;
+
+        TableListing {
+            table: TableModel {
+                id: model.id,
+                gm_id: model.gm_id,
+                title: model.title,
+                description: model.description,
+                status: model.status,
+                slots: model.slots,
+                game_system_id: model.game_system_id,
+                tags: model.tags,
+                language: model.language,
+                format: model.format,
+                beginner_friendly: model.beginner_friendly,
+                age_rating: model.age_rating,
+                venue_address: model.venue_address,
+                latitude: model.latitude,
+                longitude: model.longitude,
+                visibility: model.visibility,
+                created_at: model.created_at,
+                updated_at: model.updated_at,
+            }
+            .into(),
+            open_slots,
+            distance_km: model.distance_km,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct TableDetailsModel {
     pub id: Uuid,
     pub gm_id: Uuid,
@@ -69,6 +228,15 @@ pub struct TableDetailsModel {
     pub sessions: Vec<SessionModel>,
     pub status: ETableStatus,
     pub game_system_id: Uuid,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: ETableFormat,
+    pub beginner_friendly: bool,
+    pub age_rating: EAgeRating,
+    pub venue_address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub visibility: ETableVisibility,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -83,6 +251,13 @@ impl From<TableDetailsModel> for TableDetails {
             player_slots: model.slots as u32,
             status: model.status.into(),
             game_system_id: model.game_system_id,
+            tags: model.tags,
+            language: model.language,
+            format: model.format.into(),
+            beginner_friendly: model.beginner_friendly,
+            age_rating: model.age_rating.into(),
+            location: location_from_columns(model.venue_address, model.latitude, model.longitude),
+            visibility: model.visibility.into(),
             created_at: model.created_at,
             updated_at: model.updated_at,
             players: model.players.into_iter().map(User::from).collect(),
@@ -90,3 +265,19 @@ impl From<TableDetailsModel> for TableDetails {
         }
     }
 }
+
+/// The location columns are constrained to be set or cleared together.
+fn location_from_columns(
+    address: Option<String>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+) -> Option<TableLocation> {
+    match (address, latitude, longitude) {
+        (Some(address), Some(latitude), Some(longitude)) => Some(TableLocation {
+            address,
+            latitude,
+            longitude,
+        }),
+        _ => None,
+    }
+}
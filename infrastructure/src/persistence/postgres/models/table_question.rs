@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{QuestionKind, TableQuestion};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "question_kind", rename_all = "snake_case")]
+pub enum EQuestionKind {
+    Text,
+    SingleChoice,
+    MultiChoice,
+}
+
+impl From<QuestionKind> for EQuestionKind {
+    fn from(kind: QuestionKind) -> Self {
+        match kind {
+            QuestionKind::Text => EQuestionKind::Text,
+            QuestionKind::SingleChoice => EQuestionKind::SingleChoice,
+            QuestionKind::MultiChoice => EQuestionKind::MultiChoice,
+        }
+    }
+}
+
+impl From<EQuestionKind> for QuestionKind {
+    fn from(kind: EQuestionKind) -> Self {
+        match kind {
+            EQuestionKind::Text => QuestionKind::Text,
+            EQuestionKind::SingleChoice => QuestionKind::SingleChoice,
+            EQuestionKind::MultiChoice => QuestionKind::MultiChoice,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableQuestionModel {
+    pub id: Uuid,
+    pub table_id: Uuid,
+    pub position: i32,
+    pub prompt: String,
+    pub kind: EQuestionKind,
+    pub options: Vec<String>,
+    pub required: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TableQuestionModel> for TableQuestion {
+    fn from(model: TableQuestionModel) -> Self {
+        TableQuestion {
+            id: model.id,
+            table_id: model.table_id,
+            position: model.position,
+            prompt: model.prompt,
+            kind: model.kind.into(),
+            options: model.options,
+            required: model.required,
+            created_at: model.created_at,
+        }
+    }
+}
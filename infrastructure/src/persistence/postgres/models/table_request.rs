@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
-use domain::entities::{TableRequest, TableRequestStatus};
+use domain::entities::{ApplicationAnswer, TableRequest, TableRequestStatus};
+use sqlx::types::Json;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]
@@ -36,6 +37,7 @@ pub struct TableRequestModel {
     pub user_id: Uuid,
     pub table_id: Uuid,
     pub message: Option<String>,
+    pub answers: Json<Vec<ApplicationAnswer>>,
     pub status: ETableRequestStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -48,6 +50,7 @@ impl From<TableRequestModel> for TableRequest {
             user_id: model.user_id,
             table_id: model.table_id,
             message: model.message,
+            answers: model.answers.0,
             status: model.status.into(),
             created_at: model.created_at,
             updated_at: model.updated_at,
@@ -1,14 +1,41 @@
 use chrono::{DateTime, Utc};
-use domain::entities::User;
+use domain::entities::{User, UserRole};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[sqlx(type_name = "user_role", rename_all = "lowercase")]
+pub enum EUserRole {
+    User,
+    Admin,
+}
+
+impl From<UserRole> for EUserRole {
+    fn from(role: UserRole) -> Self {
+        match role {
+            UserRole::User => EUserRole::User,
+            UserRole::Admin => EUserRole::Admin,
+        }
+    }
+}
+
+impl From<EUserRole> for UserRole {
+    fn from(role: EUserRole) -> Self {
+        match role {
+            EUserRole::User => UserRole::User,
+            EUserRole::Admin => UserRole::Admin,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserModel {
     pub id: Uuid,
     pub username: String,
     pub email: String,
     pub password: String,
+    pub role: EUserRole,
+    pub suspended_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -20,6 +47,8 @@ impl From<UserModel> for User {
             username: model.username,
             email: model.email,
             password: model.password,
+            role: model.role.into(),
+            suspended_at: model.suspended_at,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use domain::entities::{UserEvent, UserEventKind};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[sqlx(type_name = "user_event_kind", rename_all = "snake_case")]
+pub enum EUserEventKind {
+    SessionScheduled,
+    SessionStarted,
+    SessionCompleted,
+    IntentChanged,
+    RequestAccepted,
+}
+
+impl From<UserEventKind> for EUserEventKind {
+    fn from(kind: UserEventKind) -> Self {
+        match kind {
+            UserEventKind::SessionScheduled => EUserEventKind::SessionScheduled,
+            UserEventKind::SessionStarted => EUserEventKind::SessionStarted,
+            UserEventKind::SessionCompleted => EUserEventKind::SessionCompleted,
+            UserEventKind::IntentChanged => EUserEventKind::IntentChanged,
+            UserEventKind::RequestAccepted => EUserEventKind::RequestAccepted,
+        }
+    }
+}
+
+impl From<EUserEventKind> for UserEventKind {
+    fn from(kind: EUserEventKind) -> Self {
+        match kind {
+            EUserEventKind::SessionScheduled => UserEventKind::SessionScheduled,
+            EUserEventKind::SessionStarted => UserEventKind::SessionStarted,
+            EUserEventKind::SessionCompleted => UserEventKind::SessionCompleted,
+            EUserEventKind::IntentChanged => UserEventKind::IntentChanged,
+            EUserEventKind::RequestAccepted => UserEventKind::RequestAccepted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserEventModel {
+    pub id: i64,
+    pub user_id: Uuid,
+    pub kind: EUserEventKind,
+    pub payload: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<UserEventModel> for UserEvent {
+    fn from(model: UserEventModel) -> Self {
+        UserEvent {
+            id: model.id,
+            user_id: model.user_id,
+            kind: model.kind.into(),
+            payload: model.payload,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -1,14 +1,22 @@
+use crate::config::DatabasePoolConfig;
 use crate::persistence::Db;
 use shared::Result;
 use shared::error::Error;
 use shared::error::SetupError;
+use sqlx::postgres::PgPoolOptions;
 
-pub async fn create_postgres_pool(database_url: &str) -> Result<Db> {
-    let pool = Db::connect(database_url).await.map_err(|e| {
-        Error::Setup(SetupError::FailedToEstablishDatabaseConnection(
-            e.to_string(),
-        ))
-    })?;
+pub async fn create_postgres_pool(database_url: &str, pool: &DatabasePoolConfig) -> Result<Db> {
+    let pool = PgPoolOptions::new()
+        .max_connections(pool.max_connections)
+        .min_connections(pool.min_connections)
+        .acquire_timeout(pool.acquire_timeout)
+        .connect(database_url)
+        .await
+        .map_err(|e| {
+            Error::Setup(SetupError::FailedToEstablishDatabaseConnection(
+                e.to_string(),
+            ))
+        })?;
 
     Ok(pool)
 }
@@ -0,0 +1,222 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::{AccountExportModel, EExportStatus};
+use chrono::{DateTime, Utc};
+use domain::entities::*;
+use domain::repositories::AccountExportRepository;
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresAccountExportRepository {
+    pool: PgPool,
+}
+
+impl PostgresAccountExportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_pending_by_user(&self, user_id: Uuid) -> Result<Option<AccountExport>> {
+        let export = sqlx::query_as!(
+            AccountExportModel,
+            r#"
+                SELECT
+                    id,
+                    user_id,
+                    status as "status: EExportStatus",
+                    payload,
+                    error,
+                    created_at,
+                    completed_at
+                FROM account_exports
+                WHERE user_id = $1 AND status = 'pending'
+            "#,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(export.map(|model| model.into()))
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountExportRepository for PostgresAccountExportRepository {
+    #[instrument(name = "account_export.find_or_create_pending", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_or_create_pending(
+        &self,
+        command: CreateAccountExportCommand,
+    ) -> Result<AccountExport> {
+        // A concurrent request may win the insert, in which case the pending
+        // export it created is returned instead.
+        loop {
+            if let Some(export) = self.find_pending_by_user(command.user_id).await? {
+                return Ok(export);
+            }
+
+            let created = sqlx::query_as!(
+                AccountExportModel,
+                r#"
+                    INSERT INTO account_exports
+                        (id, user_id)
+                    VALUES
+                        ($1, $2)
+                    ON CONFLICT (user_id) WHERE status = 'pending' DO NOTHING
+                    RETURNING
+                        id,
+                        user_id,
+                        status as "status: EExportStatus",
+                        payload,
+                        error,
+                        created_at,
+                        completed_at
+                "#,
+                command.id,
+                command.user_id,
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+            if let Some(export) = created {
+                return Ok(export.into());
+            }
+        }
+    }
+
+    #[instrument(name = "account_export.claim_next", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn claim_next(&self, stale_before: DateTime<Utc>) -> Result<Option<AccountExport>> {
+        let export = sqlx::query_as!(
+            AccountExportModel,
+            r#"
+                UPDATE account_exports
+                SET claimed_at = NOW()
+                WHERE id = (
+                    SELECT id
+                    FROM account_exports
+                    WHERE status = 'pending'
+                        AND (claimed_at IS NULL OR claimed_at < $1)
+                    ORDER BY created_at
+                    LIMIT 1
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING
+                    id,
+                    user_id,
+                    status as "status: EExportStatus",
+                    payload,
+                    error,
+                    created_at,
+                    completed_at
+            "#,
+            stale_before
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(export.map(|model| model.into()))
+    }
+
+    #[instrument(name = "account_export.release_claim", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn release_claim(&self, id: Uuid) -> Result<()> {
+        sqlx::query!(
+            r#"
+                UPDATE account_exports
+                SET claimed_at = NULL
+                WHERE id = $1 AND status = 'pending'
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "account_export.complete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn complete(&self, command: CompleteAccountExportCommand) -> Result<AccountExport> {
+        let export = sqlx::query_as!(
+            AccountExportModel,
+            r#"
+                UPDATE account_exports
+                SET
+                    status = $2,
+                    payload = $3,
+                    error = $4,
+                    completed_at = NOW()
+                WHERE id = $1
+                RETURNING
+                    id,
+                    user_id,
+                    status as "status: EExportStatus",
+                    payload,
+                    error,
+                    created_at,
+                    completed_at
+            "#,
+            command.id,
+            EExportStatus::from(command.status) as EExportStatus,
+            command.payload,
+            command.error,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(export.into())
+    }
+
+    #[instrument(name = "account_export.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountExport>> {
+        let export = sqlx::query_as!(
+            AccountExportModel,
+            r#"
+                SELECT
+                    id,
+                    user_id,
+                    status as "status: EExportStatus",
+                    payload,
+                    error,
+                    created_at,
+                    completed_at
+                FROM account_exports
+                WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(export.map(|model| model.into()))
+    }
+
+    #[instrument(name = "account_export.count_personal_records", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn count_personal_records(&self, user_id: Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+                SELECT
+                    (SELECT COUNT(*) FROM tables WHERE gm_id = $1)
+                    + (SELECT COUNT(*) FROM table_members WHERE user_id = $1)
+                    + (SELECT COUNT(*) FROM table_requests WHERE user_id = $1)
+                    + (SELECT COUNT(*) FROM session_intents WHERE user_id = $1)
+                    + (SELECT COUNT(*)
+                       FROM session_checkins
+                       JOIN session_intents
+                           ON session_intents.id = session_checkins.session_intent_id
+                       WHERE session_intents.user_id = $1) as "count!"
+            "#,
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(count)
+    }
+}
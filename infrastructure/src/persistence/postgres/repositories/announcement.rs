@@ -0,0 +1,266 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::{
+    AnnouncementCommentModel, AnnouncementListingModel, AnnouncementModel,
+    AnnouncementReadReceiptModel,
+};
+use domain::entities::*;
+use domain::repositories::AnnouncementRepository;
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresAnnouncementRepository {
+    pool: PgPool,
+}
+
+impl PostgresAnnouncementRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl AnnouncementRepository for PostgresAnnouncementRepository {
+    #[instrument(name = "announcement.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create(&self, command: CreateAnnouncementCommand) -> Result<Announcement> {
+        let announcement = sqlx::query_as!(
+            AnnouncementModel,
+            r#"
+                INSERT INTO announcements (id, table_id, author_id, title, body, pinned)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING id, table_id, author_id, title, body, pinned, created_at, updated_at
+            "#,
+            command.id,
+            command.table_id,
+            command.author_id,
+            command.title,
+            command.body,
+            command.pinned,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(announcement.into())
+    }
+
+    #[instrument(name = "announcement.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn update(&self, command: UpdateAnnouncementCommand) -> Result<Option<Announcement>> {
+        let announcement = sqlx::query_as!(
+            AnnouncementModel,
+            r#"
+                UPDATE announcements SET
+                    title = COALESCE($2, title),
+                    body = COALESCE($3, body),
+                    pinned = COALESCE($4, pinned),
+                    updated_at = NOW()
+                WHERE id = $1
+                RETURNING id, table_id, author_id, title, body, pinned, created_at, updated_at
+            "#,
+            command.id,
+            command.title,
+            command.body,
+            command.pinned,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(announcement.map(Announcement::from))
+    }
+
+    #[instrument(name = "announcement.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn delete(&self, id: Uuid) -> Result<()> {
+        sqlx::query!("DELETE FROM announcements WHERE id = $1", id)
+            .execute(&self.pool)
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "announcement.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Announcement>> {
+        let announcement = sqlx::query_as!(
+            AnnouncementModel,
+            r#"
+                SELECT id, table_id, author_id, title, body, pinned, created_at, updated_at
+                FROM announcements
+                WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(announcement.map(Announcement::from))
+    }
+
+    #[instrument(name = "announcement.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_table_id(
+        &self,
+        table_id: Uuid,
+        viewer_id: Uuid,
+    ) -> Result<Vec<AnnouncementListing>> {
+        let announcements = sqlx::query_as!(
+            AnnouncementListingModel,
+            r#"
+                SELECT
+                    a.id,
+                    a.table_id,
+                    a.author_id,
+                    a.title,
+                    a.body,
+                    a.pinned,
+                    a.created_at,
+                    a.updated_at,
+                    (
+                        SELECT COUNT(*) FROM announcement_comments c
+                        WHERE c.announcement_id = a.id
+                    ) as "comment_count!",
+                    (
+                        SELECT COUNT(*) FROM announcement_reads r
+                        WHERE r.announcement_id = a.id
+                    ) as "read_count!",
+                    EXISTS (
+                        SELECT 1 FROM announcement_reads r
+                        WHERE r.announcement_id = a.id AND r.user_id = $2
+                    ) as "read!"
+                FROM announcements a
+                WHERE a.table_id = $1
+                ORDER BY a.pinned DESC, a.created_at DESC
+            "#,
+            table_id,
+            viewer_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(announcements
+            .into_iter()
+            .map(AnnouncementListing::from)
+            .collect())
+    }
+
+    #[instrument(name = "announcement.mark_read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn mark_read(&self, announcement_id: Uuid, user_id: Uuid) -> Result<()> {
+        sqlx::query!(
+            r#"
+                INSERT INTO announcement_reads (announcement_id, user_id)
+                VALUES ($1, $2)
+                ON CONFLICT (announcement_id, user_id) DO NOTHING
+            "#,
+            announcement_id,
+            user_id,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "announcement.find_read_receipts", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_read_receipts(
+        &self,
+        announcement_id: Uuid,
+    ) -> Result<Vec<AnnouncementReadReceipt>> {
+        let receipts = sqlx::query_as!(
+            AnnouncementReadReceiptModel,
+            r#"
+                SELECT r.user_id, u.username, r.read_at
+                FROM announcement_reads r
+                JOIN users u ON u.id = r.user_id
+                WHERE r.announcement_id = $1
+                ORDER BY r.read_at
+            "#,
+            announcement_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(receipts
+            .into_iter()
+            .map(AnnouncementReadReceipt::from)
+            .collect())
+    }
+
+    #[instrument(name = "announcement.create_comment", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create_comment(
+        &self,
+        command: CreateAnnouncementCommentCommand,
+    ) -> Result<AnnouncementComment> {
+        let comment = sqlx::query_as!(
+            AnnouncementCommentModel,
+            r#"
+                INSERT INTO announcement_comments (id, announcement_id, author_id, body)
+                VALUES ($1, $2, $3, $4)
+                RETURNING id, announcement_id, author_id, body, created_at
+            "#,
+            command.id,
+            command.announcement_id,
+            command.author_id,
+            command.body,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(comment.into())
+    }
+
+    #[instrument(name = "announcement.find_comments", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_comments(&self, announcement_id: Uuid) -> Result<Vec<AnnouncementComment>> {
+        let comments = sqlx::query_as!(
+            AnnouncementCommentModel,
+            r#"
+                SELECT id, announcement_id, author_id, body, created_at
+                FROM announcement_comments
+                WHERE announcement_id = $1
+                ORDER BY created_at
+            "#,
+            announcement_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(comments
+            .into_iter()
+            .map(AnnouncementComment::from)
+            .collect())
+    }
+
+    #[instrument(name = "announcement.find_comment_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_comment_by_id(&self, id: Uuid) -> Result<Option<AnnouncementComment>> {
+        let comment = sqlx::query_as!(
+            AnnouncementCommentModel,
+            r#"
+                SELECT id, announcement_id, author_id, body, created_at
+                FROM announcement_comments
+                WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(comment.map(AnnouncementComment::from))
+    }
+
+    #[instrument(name = "announcement.delete_comment", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn delete_comment(&self, id: Uuid) -> Result<()> {
+        sqlx::query!("DELETE FROM announcement_comments WHERE id = $1", id)
+            .execute(&self.pool)
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(())
+    }
+}
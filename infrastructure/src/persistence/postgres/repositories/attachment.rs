@@ -0,0 +1,258 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::AttachmentModel;
+use chrono::{DateTime, Utc};
+use domain::entities::*;
+use domain::repositories::AttachmentRepository;
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresAttachmentRepository {
+    pool: PgPool,
+}
+
+impl PostgresAttachmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl AttachmentRepository for PostgresAttachmentRepository {
+    #[instrument(name = "attachment.create_within_quota", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create_within_quota(
+        &self,
+        command: CreateAttachmentCommand,
+        quota_bytes: u64,
+    ) -> Result<Option<Attachment>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        // Locking the table row serializes concurrent uploads to the same
+        // table, so two uploads cannot both squeeze under the quota.
+        sqlx::query!(
+            "SELECT id FROM tables WHERE id = $1 FOR UPDATE",
+            command.table_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let used_bytes = sqlx::query_scalar!(
+            r#"
+                SELECT COALESCE(SUM(size_bytes), 0)::BIGINT as "used_bytes!"
+                FROM attachments
+                WHERE table_id = $1
+            "#,
+            command.table_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        if used_bytes as u64 + command.size_bytes > quota_bytes {
+            return Ok(None);
+        }
+
+        let attachment = sqlx::query_as!(
+            AttachmentModel,
+            r#"
+                INSERT INTO attachments
+                    (id, table_id, session_id, uploaded_by, file_name, content_type,
+                     size_bytes, storage_key)
+                VALUES
+                    ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING
+                    id,
+                    table_id,
+                    session_id,
+                    uploaded_by,
+                    file_name,
+                    content_type,
+                    size_bytes,
+                    storage_key,
+                    created_at
+            "#,
+            command.id,
+            command.table_id,
+            command.session_id,
+            command.uploaded_by,
+            command.file_name,
+            command.content_type,
+            command.size_bytes as i64,
+            command.storage_key,
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(Some(attachment.into()))
+    }
+
+    #[instrument(name = "attachment.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Attachment>> {
+        let attachment = sqlx::query_as!(
+            AttachmentModel,
+            r#"
+                SELECT
+                    id,
+                    table_id,
+                    session_id,
+                    uploaded_by,
+                    file_name,
+                    content_type,
+                    size_bytes,
+                    storage_key,
+                    created_at
+                FROM attachments
+                WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(attachment.map(Attachment::from))
+    }
+
+    #[instrument(name = "attachment.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<Attachment>> {
+        let attachments = sqlx::query_as!(
+            AttachmentModel,
+            r#"
+                SELECT
+                    id,
+                    table_id,
+                    session_id,
+                    uploaded_by,
+                    file_name,
+                    content_type,
+                    size_bytes,
+                    storage_key,
+                    created_at
+                FROM attachments
+                WHERE table_id = $1 AND session_id IS NULL
+                ORDER BY created_at DESC
+            "#,
+            table_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(attachments.into_iter().map(Attachment::from).collect())
+    }
+
+    #[instrument(name = "attachment.find_by_session_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_session_id(&self, session_id: Uuid) -> Result<Vec<Attachment>> {
+        let attachments = sqlx::query_as!(
+            AttachmentModel,
+            r#"
+                SELECT
+                    id,
+                    table_id,
+                    session_id,
+                    uploaded_by,
+                    file_name,
+                    content_type,
+                    size_bytes,
+                    storage_key,
+                    created_at
+                FROM attachments
+                WHERE session_id = $1
+                ORDER BY created_at DESC
+            "#,
+            session_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(attachments.into_iter().map(Attachment::from).collect())
+    }
+
+    #[instrument(name = "attachment.used_bytes_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn used_bytes_by_table_id(&self, table_id: Uuid) -> Result<u64> {
+        let used_bytes = sqlx::query_scalar!(
+            r#"
+                SELECT COALESCE(SUM(size_bytes), 0)::BIGINT as "used_bytes!"
+                FROM attachments
+                WHERE table_id = $1
+            "#,
+            table_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(used_bytes as u64)
+    }
+
+    #[instrument(name = "attachment.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn delete(&self, id: Uuid) -> Result<Option<Attachment>> {
+        let attachment = sqlx::query_as!(
+            AttachmentModel,
+            r#"
+                DELETE FROM attachments
+                WHERE id = $1
+                RETURNING
+                    id,
+                    table_id,
+                    session_id,
+                    uploaded_by,
+                    file_name,
+                    content_type,
+                    size_bytes,
+                    storage_key,
+                    created_at
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(attachment.map(Attachment::from))
+    }
+
+    #[instrument(name = "attachment.purge_deleted", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<Vec<Attachment>> {
+        // The foreign keys would cascade these rows away on purge, leaving
+        // their stored files behind, so they are removed here first.
+        let attachments = sqlx::query_as!(
+            AttachmentModel,
+            r#"
+                DELETE FROM attachments
+                WHERE table_id IN (SELECT id FROM tables WHERE deleted_at < $1)
+                    OR session_id IN (SELECT id FROM sessions WHERE deleted_at < $1)
+                    OR uploaded_by IN (SELECT id FROM users WHERE deleted_at < $1)
+                RETURNING
+                    id,
+                    table_id,
+                    session_id,
+                    uploaded_by,
+                    file_name,
+                    content_type,
+                    size_bytes,
+                    storage_key,
+                    created_at
+            "#,
+            deleted_before
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(attachments.into_iter().map(Attachment::from).collect())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::{AuditLogModel, EAuditAction, EAuditEntity};
+use domain::entities::*;
+use domain::repositories::{AuditLogRepository, DEFAULT_PAGINATION_LIMIT, MAX_PAGINATION_LIMIT};
+use shared::Result;
+use sqlx::{PgExecutor, PgPool};
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct PostgresAuditLogRepository {
+    pool: PgPool,
+}
+
+impl PostgresAuditLogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogRepository for PostgresAuditLogRepository {
+    #[instrument(name = "audit_log.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create(&self, command: CreateAuditLogCommand) -> Result<AuditLog> {
+        insert_audit_log(&self.pool, command).await
+    }
+
+    #[instrument(name = "audit_log.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn read(&self, command: GetAuditLogCommand) -> Result<Vec<AuditLog>> {
+        let limit = command
+            .limit
+            .unwrap_or(DEFAULT_PAGINATION_LIMIT)
+            .clamp(1, MAX_PAGINATION_LIMIT);
+        let offset = command.offset.unwrap_or(0).max(0);
+
+        let entries = sqlx::query_as!(
+            AuditLogModel,
+            r#"
+                SELECT
+                    id,
+                    actor_id,
+                    action as "action: EAuditAction",
+                    entity_type as "entity_type: EAuditEntity",
+                    entity_id,
+                    table_id,
+                    before,
+                    after,
+                    created_at
+                FROM audit_logs
+                WHERE ($1::uuid IS NULL OR actor_id = $1)
+                    AND ($2::audit_action IS NULL OR action = $2)
+                    AND ($3::audit_entity IS NULL OR entity_type = $3)
+                    AND ($4::uuid IS NULL OR entity_id = $4)
+                    AND ($5::uuid IS NULL OR table_id = $5)
+                    AND ($6::timestamptz IS NULL OR created_at >= $6)
+                    AND ($7::timestamptz IS NULL OR created_at < $7)
+                ORDER BY created_at DESC, id DESC
+                LIMIT $8 OFFSET $9
+            "#,
+            command.actor_id,
+            command.action.map(EAuditAction::from) as Option<EAuditAction>,
+            command.entity_type.map(EAuditEntity::from) as Option<EAuditEntity>,
+            command.entity_id,
+            command.table_id,
+            command.since,
+            command.until,
+            limit,
+            offset,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(entries.into_iter().map(|model| model.into()).collect())
+    }
+}
+
+/// Writes an entry on `executor`, so repositories can record it in the same
+/// transaction as the change it describes.
+pub(crate) async fn insert_audit_log<'e, E>(
+    executor: E,
+    command: CreateAuditLogCommand,
+) -> Result<AuditLog>
+where
+    E: PgExecutor<'e>,
+{
+    let entry = sqlx::query_as!(
+        AuditLogModel,
+        r#"
+            INSERT INTO audit_logs
+                (id, actor_id, action, entity_type, entity_id, table_id, before, after)
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING
+                id,
+                actor_id,
+                action as "action: EAuditAction",
+                entity_type as "entity_type: EAuditEntity",
+                entity_id,
+                table_id,
+                before,
+                after,
+                created_at
+        "#,
+        command.id,
+        command.actor_id,
+        EAuditAction::from(command.action) as EAuditAction,
+        EAuditEntity::from(command.entity_type) as EAuditEntity,
+        command.entity_id,
+        command.table_id,
+        command.before,
+        command.after,
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(entry.into())
+}
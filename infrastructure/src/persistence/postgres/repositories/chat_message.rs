@@ -0,0 +1,70 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::ChatMessageModel;
+use domain::entities::*;
+use domain::repositories::ChatMessageRepository;
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresChatMessageRepository {
+    pool: PgPool,
+}
+
+impl PostgresChatMessageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatMessageRepository for PostgresChatMessageRepository {
+    #[instrument(name = "chat_message.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create(&self, command: CreateChatMessageCommand) -> Result<ChatMessage> {
+        let message = sqlx::query_as!(
+            ChatMessageModel,
+            r#"
+                INSERT INTO chat_messages (id, table_id, author_id, body)
+                VALUES ($1, $2, $3, $4)
+                RETURNING id, table_id, author_id, body, created_at
+            "#,
+            command.id,
+            command.table_id,
+            command.author_id,
+            command.body,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(message.into())
+    }
+
+    #[instrument(name = "chat_message.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_table_id(
+        &self,
+        table_id: Uuid,
+        before: Option<Uuid>,
+        limit: u32,
+    ) -> Result<Vec<ChatMessage>> {
+        let messages = sqlx::query_as!(
+            ChatMessageModel,
+            r#"
+                SELECT id, table_id, author_id, body, created_at
+                FROM chat_messages
+                WHERE table_id = $1 AND ($2::UUID IS NULL OR id < $2)
+                ORDER BY id DESC
+                LIMIT $3
+            "#,
+            table_id,
+            before,
+            limit as i64,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(messages.into_iter().map(ChatMessage::from).collect())
+    }
+}
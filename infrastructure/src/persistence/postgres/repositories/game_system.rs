@@ -5,6 +5,7 @@ use domain::repositories::{GameSystemRepository, Repository};
 use shared::Result;
 use shared::error::{ApplicationError, Error};
 use sqlx::PgPool;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -28,6 +29,7 @@ impl
         DeleteGameSystemCommand,
     > for PostgresGameSystemRepository
 {
+    #[instrument(name = "game_system.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, command: CreateGameSystemCommand) -> Result<GameSystem> {
         let result = sqlx::query_as!(
             GameSystemModel,
@@ -36,7 +38,7 @@ impl
                     (id, name)
                 VALUES
                     ($1, $2)
-                RETURNING *
+                RETURNING id, name, created_at, updated_at
             "#,
             command.id,
             command.name
@@ -48,11 +50,12 @@ impl
         Ok(result.into())
     }
 
+    #[instrument(name = "game_system.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetGameSystemCommand) -> Result<Vec<GameSystem>> {
         let result = sqlx::query_as!(
             GameSystemModel,
             r#"
-                SELECT *
+                SELECT id, name, created_at, updated_at
                 FROM game_systems
                 WHERE ($1::text IS NULL OR name ILIKE $1)
             "#,
@@ -65,6 +68,7 @@ impl
         Ok(result.into_iter().map(|m| m.into()).collect())
     }
 
+    #[instrument(name = "game_system.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, command: UpdateGameSystemCommand) -> Result<GameSystem> {
         if command.name.is_none() {
             return Err(Error::Application(ApplicationError::InvalidInput {
@@ -82,7 +86,7 @@ impl
                     name = COALESCE($2, name),
                     updated_at = NOW()
                 WHERE id = $1
-                RETURNING *
+                RETURNING id, name, created_at, updated_at
             "#,
             command.id,
             name_value
@@ -94,13 +98,14 @@ impl
         Ok(updated_game_system.into())
     }
 
+    #[instrument(name = "game_system.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteGameSystemCommand) -> Result<GameSystem> {
         let result = sqlx::query_as!(
             GameSystemModel,
             r#"
                 DELETE FROM game_systems
                 WHERE id = $1
-                RETURNING *
+                RETURNING id, name, created_at, updated_at
             "#,
             command.id,
         )
@@ -111,11 +116,12 @@ impl
         Ok(result.into())
     }
 
+    #[instrument(name = "game_system.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<GameSystem>> {
         let game_system = sqlx::query_as!(
             GameSystemModel,
             r#"
-                SELECT *
+                SELECT id, name, created_at, updated_at
                 FROM game_systems
                 WHERE id = $1
             "#,
@@ -131,11 +137,12 @@ impl
 
 #[async_trait::async_trait]
 impl GameSystemRepository for PostgresGameSystemRepository {
+    #[instrument(name = "game_system.find_by_name", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_name(&self, name: &str) -> Result<Option<GameSystem>> {
         let game_system = sqlx::query_as!(
             GameSystemModel,
             r#"
-                SELECT *
+                SELECT id, name, created_at, updated_at
                 FROM game_systems
                 WHERE name = $1
             "#,
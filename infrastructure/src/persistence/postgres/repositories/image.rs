@@ -0,0 +1,187 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::ImageModel;
+use crate::persistence::postgres::models::image::EImageKind;
+use chrono::{DateTime, Utc};
+use domain::entities::*;
+use domain::repositories::ImageRepository;
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresImageRepository {
+    pool: PgPool,
+}
+
+impl PostgresImageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ImageRepository for PostgresImageRepository {
+    #[instrument(name = "image.upsert", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn upsert(&self, command: CreateImageCommand) -> Result<Image> {
+        let image = sqlx::query_as!(
+            ImageModel,
+            r#"
+                INSERT INTO images
+                    (id, kind, subject_id, uploaded_by, content_type, width, height,
+                     size_bytes, storage_key, thumbnail_key)
+                VALUES
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                ON CONFLICT (kind, subject_id) DO UPDATE SET
+                    id = EXCLUDED.id,
+                    uploaded_by = EXCLUDED.uploaded_by,
+                    content_type = EXCLUDED.content_type,
+                    width = EXCLUDED.width,
+                    height = EXCLUDED.height,
+                    size_bytes = EXCLUDED.size_bytes,
+                    storage_key = EXCLUDED.storage_key,
+                    thumbnail_key = EXCLUDED.thumbnail_key,
+                    created_at = NOW()
+                RETURNING
+                    id,
+                    kind as "kind: EImageKind",
+                    subject_id,
+                    uploaded_by,
+                    content_type,
+                    width,
+                    height,
+                    size_bytes,
+                    storage_key,
+                    thumbnail_key,
+                    created_at
+            "#,
+            command.id,
+            EImageKind::from(command.kind) as EImageKind,
+            command.subject_id,
+            command.uploaded_by,
+            command.content_type,
+            command.width as i32,
+            command.height as i32,
+            command.size_bytes as i64,
+            command.storage_key,
+            command.thumbnail_key,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(image.into())
+    }
+
+    #[instrument(name = "image.find", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find(&self, kind: ImageKind, subject_id: Uuid) -> Result<Option<Image>> {
+        let image = sqlx::query_as!(
+            ImageModel,
+            r#"
+                SELECT
+                    id,
+                    kind as "kind: EImageKind",
+                    subject_id,
+                    uploaded_by,
+                    content_type,
+                    width,
+                    height,
+                    size_bytes,
+                    storage_key,
+                    thumbnail_key,
+                    created_at
+                FROM images
+                WHERE kind = $1 AND subject_id = $2
+            "#,
+            EImageKind::from(kind) as EImageKind,
+            subject_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(image.map(Image::from))
+    }
+
+    #[instrument(name = "image.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn delete(&self, kind: ImageKind, subject_id: Uuid) -> Result<Option<Image>> {
+        let image = sqlx::query_as!(
+            ImageModel,
+            r#"
+                DELETE FROM images
+                WHERE kind = $1 AND subject_id = $2
+                RETURNING
+                    id,
+                    kind as "kind: EImageKind",
+                    subject_id,
+                    uploaded_by,
+                    content_type,
+                    width,
+                    height,
+                    size_bytes,
+                    storage_key,
+                    thumbnail_key,
+                    created_at
+            "#,
+            EImageKind::from(kind) as EImageKind,
+            subject_id,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(image.map(Image::from))
+    }
+
+    #[instrument(name = "image.purge_orphaned", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn purge_orphaned(&self, deleted_before: DateTime<Utc>) -> Result<Vec<Image>> {
+        // subject_id cannot carry a foreign key, so covers and avatars are
+        // removed here before their table or user is purged.
+        let images = sqlx::query_as!(
+            ImageModel,
+            r#"
+                WITH purged_users AS (
+                    SELECT id FROM users WHERE deleted_at < $1
+                )
+                DELETE FROM images
+                WHERE uploaded_by IN (SELECT id FROM purged_users)
+                    OR (
+                        kind = 'table_cover'
+                        AND NOT EXISTS (
+                            SELECT 1
+                            FROM tables
+                            WHERE tables.id = images.subject_id
+                                AND (tables.deleted_at IS NULL OR tables.deleted_at >= $1)
+                        )
+                    )
+                    OR (
+                        kind = 'avatar'
+                        AND NOT EXISTS (
+                            SELECT 1
+                            FROM users
+                            WHERE users.id = images.subject_id
+                                AND (users.deleted_at IS NULL OR users.deleted_at >= $1)
+                        )
+                    )
+                RETURNING
+                    id,
+                    kind as "kind: EImageKind",
+                    subject_id,
+                    uploaded_by,
+                    content_type,
+                    width,
+                    height,
+                    size_bytes,
+                    storage_key,
+                    thumbnail_key,
+                    created_at
+            "#,
+            deleted_before,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(images.into_iter().map(Image::from).collect())
+    }
+}
@@ -0,0 +1,258 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::{JournalEntryModel, JournalEntryRevisionModel};
+use domain::entities::*;
+use domain::repositories::JournalRepository;
+use shared::Result;
+use shared::error::Error;
+use sqlx::{PgExecutor, PgPool};
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresJournalRepository {
+    pool: PgPool,
+}
+
+impl PostgresJournalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+async fn fetch_entry<'e>(
+    executor: impl PgExecutor<'e>,
+    id: Uuid,
+) -> Result<Option<JournalEntryModel>> {
+    let entry = sqlx::query_as!(
+        JournalEntryModel,
+        r#"
+            SELECT
+                j.id,
+                j.table_id,
+                j.session_id,
+                j.author_id,
+                j.title,
+                j.body,
+                j.body_html,
+                j.revision,
+                COALESCE(s.scheduled_for, j.created_at) as "occurred_at!",
+                j.created_at,
+                j.updated_at
+            FROM journal_entries j
+            LEFT JOIN sessions s ON s.id = j.session_id
+            WHERE j.id = $1
+        "#,
+        id
+    )
+    .fetch_optional(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(entry)
+}
+
+#[async_trait::async_trait]
+impl JournalRepository for PostgresJournalRepository {
+    #[instrument(name = "journal.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create(&self, command: CreateJournalEntryCommand) -> Result<JournalEntry> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO journal_entries
+                    (id, table_id, session_id, author_id, title, body, body_html)
+                VALUES
+                    ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            command.id,
+            command.table_id,
+            command.session_id,
+            command.author_id,
+            command.title,
+            command.body,
+            command.body_html,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO journal_entry_revisions
+                    (entry_id, revision, title, body, edited_by)
+                VALUES
+                    ($1, 1, $2, $3, $4)
+            "#,
+            command.id,
+            command.title,
+            command.body,
+            command.author_id,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let entry = fetch_entry(&mut *tx, command.id)
+            .await?
+            .ok_or(Error::InternalServerError)?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(entry.into())
+    }
+
+    #[instrument(name = "journal.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn update(&self, command: UpdateJournalEntryCommand) -> Result<Option<JournalEntry>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let updated = sqlx::query!(
+            r#"
+                UPDATE journal_entries SET
+                    title = COALESCE($2, title),
+                    body = COALESCE($3, body),
+                    body_html = COALESCE($4, body_html),
+                    session_id = CASE WHEN $5 THEN $6 ELSE session_id END,
+                    revision = revision + 1,
+                    updated_at = NOW()
+                WHERE id = $1
+                RETURNING revision, title, body
+            "#,
+            command.id,
+            command.title,
+            command.body,
+            command.body_html,
+            command.session_id.is_some(),
+            command.session_id.flatten(),
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let Some(updated) = updated else {
+            return Ok(None);
+        };
+
+        sqlx::query!(
+            r#"
+                INSERT INTO journal_entry_revisions
+                    (entry_id, revision, title, body, edited_by)
+                VALUES
+                    ($1, $2, $3, $4, $5)
+            "#,
+            command.id,
+            updated.revision,
+            updated.title,
+            updated.body,
+            command.edited_by,
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let entry = fetch_entry(&mut *tx, command.id).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(entry.map(JournalEntry::from))
+    }
+
+    #[instrument(name = "journal.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<JournalEntry>> {
+        let entry = fetch_entry(&self.pool, id).await?;
+
+        Ok(entry.map(JournalEntry::from))
+    }
+
+    #[instrument(name = "journal.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_table_id(
+        &self,
+        table_id: Uuid,
+        session_id: Option<Uuid>,
+    ) -> Result<Vec<JournalEntry>> {
+        let entries = sqlx::query_as!(
+            JournalEntryModel,
+            r#"
+                SELECT
+                    j.id,
+                    j.table_id,
+                    j.session_id,
+                    j.author_id,
+                    j.title,
+                    j.body,
+                    j.body_html,
+                    j.revision,
+                    COALESCE(s.scheduled_for, j.created_at) as "occurred_at!",
+                    j.created_at,
+                    j.updated_at
+                FROM journal_entries j
+                LEFT JOIN sessions s ON s.id = j.session_id
+                WHERE j.table_id = $1
+                  AND ($2::UUID IS NULL OR j.session_id = $2)
+                ORDER BY COALESCE(s.scheduled_for, j.created_at) ASC, j.created_at ASC
+            "#,
+            table_id,
+            session_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(entries.into_iter().map(JournalEntry::from).collect())
+    }
+
+    #[instrument(name = "journal.find_revisions", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_revisions(&self, entry_id: Uuid) -> Result<Vec<JournalEntryRevision>> {
+        let revisions = sqlx::query_as!(
+            JournalEntryRevisionModel,
+            r#"
+                SELECT entry_id, revision, title, body, edited_by, created_at
+                FROM journal_entry_revisions
+                WHERE entry_id = $1
+                ORDER BY revision DESC
+            "#,
+            entry_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(revisions
+            .into_iter()
+            .map(JournalEntryRevision::from)
+            .collect())
+    }
+
+    #[instrument(name = "journal.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn delete(&self, id: Uuid) -> Result<Option<JournalEntry>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let entry = fetch_entry(&mut *tx, id).await?;
+
+        sqlx::query!("DELETE FROM journal_entries WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(entry.map(JournalEntry::from))
+    }
+}
@@ -0,0 +1,103 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::MagicLinkTokenModel;
+use chrono::{DateTime, Utc};
+use domain::entities::*;
+use domain::repositories::MagicLinkRepository;
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresMagicLinkRepository {
+    pool: PgPool,
+}
+
+impl PostgresMagicLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl MagicLinkRepository for PostgresMagicLinkRepository {
+    #[instrument(name = "magic_link.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create(&self, command: CreateMagicLinkTokenCommand) -> Result<MagicLinkToken> {
+        let token = sqlx::query_as!(
+            MagicLinkTokenModel,
+            r#"
+                INSERT INTO magic_link_tokens
+                    (id, user_id, token_hash, expires_at)
+                VALUES
+                    ($1, $2, $3, $4)
+                RETURNING
+                    *
+            "#,
+            command.id,
+            command.user_id,
+            command.token_hash,
+            command.expires_at,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(token.into())
+    }
+
+    #[instrument(name = "magic_link.consume", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn consume(&self, token_hash: &str) -> Result<Option<MagicLinkToken>> {
+        let token = sqlx::query_as!(
+            MagicLinkTokenModel,
+            r#"
+                UPDATE magic_link_tokens
+                SET consumed_at = NOW()
+                WHERE token_hash = $1
+                  AND consumed_at IS NULL
+                  AND expires_at > NOW()
+                RETURNING
+                    *
+            "#,
+            token_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(token.map(|t| t.into()))
+    }
+
+    #[instrument(name = "magic_link.count_created_since", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn count_created_since(&self, user_id: Uuid, since: DateTime<Utc>) -> Result<i64> {
+        let count = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) as "count!"
+                FROM magic_link_tokens
+                WHERE user_id = $1 AND created_at >= $2
+            "#,
+            user_id,
+            since
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(count)
+    }
+
+    #[instrument(name = "magic_link.purge_expired", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn purge_expired(&self, expired_before: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM magic_link_tokens
+                WHERE expires_at < $1
+            "#,
+            expired_before
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,19 +1,41 @@
+pub mod account_export;
+pub mod announcement;
+pub mod attachment;
+pub mod audit_log;
+pub mod chat_message;
 pub mod game_system;
+pub mod image;
+pub mod journal;
+pub mod magic_link;
 pub mod refresh_token;
+pub mod search;
 pub mod session;
 pub mod session_checkin;
 pub mod session_intent;
 pub mod table;
 pub mod table_member;
+pub mod table_question;
 pub mod table_request;
 pub mod user;
+pub mod user_event;
 
+pub use account_export::*;
+pub use announcement::*;
+pub use attachment::*;
+pub use audit_log::*;
+pub use chat_message::*;
 pub use game_system::*;
+pub use image::*;
+pub use journal::*;
+pub use magic_link::*;
 pub use refresh_token::*;
+pub use search::*;
 pub use session::*;
 pub use session_checkin::*;
 pub use session_intent::*;
 pub use table::*;
 pub use table_member::*;
+pub use table_question::*;
 pub use table_request::*;
 pub use user::*;
+pub use user_event::*;
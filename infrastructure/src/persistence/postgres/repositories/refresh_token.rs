@@ -4,6 +4,7 @@ use domain::entities::*;
 use domain::repositories::{RefreshTokenRepository, Repository};
 use shared::Result;
 use sqlx::PgPool;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -27,6 +28,7 @@ impl
         DeleteRefreshTokenCommand,
     > for PostgresRefreshTokenRepository
 {
+    #[instrument(name = "refresh_token.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, token: CreateRefreshTokenCommand) -> Result<RefreshToken> {
         let refresh_token = sqlx::query_as!(
             RefreshTokenModel,
@@ -50,18 +52,22 @@ impl
         Ok(refresh_token.into())
     }
 
+    #[instrument(name = "refresh_token.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, _: GetRefreshTokenCommand) -> Result<Vec<RefreshToken>> {
         todo!()
     }
 
+    #[instrument(name = "refresh_token.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, _: UpdateRefreshTokenCommand) -> Result<RefreshToken> {
         todo!()
     }
 
+    #[instrument(name = "refresh_token.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, _: DeleteRefreshTokenCommand) -> Result<RefreshToken> {
         todo!()
     }
 
+    #[instrument(name = "refresh_token.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, _: uuid::Uuid) -> Result<Option<RefreshToken>> {
         todo!()
     }
@@ -69,6 +75,7 @@ impl
 
 #[async_trait::async_trait]
 impl RefreshTokenRepository for PostgresRefreshTokenRepository {
+    #[instrument(name = "refresh_token.find_by_token", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_token(&self, token: &str) -> Result<Option<RefreshToken>> {
         let refresh_token = sqlx::query_as!(
             RefreshTokenModel,
@@ -87,6 +94,7 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         Ok(refresh_token.map(|r| r.into()))
     }
 
+    #[instrument(name = "refresh_token.delete_by_token", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete_by_token(&self, token: &str) -> Result<Option<RefreshToken>> {
         let refresh_token = sqlx::query_as!(
             RefreshTokenModel,
@@ -104,6 +112,7 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         Ok(refresh_token.map(|r| r.into()))
     }
 
+    #[instrument(name = "refresh_token.delete_by_user", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete_by_user(&self, user_id: Uuid) -> Result<Vec<RefreshToken>> {
         let refresh_token = sqlx::query_as!(
             RefreshTokenModel,
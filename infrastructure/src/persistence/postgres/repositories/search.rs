@@ -0,0 +1,163 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::SearchHitModel;
+use domain::repositories::SearchRepository;
+use domain::search::{SearchCriteria, SearchResult};
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct PostgresSearchRepository {
+    pool: PgPool,
+}
+
+impl PostgresSearchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchRepository for PostgresSearchRepository {
+    #[instrument(name = "search.search", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn search(&self, criteria: SearchCriteria) -> Result<(Vec<SearchResult>, u64)> {
+        let types: Vec<String> = criteria
+            .types
+            .iter()
+            .map(|t| t.as_str().to_string())
+            .collect();
+
+        let rows = sqlx::query_as!(
+            SearchHitModel,
+            r#"
+                WITH q AS (
+                    SELECT websearch_to_tsquery('simple', $1) AS query
+                ),
+                hits AS (
+                    SELECT
+                        t.id,
+                        t.title,
+                        t.description AS description,
+                        'table'::text AS kind,
+                        t.created_at,
+                        ts_rank(t.search_vector, q.query) AS rank,
+                        t.title || ' ' || t.description AS body
+                    FROM q, tables t
+                    WHERE t.deleted_at IS NULL
+                      AND (
+                          t.visibility = 'public'
+                          OR t.gm_id = $5
+                          OR EXISTS (
+                              SELECT 1 FROM table_members m
+                              WHERE m.table_id = t.id AND m.user_id = $5
+                          )
+                      )
+                      AND t.search_vector @@ q.query
+                      AND (cardinality($2::text[]) = 0 OR 'table' = ANY($2))
+
+                    UNION ALL
+
+                    SELECT
+                        s.id,
+                        s.title,
+                        s.description,
+                        'session'::text,
+                        s.created_at,
+                        ts_rank(s.search_vector, q.query),
+                        s.title || ' ' || s.description
+                    FROM q, sessions s
+                    INNER JOIN tables t ON t.id = s.table_id AND t.deleted_at IS NULL
+                    WHERE s.deleted_at IS NULL
+                      AND (
+                          t.visibility = 'public'
+                          OR t.gm_id = $5
+                          OR EXISTS (
+                              SELECT 1 FROM table_members m
+                              WHERE m.table_id = t.id AND m.user_id = $5
+                          )
+                      )
+                      AND s.search_vector @@ q.query
+                      AND (cardinality($2::text[]) = 0 OR 'session' = ANY($2))
+
+                    UNION ALL
+
+                    SELECT
+                        u.id,
+                        u.username,
+                        NULL::text,
+                        'user'::text,
+                        u.created_at,
+                        ts_rank(u.search_vector, q.query),
+                        u.username
+                    FROM q, users u
+                    WHERE u.deleted_at IS NULL
+                      AND u.suspended_at IS NULL
+                      AND u.search_vector @@ q.query
+                      AND (cardinality($2::text[]) = 0 OR 'user' = ANY($2))
+
+                    UNION ALL
+
+                    SELECT
+                        g.id,
+                        g.name,
+                        NULL::text,
+                        'game_system'::text,
+                        g.created_at,
+                        ts_rank(g.search_vector, q.query),
+                        g.name
+                    FROM q, game_systems g
+                    WHERE g.search_vector @@ q.query
+                      AND (cardinality($2::text[]) = 0 OR 'game_system' = ANY($2))
+                ),
+                page AS (
+                    SELECT *
+                    FROM hits
+                    ORDER BY rank DESC, created_at DESC, id
+                    LIMIT $3 OFFSET $4
+                )
+                SELECT
+                    page.id,
+                    page.title,
+                    page.description,
+                    page.kind,
+                    page.created_at,
+                    page.rank,
+                    -- The body is HTML-escaped first so only the <mark> tags
+                    -- added here reach clients as markup.
+                    ts_headline(
+                        'simple',
+                        replace(replace(replace(replace(replace(
+                            page.body,
+                            '&', '&amp;'),
+                            '<', '&lt;'),
+                            '>', '&gt;'),
+                            '"', '&quot;'),
+                            '''', '&#39;'),
+                        q.query,
+                        'StartSel=<mark>, StopSel=</mark>, MaxWords=30, MinWords=10, MaxFragments=2'
+                    ) AS highlight,
+                    counts.total AS "total!"
+                FROM (SELECT COUNT(*) AS total FROM hits) counts
+                CROSS JOIN q
+                LEFT JOIN page ON true
+                ORDER BY page.rank DESC, page.created_at DESC, page.id
+            "#,
+            criteria.terms,
+            &types,
+            i64::from(criteria.limit),
+            i64::from(criteria.offset),
+            criteria.viewer_id,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let total = rows.first().map(|row| row.total).unwrap_or_default() as u64;
+        let results = rows
+            .into_iter()
+            .filter_map(SearchHitModel::into_result)
+            .collect();
+
+        Ok((results, total))
+    }
+}
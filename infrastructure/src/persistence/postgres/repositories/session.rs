@@ -2,11 +2,14 @@ use crate::persistence::models::{EIntentStatus, SessionCheckinResultModel};
 use crate::persistence::postgres::constraint_mapper;
 use crate::persistence::postgres::models::SessionModel;
 use crate::persistence::postgres::models::session::ESessionStatus;
+use crate::persistence::postgres::repositories::{insert_audit_log, insert_user_event};
+use chrono::{DateTime, Utc};
 use domain::entities::session_checkin::{SessionFinalizationData, SessionFinalizationResult};
 use domain::entities::*;
-use domain::repositories::{Repository, SessionRepository};
+use domain::repositories::{PaginationParams, Repository, SessionRepository};
 use shared::Result;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
+use tracing::instrument;
 use uuid::Uuid;
 
 pub struct PostgresSessionRepository {
@@ -19,6 +22,79 @@ impl PostgresSessionRepository {
     }
 }
 
+async fn insert_session<'e, E: PgExecutor<'e>>(
+    executor: E,
+    session: CreateSessionCommand,
+) -> Result<Session> {
+    let created_session = sqlx::query_as!(
+        SessionModel,
+        r#"
+            INSERT INTO sessions
+                (id, title, description, table_id, scheduled_for, status)
+            VALUES
+                ($1, $2, $3, $4, $5, $6)
+            RETURNING
+                id,
+                title,
+                description,
+                table_id,
+                scheduled_for,
+                status as "status: ESessionStatus",
+                created_at,
+                updated_at
+        "#,
+        session.id,
+        session.title,
+        session.description,
+        session.table_id,
+        session.scheduled_for.as_ref(),
+        ESessionStatus::from(session.status) as ESessionStatus
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(created_session.into())
+}
+
+async fn update_session<'e, E: PgExecutor<'e>>(
+    executor: E,
+    command: UpdateSessionCommand,
+) -> Result<Session> {
+    let updated_session = sqlx::query_as!(
+        SessionModel,
+        r#"
+            UPDATE sessions
+            SET
+                title = COALESCE($2, title),
+                description = COALESCE($3, description),
+                scheduled_for = COALESCE($4, scheduled_for),
+                status = COALESCE($5, status),
+                updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING
+                id,
+                title,
+                description,
+                table_id,
+                scheduled_for,
+                status as "status: ESessionStatus",
+                created_at,
+                updated_at
+        "#,
+        command.id,
+        command.title.as_deref(),
+        command.description.as_deref(),
+        command.scheduled_for,
+        command.status.map(ESessionStatus::from) as Option<ESessionStatus>,
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(updated_session.into())
+}
+
 #[async_trait::async_trait]
 impl
     Repository<
@@ -29,38 +105,12 @@ impl
         DeleteSessionCommand,
     > for PostgresSessionRepository
 {
+    #[instrument(name = "session.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, session: CreateSessionCommand) -> Result<Session> {
-        let created_session = sqlx::query_as!(
-            SessionModel,
-            r#"
-                INSERT INTO sessions
-                    (id, title, description, table_id, scheduled_for, status)
-                VALUES
-                    ($1, $2, $3, $4, $5, $6)
-                RETURNING
-                    id,
-                    title,
-                    description,
-                    table_id,
-                    scheduled_for,
-                    status as "status: ESessionStatus",
-                    created_at,
-                    updated_at
-            "#,
-            session.id,
-            session.title,
-            session.description,
-            session.table_id,
-            session.scheduled_for.as_ref(),
-            ESessionStatus::from(session.status) as ESessionStatus
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(constraint_mapper::map_database_error)?;
-
-        Ok(created_session.into())
+        insert_session(&self.pool, session).await
     }
 
+    #[instrument(name = "session.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetSessionCommand) -> Result<Vec<Session>> {
         let sessions = sqlx::query_as!(
             SessionModel,
@@ -80,12 +130,28 @@ impl
                     AND ($3::timestamptz IS NULL OR scheduled_for >= $3)
                     AND ($4::timestamptz IS NULL OR scheduled_for <= $4)
                     AND ($5::session_status IS NULL OR status = $5)
+                    AND deleted_at IS NULL
+                    AND ($8::uuid IS NULL OR CASE WHEN $7 = 'asc' THEN id > $8 ELSE id < $8 END)
+                ORDER BY
+                    CASE WHEN $6 = 'scheduled_for' AND $7 = 'asc' THEN scheduled_for END ASC,
+                    CASE WHEN $6 = 'scheduled_for' AND $7 = 'desc' THEN scheduled_for END DESC,
+                    CASE WHEN $6 = 'title' AND $7 = 'asc' THEN title END ASC,
+                    CASE WHEN $6 = 'title' AND $7 = 'desc' THEN title END DESC,
+                    CASE WHEN $7 = 'asc' THEN id END ASC,
+                    id DESC
+                LIMIT $9
+                OFFSET $10
             "#,
             command.id,
             command.table_id,
             command.scheduled_after.as_ref(),
             command.scheduled_before.as_ref(),
             command.status.map(ESessionStatus::from) as Option<ESessionStatus>,
+            command.sort.field.as_str(),
+            command.sort.order.as_str(),
+            command.page.as_ref().and_then(|page| page.cursor),
+            command.page.as_ref().map(PaginationParams::limit),
+            command.page.as_ref().map_or(0, PaginationParams::offset),
         )
         .fetch_all(&self.pool)
         .await
@@ -94,41 +160,12 @@ impl
         Ok(sessions.into_iter().map(|s| s.into()).collect())
     }
 
+    #[instrument(name = "session.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, command: UpdateSessionCommand) -> Result<Session> {
-        let updated_session = sqlx::query_as!(
-            SessionModel,
-            r#"
-                UPDATE sessions
-                SET
-                    title = COALESCE($2, title),
-                    description = COALESCE($3, description),
-                    scheduled_for = COALESCE($4, scheduled_for),
-                    status = COALESCE($5, status),
-                    updated_at = NOW()
-                WHERE id = $1
-                RETURNING
-                    id,
-                    title,
-                    description,
-                    table_id,
-                    scheduled_for,
-                    status as "status: ESessionStatus",
-                    created_at,
-                    updated_at
-            "#,
-            command.id,
-            command.title.as_deref(),
-            command.description.as_deref(),
-            command.scheduled_for,
-            command.status.map(ESessionStatus::from) as Option<ESessionStatus>,
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(constraint_mapper::map_database_error)?;
-
-        Ok(updated_session.into())
+        update_session(&self.pool, command).await
     }
 
+    #[instrument(name = "session.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteSessionCommand) -> Result<Session> {
         let session = sqlx::query_as!(
             SessionModel,
@@ -154,6 +191,7 @@ impl
         Ok(session.into())
     }
 
+    #[instrument(name = "session.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Session>> {
         let session = sqlx::query_as!(
             SessionModel,
@@ -168,7 +206,7 @@ impl
                     created_at,
                     updated_at
                 FROM sessions
-                WHERE id = $1
+                WHERE id = $1 AND deleted_at IS NULL
             "#,
             id
         )
@@ -182,6 +220,33 @@ impl
 
 #[async_trait::async_trait]
 impl SessionRepository for PostgresSessionRepository {
+    #[instrument(name = "session.count", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn count(&self, command: GetSessionCommand) -> Result<i64> {
+        let total = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!"
+                FROM sessions
+                WHERE ($1::uuid IS NULL OR id = $1)
+                    AND ($2::uuid IS NULL OR table_id = $2)
+                    AND ($3::timestamptz IS NULL OR scheduled_for >= $3)
+                    AND ($4::timestamptz IS NULL OR scheduled_for <= $4)
+                    AND ($5::session_status IS NULL OR status = $5)
+                    AND deleted_at IS NULL
+            "#,
+            command.id,
+            command.table_id,
+            command.scheduled_after.as_ref(),
+            command.scheduled_before.as_ref(),
+            command.status.map(ESessionStatus::from) as Option<ESessionStatus>,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(total)
+    }
+
+    #[instrument(name = "session.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<Session>> {
         let sessions = sqlx::query_as!(
             SessionModel,
@@ -196,7 +261,7 @@ impl SessionRepository for PostgresSessionRepository {
                     created_at,
                     updated_at
                 FROM sessions
-                WHERE table_id = $1
+                WHERE table_id = $1 AND deleted_at IS NULL
             "#,
             table_id
         )
@@ -207,9 +272,61 @@ impl SessionRepository for PostgresSessionRepository {
         Ok(sessions.into_iter().map(|model| model.into()).collect())
     }
 
+    #[instrument(name = "session.create_with_event", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create_with_event(
+        &self,
+        command: CreateSessionCommand,
+        kind: UserEventKind,
+    ) -> Result<Session> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let session = insert_session(&mut *tx, command).await?;
+        let event = CreateUserEventCommand::for_table(session.table_id, kind, &session);
+        insert_user_event(&mut tx, event).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(session)
+    }
+
+    #[instrument(name = "session.update_with_audit", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn update_with_audit(
+        &self,
+        command: UpdateSessionCommand,
+        audit: CreateAuditLogCommand,
+        event: Option<UserEventKind>,
+    ) -> Result<Session> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let session = update_session(&mut *tx, command).await?;
+        insert_audit_log(&mut *tx, audit.with_after(&session)).await?;
+        if let Some(kind) = event {
+            let event = CreateUserEventCommand::for_table(session.table_id, kind, &session);
+            insert_user_event(&mut tx, event).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(session)
+    }
+
+    #[instrument(name = "session.finalize_session_with_checkins", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn finalize_session_with_checkins(
         &self,
         finalization_data: SessionFinalizationData,
+        audit: CreateAuditLogCommand,
     ) -> Result<SessionFinalizationResult> {
         let mut tx = self
             .pool
@@ -291,13 +408,164 @@ impl SessionRepository for PostgresSessionRepository {
         .await
         .map_err(constraint_mapper::map_database_error)?;
 
+        let session = Session::from(updated_session);
+        insert_audit_log(&mut *tx, audit.with_after(&session)).await?;
+        let event = CreateUserEventCommand::for_table(
+            session.table_id,
+            UserEventKind::SessionCompleted,
+            &session,
+        );
+        insert_user_event(&mut tx, event).await?;
+
         tx.commit()
             .await
             .map_err(constraint_mapper::map_database_error)?;
 
         Ok(SessionFinalizationResult {
-            session: updated_session.into(),
+            session,
             checkins: checkins.into_iter().map(|c| c.into()).collect(),
         })
     }
+
+    #[instrument(name = "session.soft_delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn soft_delete(&self, session_id: Uuid, audit: CreateAuditLogCommand) -> Result<Session> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let session = sqlx::query_as!(
+            SessionModel,
+            r#"
+                UPDATE sessions
+                SET deleted_at = NOW()
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING
+                    id,
+                    title,
+                    description,
+                    table_id,
+                    scheduled_for,
+                    status as "status: ESessionStatus",
+                    created_at,
+                    updated_at
+            "#,
+            session_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        insert_audit_log(&mut *tx, audit).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(session.into())
+    }
+
+    #[instrument(name = "session.find_deleted_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_deleted_by_id(
+        &self,
+        session_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> Result<Option<Session>> {
+        let session = sqlx::query_as!(
+            SessionModel,
+            r#"
+                SELECT
+                    id,
+                    title,
+                    description,
+                    table_id,
+                    scheduled_for,
+                    status as "status: ESessionStatus",
+                    created_at,
+                    updated_at
+                FROM sessions
+                WHERE id = $1 AND deleted_at >= $2
+            "#,
+            session_id,
+            deleted_since
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(session.map(|model| model.into()))
+    }
+
+    #[instrument(name = "session.restore", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn restore(
+        &self,
+        session_id: Uuid,
+        deleted_since: DateTime<Utc>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Option<Session>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        // Sessions of a deleted table come back only with the table itself.
+        let session = sqlx::query_as!(
+            SessionModel,
+            r#"
+                UPDATE sessions
+                SET
+                    deleted_at = NULL,
+                    updated_at = NOW()
+                WHERE id = $1
+                    AND deleted_at >= $2
+                    AND EXISTS (
+                        SELECT 1 FROM tables
+                        WHERE tables.id = sessions.table_id AND tables.deleted_at IS NULL
+                    )
+                RETURNING
+                    id,
+                    title,
+                    description,
+                    table_id,
+                    scheduled_for,
+                    status as "status: ESessionStatus",
+                    created_at,
+                    updated_at
+            "#,
+            session_id,
+            deleted_since
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?
+        .map(Session::from);
+
+        if let Some(session) = &session {
+            insert_audit_log(&mut *tx, audit.with_after(session)).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(session)
+    }
+
+    #[instrument(name = "session.purge_deleted", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM sessions
+                WHERE deleted_at < $1
+            "#,
+            deleted_before
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(result.rows_affected())
+    }
 }
@@ -4,6 +4,7 @@ use domain::entities::*;
 use domain::repositories::{Repository, SessionCheckinRepository};
 use shared::Result;
 use sqlx::PgPool;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -27,6 +28,7 @@ impl
         DeleteSessionCheckinCommand,
     > for PostgresSessionCheckinRepository
 {
+    #[instrument(name = "session_checkin.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, command: CreateSessionCheckinCommand) -> Result<SessionCheckin> {
         let created_session_checkin = sqlx::query_as!(
             SessionCheckinModel,
@@ -50,16 +52,24 @@ impl
         Ok(created_session_checkin.into())
     }
 
+    #[instrument(name = "session_checkin.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetSessionCheckinCommand) -> Result<Vec<SessionCheckin>> {
         let session_checkins = sqlx::query_as!(
             SessionCheckinModel,
             r#"
                 SELECT
-                    *
+                    session_checkins.*
                 FROM session_checkins
-                WHERE ($1::uuid IS NULL OR id = $1)
-                    AND ($2::uuid IS NULL OR session_intent_id = $2)
-                    AND ($3::bool IS NULL OR attendance = $3)
+                INNER JOIN session_intents ON session_intents.id = session_checkins.session_intent_id
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND ($1::uuid IS NULL OR session_checkins.id = $1)
+                    AND ($2::uuid IS NULL OR session_checkins.session_intent_id = $2)
+                    AND ($3::bool IS NULL OR session_checkins.attendance = $3)
             "#,
             command.id,
             command.session_intent_id,
@@ -75,6 +85,7 @@ impl
             .collect())
     }
 
+    #[instrument(name = "session_checkin.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, command: UpdateSessionCheckinCommand) -> Result<SessionCheckin> {
         let updated_session_checkin = sqlx::query_as!(
             SessionCheckinModel,
@@ -100,6 +111,7 @@ impl
         Ok(updated_session_checkin.into())
     }
 
+    #[instrument(name = "session_checkin.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteSessionCheckinCommand) -> Result<SessionCheckin> {
         let session_checkin = sqlx::query_as!(
             SessionCheckinModel,
@@ -118,14 +130,22 @@ impl
         Ok(session_checkin.into())
     }
 
+    #[instrument(name = "session_checkin.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<SessionCheckin>> {
         let session_checkin = sqlx::query_as!(
             SessionCheckinModel,
             r#"
                 SELECT
-                    *
+                    session_checkins.*
                 FROM session_checkins
-                WHERE id = $1
+                INNER JOIN session_intents ON session_intents.id = session_checkins.session_intent_id
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND session_checkins.id = $1
             "#,
             id
         )
@@ -139,6 +159,7 @@ impl
 
 #[async_trait::async_trait]
 impl SessionCheckinRepository for PostgresSessionCheckinRepository {
+    #[instrument(name = "session_checkin.find_by_session_intent_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_session_intent_id(
         &self,
         session_intent_id: Uuid,
@@ -147,9 +168,16 @@ impl SessionCheckinRepository for PostgresSessionCheckinRepository {
             SessionCheckinModel,
             r#"
                 SELECT
-                    *
+                    session_checkins.*
                 FROM session_checkins
-                WHERE session_intent_id = $1
+                INNER JOIN session_intents ON session_intents.id = session_checkins.session_intent_id
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND session_checkins.session_intent_id = $1
             "#,
             session_intent_id
         )
@@ -163,14 +191,22 @@ impl SessionCheckinRepository for PostgresSessionCheckinRepository {
             .collect())
     }
 
+    #[instrument(name = "session_checkin.find_by_attendance", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_attendance(&self, attendance: bool) -> Result<Vec<SessionCheckin>> {
         let session_checkins = sqlx::query_as!(
             SessionCheckinModel,
             r#"
                 SELECT
-                    *
+                    session_checkins.*
                 FROM session_checkins
-                WHERE attendance = $1
+                INNER JOIN session_intents ON session_intents.id = session_checkins.session_intent_id
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND session_checkins.attendance = $1
             "#,
             attendance
         )
@@ -183,4 +219,33 @@ impl SessionCheckinRepository for PostgresSessionCheckinRepository {
             .map(|model| model.into())
             .collect())
     }
+
+    #[instrument(name = "session_checkin.find_by_user_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<SessionCheckin>> {
+        let session_checkins = sqlx::query_as!(
+            SessionCheckinModel,
+            r#"
+                SELECT
+                    session_checkins.*
+                FROM session_checkins
+                INNER JOIN session_intents ON session_intents.id = session_checkins.session_intent_id
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND session_intents.user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::DatabaseError)?;
+
+        Ok(session_checkins
+            .into_iter()
+            .map(|model| model.into())
+            .collect())
+    }
 }
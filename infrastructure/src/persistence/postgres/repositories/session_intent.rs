@@ -1,9 +1,12 @@
 use crate::persistence::postgres::constraint_mapper;
 use crate::persistence::postgres::models::{SessionIntentModel, session_intent::EIntentStatus};
+use crate::persistence::postgres::repositories::insert_user_event;
 use domain::entities::*;
 use domain::repositories::{Repository, SessionIntentRepository};
+use serde_json::json;
 use shared::Result;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -17,6 +20,67 @@ impl PostgresSessionIntentRepository {
     }
 }
 
+async fn insert_session_intent<'e, E: PgExecutor<'e>>(
+    executor: E,
+    command: CreateSessionIntentCommand,
+) -> Result<SessionIntent> {
+    let session_intent = sqlx::query_as!(
+        SessionIntentModel,
+        r#"
+            INSERT INTO session_intents
+                (id, user_id, session_id, intent_status)
+            VALUES
+                ($1, $2, $3, $4)
+            RETURNING
+                id,
+                user_id,
+                session_id,
+                intent_status as "intent_status: EIntentStatus",
+                created_at,
+                updated_at
+        "#,
+        command.id,
+        command.player_id,
+        command.session_id,
+        EIntentStatus::from(command.status) as EIntentStatus,
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(session_intent.into())
+}
+
+async fn update_session_intent<'e, E: PgExecutor<'e>>(
+    executor: E,
+    command: UpdateSessionIntentCommand,
+) -> Result<SessionIntent> {
+    let updated_model = sqlx::query_as!(
+        SessionIntentModel,
+        r#"
+            UPDATE session_intents
+            SET
+                intent_status = COALESCE($2, intent_status),
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING
+                id,
+                user_id,
+                session_id,
+                intent_status as "intent_status: EIntentStatus",
+                created_at,
+                updated_at
+        "#,
+        command.id,
+        command.status.map(EIntentStatus::from) as Option<EIntentStatus>
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(updated_model.into())
+}
+
 #[async_trait::async_trait]
 impl
     Repository<
@@ -27,61 +91,17 @@ impl
         DeleteSessionIntentCommand,
     > for PostgresSessionIntentRepository
 {
+    #[instrument(name = "session_intent.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, command: CreateSessionIntentCommand) -> Result<SessionIntent> {
-        let session_intent = sqlx::query_as!(
-            SessionIntentModel,
-            r#"
-                INSERT INTO session_intents
-                    (id, user_id, session_id, intent_status)
-                VALUES
-                    ($1, $2, $3, $4)
-                RETURNING
-                    id,
-                    user_id,
-                    session_id,
-                    intent_status as "intent_status: EIntentStatus",
-                    created_at,
-                    updated_at
-            "#,
-            command.id,
-            command.player_id,
-            command.session_id,
-            EIntentStatus::from(command.status) as EIntentStatus,
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(constraint_mapper::map_database_error)?;
-
-        Ok(session_intent.into())
+        insert_session_intent(&self.pool, command).await
     }
 
+    #[instrument(name = "session_intent.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, command: UpdateSessionIntentCommand) -> Result<SessionIntent> {
-        let updated_model = sqlx::query_as!(
-            SessionIntentModel,
-            r#"
-                UPDATE session_intents
-                SET
-                    intent_status = COALESCE($2, intent_status),
-                    updated_at = NOW()
-                WHERE id = $1
-                RETURNING
-                    id,
-                    user_id,
-                    session_id,
-                    intent_status as "intent_status: EIntentStatus",
-                    created_at,
-                    updated_at
-            "#,
-            command.id,
-            command.status.map(EIntentStatus::from) as Option<EIntentStatus>
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(constraint_mapper::map_database_error)?;
-
-        Ok(updated_model.into())
+        update_session_intent(&self.pool, command).await
     }
 
+    #[instrument(name = "session_intent.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteSessionIntentCommand) -> Result<SessionIntent> {
         let session_intent = sqlx::query_as!(
             SessionIntentModel,
@@ -105,22 +125,29 @@ impl
         Ok(session_intent.into())
     }
 
+    #[instrument(name = "session_intent.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetSessionIntentCommand) -> Result<Vec<SessionIntent>> {
         let sessions = sqlx::query_as!(
             SessionIntentModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    session_id,
-                    intent_status as "intent_status: EIntentStatus",
-                    created_at,
-                    updated_at
+                    session_intents.id,
+                    session_intents.user_id,
+                    session_intents.session_id,
+                    session_intents.intent_status as "intent_status: EIntentStatus",
+                    session_intents.created_at,
+                    session_intents.updated_at
                 FROM session_intents
-                WHERE ($1::uuid IS NULL OR id = $1)
-                  AND ($2::uuid IS NULL OR user_id = $2)
-                  AND ($3::uuid IS NULL OR session_id = $3)
-                  AND ($4::intent_status IS NULL OR intent_status = $4)
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND ($1::uuid IS NULL OR session_intents.id = $1)
+                    AND ($2::uuid IS NULL OR session_intents.user_id = $2)
+                    AND ($3::uuid IS NULL OR session_intents.session_id = $3)
+                    AND ($4::intent_status IS NULL OR session_intents.intent_status = $4)
             "#,
             command.id,
             command.user_id,
@@ -134,19 +161,26 @@ impl
         Ok(sessions.into_iter().map(|s| s.into()).collect())
     }
 
+    #[instrument(name = "session_intent.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<SessionIntent>> {
         let session_intent = sqlx::query_as!(
             SessionIntentModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    session_id,
-                    intent_status as "intent_status: EIntentStatus",
-                    created_at,
-                    updated_at
+                    session_intents.id,
+                    session_intents.user_id,
+                    session_intents.session_id,
+                    session_intents.intent_status as "intent_status: EIntentStatus",
+                    session_intents.created_at,
+                    session_intents.updated_at
                 FROM session_intents
-                WHERE id = $1
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND session_intents.id = $1
             "#,
             id
         )
@@ -160,19 +194,72 @@ impl
 
 #[async_trait::async_trait]
 impl SessionIntentRepository for PostgresSessionIntentRepository {
+    #[instrument(name = "session_intent.create_with_event", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn create_with_event(
+        &self,
+        command: CreateSessionIntentCommand,
+        table_id: Uuid,
+    ) -> Result<SessionIntent> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let intent = insert_session_intent(&mut *tx, command).await?;
+        insert_user_event(&mut tx, intent_changed(table_id, &intent)).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(intent)
+    }
+
+    #[instrument(name = "session_intent.update_with_event", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn update_with_event(
+        &self,
+        command: UpdateSessionIntentCommand,
+        table_id: Option<Uuid>,
+    ) -> Result<SessionIntent> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let intent = update_session_intent(&mut *tx, command).await?;
+        if let Some(table_id) = table_id {
+            insert_user_event(&mut tx, intent_changed(table_id, &intent)).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(intent)
+    }
+
+    #[instrument(name = "session_intent.find_by_user_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<SessionIntent>> {
         let session_intents = sqlx::query_as!(
             SessionIntentModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    session_id,
-                    intent_status as "intent_status: EIntentStatus",
-                    created_at,
-                    updated_at
+                    session_intents.id,
+                    session_intents.user_id,
+                    session_intents.session_id,
+                    session_intents.intent_status as "intent_status: EIntentStatus",
+                    session_intents.created_at,
+                    session_intents.updated_at
                 FROM session_intents
-                WHERE user_id = $1
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND session_intents.user_id = $1
             "#,
             user_id
         )
@@ -186,19 +273,26 @@ impl SessionIntentRepository for PostgresSessionIntentRepository {
             .collect())
     }
 
+    #[instrument(name = "session_intent.find_by_session_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_session_id(&self, session_id: Uuid) -> Result<Vec<SessionIntent>> {
         let session_intents = sqlx::query_as!(
             SessionIntentModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    session_id,
-                    intent_status as "intent_status: EIntentStatus",
-                    created_at,
-                    updated_at
+                    session_intents.id,
+                    session_intents.user_id,
+                    session_intents.session_id,
+                    session_intents.intent_status as "intent_status: EIntentStatus",
+                    session_intents.created_at,
+                    session_intents.updated_at
                 FROM session_intents
-                WHERE session_id = $1
+                INNER JOIN sessions ON sessions.id = session_intents.session_id
+                INNER JOIN tables ON tables.id = sessions.table_id
+                INNER JOIN users ON users.id = session_intents.user_id
+                WHERE sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND session_intents.session_id = $1
             "#,
             session_id
         )
@@ -212,3 +306,8 @@ impl SessionIntentRepository for PostgresSessionIntentRepository {
             .collect())
     }
 }
+
+fn intent_changed(table_id: Uuid, intent: &SessionIntent) -> CreateUserEventCommand {
+    let payload = json!({ "table_id": table_id, "intent": intent });
+    CreateUserEventCommand::for_table(table_id, UserEventKind::IntentChanged, &payload)
+}
@@ -2,16 +2,20 @@ use crate::persistence::constraint_mapper::map_database_error;
 use crate::persistence::models::ESessionStatus;
 use crate::persistence::models::SessionModel;
 use crate::persistence::models::TableDetailsModel;
-use crate::persistence::models::UserModel;
-use crate::persistence::models::table::ETableStatus;
+use crate::persistence::models::table::{EAgeRating, ETableFormat, ETableStatus, ETableVisibility};
+use crate::persistence::models::{EUserRole, UserModel};
 use crate::persistence::postgres::constraint_mapper;
-use crate::persistence::postgres::models::TableModel;
+use crate::persistence::postgres::models::{TableListingModel, TableModel};
+use crate::persistence::postgres::repositories::insert_audit_log;
+use chrono::{DateTime, Utc};
 use domain::entities::Table;
 use domain::entities::TableDetails;
+use domain::entities::TableListing;
 use domain::entities::commands::*;
-use domain::repositories::{Repository, TableRepository};
+use domain::repositories::{PaginationParams, Repository, TableRepository};
 use shared::Result;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -25,18 +29,93 @@ impl PostgresTableRepository {
     }
 }
 
+async fn update_table<'e, E: PgExecutor<'e>>(
+    executor: E,
+    command: UpdateTableCommand,
+) -> Result<Table> {
+    let location = command.location.clone().flatten();
+
+    let updated_table = sqlx::query_as!(
+        TableModel,
+        r#"
+            UPDATE tables
+            SET
+                title = COALESCE($2, title),
+                description = COALESCE($3, description),
+                slots = COALESCE($4, slots),
+                game_system_id = COALESCE($5, game_system_id),
+                status = COALESCE($6, status),
+                tags = COALESCE($7, tags),
+                language = CASE WHEN $8 THEN $9 ELSE language END,
+                format = COALESCE($10, format),
+                beginner_friendly = COALESCE($11, beginner_friendly),
+                age_rating = COALESCE($12, age_rating),
+                venue_address = CASE WHEN $13 THEN $14 ELSE venue_address END,
+                latitude = CASE WHEN $13 THEN $15 ELSE latitude END,
+                longitude = CASE WHEN $13 THEN $16 ELSE longitude END,
+                visibility = COALESCE($17, visibility),
+                updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            RETURNING
+                id,
+                gm_id,
+                title,
+                description,
+                slots,
+                status as "status: ETableStatus",
+                game_system_id,
+                tags,
+                language,
+                format as "format: ETableFormat",
+                beginner_friendly,
+                age_rating as "age_rating: EAgeRating",
+                venue_address,
+                latitude,
+                longitude,
+                visibility as "visibility: ETableVisibility",
+                created_at,
+                updated_at
+        "#,
+        command.id,
+        command.title.as_deref(),
+        command.description.as_deref(),
+        command.slots.map(|s| s as i32),
+        command.game_system_id,
+        command.status.map(ETableStatus::from) as Option<ETableStatus>,
+        command.tags.as_deref(),
+        command.language.is_some(),
+        command.language.flatten(),
+        command.format.map(ETableFormat::from) as Option<ETableFormat>,
+        command.beginner_friendly,
+        command.age_rating.map(EAgeRating::from) as Option<EAgeRating>,
+        command.location.is_some(),
+        location.as_ref().map(|l| l.address.as_str()),
+        location.as_ref().map(|l| l.latitude),
+        location.as_ref().map(|l| l.longitude),
+        command.visibility.map(ETableVisibility::from) as Option<ETableVisibility>,
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(updated_table.into())
+}
+
 #[async_trait::async_trait]
 impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand, DeleteTableCommand>
     for PostgresTableRepository
 {
+    #[instrument(name = "table.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, command: CreateTableCommand) -> Result<Table> {
         let created_table = sqlx::query_as!(
             TableModel,
             r#"
             INSERT INTO tables
-                (id, gm_id, title, description, slots, game_system_id)
+                (id, gm_id, title, description, slots, game_system_id,
+                 tags, language, format, beginner_friendly, age_rating,
+                 venue_address, latitude, longitude, visibility)
             VALUES
-                ($1, $2, $3, $4, $5, $6)
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             RETURNING
                 id,
                 gm_id,
@@ -45,6 +124,15 @@ impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand,
                 slots,
                 status as "status: ETableStatus",
                 game_system_id,
+                tags,
+                language,
+                format as "format: ETableFormat",
+                beginner_friendly,
+                age_rating as "age_rating: EAgeRating",
+                venue_address,
+                latitude,
+                longitude,
+                visibility as "visibility: ETableVisibility",
                 created_at,
                 updated_at
             "#,
@@ -54,6 +142,15 @@ impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand,
             command.description,
             command.slots as i32,
             command.game_system_id,
+            &command.tags,
+            command.language,
+            ETableFormat::from(command.format) as ETableFormat,
+            command.beginner_friendly,
+            EAgeRating::from(command.age_rating) as EAgeRating,
+            command.location.as_ref().map(|l| l.address.as_str()),
+            command.location.as_ref().map(|l| l.latitude),
+            command.location.as_ref().map(|l| l.longitude),
+            ETableVisibility::from(command.visibility) as ETableVisibility,
         )
         .fetch_one(&self.pool)
         .await
@@ -62,74 +159,19 @@ impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand,
         Ok(created_table.into())
     }
 
+    #[instrument(name = "table.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, command: UpdateTableCommand) -> Result<Table> {
-        let updated_table = sqlx::query_as!(
-            TableModel,
-            r#"
-                UPDATE tables
-                SET
-                    title = COALESCE($2, title),
-                    description = COALESCE($3, description),
-                    slots = COALESCE($4, slots),
-                    game_system_id = COALESCE($5, game_system_id),
-                    updated_at = NOW()
-                WHERE id = $1
-                RETURNING
-                    id,
-                    gm_id,
-                    title,
-                    description,
-                    slots,
-                    status as "status: ETableStatus",
-                    game_system_id,
-                    created_at,
-                    updated_at
-            "#,
-            command.id,
-            command.title.as_deref(),
-            command.description.as_deref(),
-            command.slots.map(|s| s as i32),
-            command.game_system_id,
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(constraint_mapper::map_database_error)?;
-
-        Ok(updated_table.into())
+        update_table(&self.pool, command).await
     }
 
+    #[instrument(name = "table.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetTableCommand) -> Result<Vec<Table>> {
-        let tables = sqlx::query_as!(
-            TableModel,
-            r#"
-                SELECT
-                    id,
-                    gm_id,
-                    title,
-                    description,
-                    slots,
-                    status as "status: ETableStatus",
-                    game_system_id,
-                    created_at,
-                    updated_at
-                FROM tables
-                WHERE ($1::uuid IS NULL OR id = $1)
-                    AND ($2::uuid IS NULL OR gm_id = $2)
-                    AND ($3::table_status IS NULL OR status = $3)
-                    AND ($4::uuid IS NULL OR game_system_id = $4)
-            "#,
-            command.id,
-            command.gm_id,
-            command.status.map(ETableStatus::from) as Option<ETableStatus>,
-            command.game_system_id
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(constraint_mapper::map_database_error)?;
+        let listings = self.list(command).await?;
 
-        Ok(tables.into_iter().map(|model| model.into()).collect())
+        Ok(listings.into_iter().map(|listing| listing.table).collect())
     }
 
+    #[instrument(name = "table.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteTableCommand) -> Result<Table> {
         let table = sqlx::query_as!(
             TableModel,
@@ -144,6 +186,15 @@ impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand,
                     slots,
                     status as "status: ETableStatus",
                     game_system_id,
+                    tags,
+                    language,
+                    format as "format: ETableFormat",
+                    beginner_friendly,
+                    age_rating as "age_rating: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility: ETableVisibility",
                     created_at,
                     updated_at
             "#,
@@ -156,6 +207,7 @@ impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand,
         Ok(table.into())
     }
 
+    #[instrument(name = "table.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Table>> {
         let table = sqlx::query_as!(
             TableModel,
@@ -168,10 +220,19 @@ impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand,
                     slots,
                     status as "status: ETableStatus",
                     game_system_id,
+                    tags,
+                    language,
+                    format as "format: ETableFormat",
+                    beginner_friendly,
+                    age_rating as "age_rating: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility: ETableVisibility",
                     created_at,
                     updated_at
                 FROM tables
-                WHERE id = $1
+                WHERE id = $1 AND deleted_at IS NULL
             "#,
             &id
         )
@@ -185,6 +246,29 @@ impl Repository<Table, CreateTableCommand, UpdateTableCommand, GetTableCommand,
 
 #[async_trait::async_trait]
 impl TableRepository for PostgresTableRepository {
+    #[instrument(name = "table.update_with_audit", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn update_with_audit(
+        &self,
+        command: UpdateTableCommand,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Table> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let table = update_table(&mut *tx, command).await?;
+        insert_audit_log(&mut *tx, audit.with_after(&table)).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(table)
+    }
+
+    #[instrument(name = "table.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<Table>> {
         let tables = sqlx::query_as!(
             TableModel,
@@ -197,10 +281,19 @@ impl TableRepository for PostgresTableRepository {
                     slots,
                     status as "status: ETableStatus",
                     game_system_id,
+                    tags,
+                    language,
+                    format as "format: ETableFormat",
+                    beginner_friendly,
+                    age_rating as "age_rating: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility: ETableVisibility",
                     created_at,
                     updated_at
                 FROM tables
-                WHERE id = $1
+                WHERE id = $1 AND deleted_at IS NULL
             "#,
             table_id
         )
@@ -211,6 +304,7 @@ impl TableRepository for PostgresTableRepository {
         Ok(tables.into_iter().map(|model| model.into()).collect())
     }
 
+    #[instrument(name = "table.find_by_user_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Table>> {
         let tables = sqlx::query_as!(
             TableModel,
@@ -223,10 +317,19 @@ impl TableRepository for PostgresTableRepository {
                     slots,
                     status as "status: ETableStatus",
                     game_system_id,
+                    tags,
+                    language,
+                    format as "format: ETableFormat",
+                    beginner_friendly,
+                    age_rating as "age_rating: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility: ETableVisibility",
                     created_at,
                     updated_at
                 FROM tables
-                WHERE gm_id = $1
+                WHERE gm_id = $1 AND deleted_at IS NULL
             "#,
             user_id
         )
@@ -237,6 +340,7 @@ impl TableRepository for PostgresTableRepository {
         Ok(tables.into_iter().map(|model| model.into()).collect())
     }
 
+    #[instrument(name = "table.find_by_session_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_session_id(&self, session_id: Uuid) -> Result<Option<Table>> {
         let table = sqlx::query_as!(
             TableModel,
@@ -249,12 +353,23 @@ impl TableRepository for PostgresTableRepository {
                     tables.slots,
                     tables.status as "status: ETableStatus",
                     tables.game_system_id,
+                    tables.tags,
+                    tables.language,
+                    tables.format as "format: ETableFormat",
+                    tables.beginner_friendly,
+                    tables.age_rating as "age_rating: EAgeRating",
+                    tables.venue_address,
+                    tables.latitude,
+                    tables.longitude,
+                    tables.visibility as "visibility: ETableVisibility",
                     tables.created_at,
                     tables.updated_at
                 FROM tables
                 INNER JOIN sessions
                 ON tables.id = sessions.table_id
                 WHERE sessions.id = $1
+                    AND sessions.deleted_at IS NULL
+                    AND tables.deleted_at IS NULL
             "#,
             session_id
         )
@@ -265,6 +380,7 @@ impl TableRepository for PostgresTableRepository {
         Ok(table.map(|model| model.into()))
     }
 
+    #[instrument(name = "table.find_details_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_details_by_id(&self, table_id: Uuid) -> Result<Option<TableDetails>> {
         let table_task = sqlx::query_as!(
             TableModel,
@@ -277,10 +393,19 @@ impl TableRepository for PostgresTableRepository {
                     tables.slots,
                     tables.status as "status: ETableStatus",
                     tables.game_system_id,
+                    tables.tags,
+                    tables.language,
+                    tables.format as "format: ETableFormat",
+                    tables.beginner_friendly,
+                    tables.age_rating as "age_rating: EAgeRating",
+                    tables.venue_address,
+                    tables.latitude,
+                    tables.longitude,
+                    tables.visibility as "visibility: ETableVisibility",
                     tables.created_at,
                     tables.updated_at
                 FROM tables
-                WHERE tables.id = $1
+                WHERE tables.id = $1 AND tables.deleted_at IS NULL
             "#,
             table_id
         )
@@ -293,13 +418,15 @@ impl TableRepository for PostgresTableRepository {
                     users.id,
                     users.email,
                     users.username,
-                    users.created_at,
                     users.password,
+                    users.role as "role: EUserRole",
+                    users.suspended_at,
+                    users.created_at,
                     users.updated_at
                 FROM users
                 INNER JOIN table_members
                 ON users.id = table_members.user_id
-                WHERE table_members.table_id = $1
+                WHERE table_members.table_id = $1 AND users.deleted_at IS NULL
             "#,
             table_id
         )
@@ -318,7 +445,7 @@ impl TableRepository for PostgresTableRepository {
                     sessions.created_at,
                     sessions.updated_at
                 FROM sessions
-                WHERE sessions.table_id = $1
+                WHERE sessions.table_id = $1 AND sessions.deleted_at IS NULL
             "#,
             table_id
         )
@@ -338,6 +465,15 @@ impl TableRepository for PostgresTableRepository {
                     sessions,
                     status: table.status,
                     game_system_id: table.game_system_id,
+                    tags: table.tags,
+                    language: table.language,
+                    format: table.format,
+                    beginner_friendly: table.beginner_friendly,
+                    age_rating: table.age_rating,
+                    venue_address: table.venue_address,
+                    latitude: table.latitude,
+                    longitude: table.longitude,
+                    visibility: table.visibility,
                     created_at: table.created_at,
                     updated_at: table.updated_at,
                 };
@@ -348,4 +484,348 @@ impl TableRepository for PostgresTableRepository {
             Err(err) => Err(map_database_error(err).into()),
         }
     }
+
+    #[instrument(name = "table.find_visible_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_visible_by_id(&self, table_id: Uuid, viewer_id: Uuid) -> Result<Option<Table>> {
+        let table = sqlx::query_as!(
+            TableModel,
+            r#"
+                SELECT
+                    id,
+                    gm_id,
+                    title,
+                    description,
+                    slots,
+                    status as "status: ETableStatus",
+                    game_system_id,
+                    tags,
+                    language,
+                    format as "format: ETableFormat",
+                    beginner_friendly,
+                    age_rating as "age_rating: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility: ETableVisibility",
+                    created_at,
+                    updated_at
+                FROM tables
+                WHERE id = $1
+                    AND deleted_at IS NULL
+                    AND (
+                        visibility <> 'private'
+                        OR gm_id = $2
+                        OR EXISTS (
+                            SELECT 1 FROM table_members
+                            WHERE table_members.table_id = tables.id
+                                AND table_members.user_id = $2
+                        )
+                    )
+            "#,
+            table_id,
+            viewer_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(table.map(TableModel::into))
+    }
+
+    #[instrument(name = "table.list", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn list(&self, command: GetTableCommand) -> Result<Vec<TableListing>> {
+        let listings = sqlx::query_as!(
+            TableListingModel,
+            r#"
+                SELECT
+                    id as "id!",
+                    gm_id as "gm_id!",
+                    title as "title!",
+                    description as "description!",
+                    slots as "slots!",
+                    status as "status!: ETableStatus",
+                    game_system_id as "game_system_id!",
+                    tags as "tags!",
+                    language,
+                    format as "format!: ETableFormat",
+                    beginner_friendly as "beginner_friendly!",
+                    age_rating as "age_rating!: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility!: ETableVisibility",
+                    created_at as "created_at!",
+                    updated_at as "updated_at!",
+                    open_slots as "open_slots!",
+                    distance_km
+                FROM table_listings($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                WHERE ($17::uuid IS NULL OR CASE WHEN $16 = 'asc' THEN id > $17 ELSE id < $17 END)
+                ORDER BY
+                    CASE WHEN $15 = 'distance' AND $16 = 'asc' THEN distance_km END ASC,
+                    CASE WHEN $15 = 'distance' AND $16 = 'desc' THEN distance_km END DESC,
+                    CASE WHEN $15 = 'title' AND $16 = 'asc' THEN title END ASC,
+                    CASE WHEN $15 = 'title' AND $16 = 'desc' THEN title END DESC,
+                    CASE WHEN $15 = 'open_slots' AND $16 = 'asc' THEN open_slots END ASC,
+                    CASE WHEN $15 = 'open_slots' AND $16 = 'desc' THEN open_slots END DESC,
+                    CASE WHEN $16 = 'asc' THEN id END ASC,
+                    id DESC
+                LIMIT $18
+                OFFSET $19
+            "#,
+            command.id,
+            command.gm_id,
+            command.status.map(ETableStatus::from) as Option<ETableStatus>,
+            command.game_system_id,
+            command.tags.as_deref(),
+            command.language,
+            command.format.map(ETableFormat::from) as Option<ETableFormat>,
+            command.beginner_friendly,
+            command.age_rating.map(EAgeRating::from) as Option<EAgeRating>,
+            command.min_open_slots.map(|slots| slots as i32),
+            command.near.map(|point| point.latitude),
+            command.near.map(|point| point.longitude),
+            command.radius_km,
+            command.visible_to,
+            command.sort.field.as_str(),
+            command.sort.order.as_str(),
+            command.page.as_ref().and_then(|page| page.cursor),
+            command.page.as_ref().map(PaginationParams::limit),
+            command.page.as_ref().map_or(0, PaginationParams::offset),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(listings.into_iter().map(TableListing::from).collect())
+    }
+
+    #[instrument(name = "table.count", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn count(&self, command: GetTableCommand) -> Result<i64> {
+        let total = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!"
+                FROM table_listings($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            "#,
+            command.id,
+            command.gm_id,
+            command.status.map(ETableStatus::from) as Option<ETableStatus>,
+            command.game_system_id,
+            command.tags.as_deref(),
+            command.language,
+            command.format.map(ETableFormat::from) as Option<ETableFormat>,
+            command.beginner_friendly,
+            command.age_rating.map(EAgeRating::from) as Option<EAgeRating>,
+            command.min_open_slots.map(|slots| slots as i32),
+            command.near.map(|point| point.latitude),
+            command.near.map(|point| point.longitude),
+            command.radius_km,
+            command.visible_to,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(total)
+    }
+
+    #[instrument(name = "table.soft_delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn soft_delete(&self, table_id: Uuid, audit: CreateAuditLogCommand) -> Result<Table> {
+        let deleted_at = Utc::now();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let table = sqlx::query_as!(
+            TableModel,
+            r#"
+                UPDATE tables
+                SET deleted_at = $2
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING
+                    id,
+                    gm_id,
+                    title,
+                    description,
+                    slots,
+                    status as "status: ETableStatus",
+                    game_system_id,
+                    tags,
+                    language,
+                    format as "format: ETableFormat",
+                    beginner_friendly,
+                    age_rating as "age_rating: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility: ETableVisibility",
+                    created_at,
+                    updated_at
+            "#,
+            table_id,
+            deleted_at
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                UPDATE sessions
+                SET deleted_at = $2
+                WHERE table_id = $1 AND deleted_at IS NULL
+            "#,
+            table_id,
+            deleted_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        insert_audit_log(&mut *tx, audit).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(table.into())
+    }
+
+    #[instrument(name = "table.find_deleted_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_deleted_by_id(
+        &self,
+        table_id: Uuid,
+        deleted_since: DateTime<Utc>,
+    ) -> Result<Option<Table>> {
+        let table = sqlx::query_as!(
+            TableModel,
+            r#"
+                SELECT
+                    id,
+                    gm_id,
+                    title,
+                    description,
+                    slots,
+                    status as "status: ETableStatus",
+                    game_system_id,
+                    tags,
+                    language,
+                    format as "format: ETableFormat",
+                    beginner_friendly,
+                    age_rating as "age_rating: EAgeRating",
+                    venue_address,
+                    latitude,
+                    longitude,
+                    visibility as "visibility: ETableVisibility",
+                    created_at,
+                    updated_at
+                FROM tables
+                WHERE id = $1 AND deleted_at >= $2
+            "#,
+            table_id,
+            deleted_since
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(table.map(|model| model.into()))
+    }
+
+    #[instrument(name = "table.restore", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn restore(
+        &self,
+        table_id: Uuid,
+        deleted_since: DateTime<Utc>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Option<Table>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        // A table hidden together with its GM's account comes back only when
+        // the account is restored.
+        let table = sqlx::query_as!(
+            TableModel,
+            r#"
+                WITH target AS (
+                    SELECT tables.id, tables.deleted_at
+                    FROM tables
+                    INNER JOIN users ON users.id = tables.gm_id
+                    WHERE tables.id = $1
+                        AND tables.deleted_at >= $2
+                        AND users.deleted_at IS NULL
+                    FOR UPDATE OF tables
+                ),
+                restored_sessions AS (
+                    UPDATE sessions
+                    SET deleted_at = NULL
+                    FROM target
+                    WHERE sessions.table_id = target.id
+                        AND sessions.deleted_at = target.deleted_at
+                )
+                UPDATE tables
+                SET
+                    deleted_at = NULL,
+                    updated_at = NOW()
+                FROM target
+                WHERE tables.id = target.id
+                RETURNING
+                    tables.id,
+                    tables.gm_id,
+                    tables.title,
+                    tables.description,
+                    tables.slots,
+                    tables.status as "status: ETableStatus",
+                    tables.game_system_id,
+                    tables.tags,
+                    tables.language,
+                    tables.format as "format: ETableFormat",
+                    tables.beginner_friendly,
+                    tables.age_rating as "age_rating: EAgeRating",
+                    tables.venue_address,
+                    tables.latitude,
+                    tables.longitude,
+                    tables.visibility as "visibility: ETableVisibility",
+                    tables.created_at,
+                    tables.updated_at
+            "#,
+            table_id,
+            deleted_since
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?
+        .map(Table::from);
+
+        if let Some(table) = &table {
+            insert_audit_log(&mut *tx, audit.with_after(table)).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(table)
+    }
+
+    #[instrument(name = "table.purge_deleted", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM tables
+                WHERE deleted_at < $1
+            "#,
+            deleted_before
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(result.rows_affected())
+    }
 }
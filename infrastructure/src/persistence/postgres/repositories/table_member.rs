@@ -1,9 +1,10 @@
 use crate::persistence::postgres::constraint_mapper;
 use crate::persistence::postgres::models::TableMemberModel;
-use domain::repositories::TableMemberRepository;
+use domain::repositories::{PaginationParams, TableMemberRepository};
 use domain::{entities::*, repositories::Repository};
 use shared::Result;
 use sqlx::PgPool;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -27,6 +28,7 @@ impl
         DeleteTableMemberCommand,
     > for PostgresTableMemberRepository
 {
+    #[instrument(name = "table_member.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, command: CreateTableMemberCommand) -> Result<TableMember> {
         let member = sqlx::query_as!(
             TableMemberModel,
@@ -47,19 +49,34 @@ impl
         Ok(member.into())
     }
 
+    #[instrument(name = "table_member.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetTableMemberCommand) -> Result<Vec<TableMember>> {
         let members = sqlx::query_as!(
             TableMemberModel,
             r#"
-                SELECT *
+                SELECT table_members.*
                 FROM table_members
-                WHERE ($1::uuid IS NULL OR id = $1)
-                    AND ($2::uuid IS NULL OR table_id = $2)
-                    AND ($3::uuid IS NULL OR user_id = $3)
+                INNER JOIN tables ON tables.id = table_members.table_id
+                INNER JOIN users ON users.id = table_members.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND ($1::uuid IS NULL OR table_members.id = $1)
+                    AND ($2::uuid IS NULL OR table_members.table_id = $2)
+                    AND ($3::uuid IS NULL OR table_members.user_id = $3)
+                    AND ($5::uuid IS NULL OR CASE WHEN $4 = 'asc' THEN table_members.id > $5 ELSE table_members.id < $5 END)
+                ORDER BY
+                    CASE WHEN $4 = 'asc' THEN table_members.id END ASC,
+                    table_members.id DESC
+                LIMIT $6
+                OFFSET $7
             "#,
             command.id,
             command.table_id,
-            command.user_id
+            command.user_id,
+            command.sort_order.as_str(),
+            command.page.as_ref().and_then(|page| page.cursor),
+            command.page.as_ref().map(PaginationParams::limit),
+            command.page.as_ref().map_or(0, PaginationParams::offset),
         )
         .fetch_all(&self.pool)
         .await
@@ -68,13 +85,18 @@ impl
         Ok(members.into_iter().map(|m| m.into()).collect())
     }
 
+    #[instrument(name = "table_member.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<TableMember>> {
         let member = sqlx::query_as!(
             TableMemberModel,
             r#"
-                SELECT *
+                SELECT table_members.*
                 FROM table_members
-                WHERE id = $1
+                INNER JOIN tables ON tables.id = table_members.table_id
+                INNER JOIN users ON users.id = table_members.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_members.id = $1
             "#,
             id
         )
@@ -85,6 +107,7 @@ impl
         Ok(member.map(|m| m.into()))
     }
 
+    #[instrument(name = "table_member.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, command: UpdateTableMemberCommand) -> Result<TableMember> {
         let member = sqlx::query_as!(
             TableMemberModel,
@@ -107,6 +130,7 @@ impl
         Ok(member.into())
     }
 
+    #[instrument(name = "table_member.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteTableMemberCommand) -> Result<TableMember> {
         let deleted = sqlx::query_as!(
             TableMemberModel,
@@ -127,13 +151,43 @@ impl
 
 #[async_trait::async_trait]
 impl TableMemberRepository for PostgresTableMemberRepository {
+    #[instrument(name = "table_member.count", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn count(&self, command: GetTableMemberCommand) -> Result<i64> {
+        let total = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!"
+                FROM table_members
+                INNER JOIN tables ON tables.id = table_members.table_id
+                INNER JOIN users ON users.id = table_members.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND ($1::uuid IS NULL OR table_members.id = $1)
+                    AND ($2::uuid IS NULL OR table_members.table_id = $2)
+                    AND ($3::uuid IS NULL OR table_members.user_id = $3)
+            "#,
+            command.id,
+            command.table_id,
+            command.user_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(total)
+    }
+
+    #[instrument(name = "table_member.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<TableMember>> {
         let members = sqlx::query_as!(
             TableMemberModel,
             r#"
-                SELECT *
+                SELECT table_members.*
                 FROM table_members
-                WHERE table_id = $1
+                INNER JOIN tables ON tables.id = table_members.table_id
+                INNER JOIN users ON users.id = table_members.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_members.table_id = $1
             "#,
             table_id
         )
@@ -144,6 +198,7 @@ impl TableMemberRepository for PostgresTableMemberRepository {
         Ok(members.into_iter().map(|m| m.into()).collect())
     }
 
+    #[instrument(name = "table_member.find_by_table_and_user", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_table_and_user(
         &self,
         table_id: Uuid,
@@ -152,9 +207,14 @@ impl TableMemberRepository for PostgresTableMemberRepository {
         let member = sqlx::query_as!(
             TableMemberModel,
             r#"
-                SELECT *
+                SELECT table_members.*
                 FROM table_members
-                WHERE table_id = $1 AND user_id = $2
+                INNER JOIN tables ON tables.id = table_members.table_id
+                INNER JOIN users ON users.id = table_members.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_members.table_id = $1
+                    AND table_members.user_id = $2
             "#,
             table_id,
             user_id
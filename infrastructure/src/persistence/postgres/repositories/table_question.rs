@@ -0,0 +1,108 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::TableQuestionModel;
+use crate::persistence::postgres::models::table_question::EQuestionKind;
+use domain::entities::*;
+use domain::repositories::TableQuestionRepository;
+use shared::Result;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresTableQuestionRepository {
+    pool: PgPool,
+}
+
+impl PostgresTableQuestionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl TableQuestionRepository for PostgresTableQuestionRepository {
+    #[instrument(name = "table_question.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<TableQuestion>> {
+        let questions = sqlx::query_as!(
+            TableQuestionModel,
+            r#"
+                SELECT
+                    id,
+                    table_id,
+                    position,
+                    prompt,
+                    kind as "kind: EQuestionKind",
+                    options,
+                    required,
+                    created_at
+                FROM table_questions
+                WHERE table_id = $1
+                ORDER BY position
+            "#,
+            table_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(questions.into_iter().map(TableQuestion::from).collect())
+    }
+
+    #[instrument(name = "table_question.replace", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn replace(&self, command: ReplaceTableQuestionsCommand) -> Result<Vec<TableQuestion>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            "DELETE FROM table_questions WHERE table_id = $1",
+            command.table_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let mut questions = Vec::with_capacity(command.questions.len());
+
+        for (position, question) in command.questions.into_iter().enumerate() {
+            let created = sqlx::query_as!(
+                TableQuestionModel,
+                r#"
+                    INSERT INTO table_questions
+                        (id, table_id, position, prompt, kind, options, required)
+                    VALUES
+                        ($1, $2, $3, $4, $5, $6, $7)
+                    RETURNING
+                        id,
+                        table_id,
+                        position,
+                        prompt,
+                        kind as "kind: EQuestionKind",
+                        options,
+                        required,
+                        created_at
+                "#,
+                Uuid::now_v7(),
+                command.table_id,
+                position as i32,
+                question.prompt,
+                EQuestionKind::from(question.kind) as EQuestionKind,
+                &question.options,
+                question.required,
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+            questions.push(created.into());
+        }
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(questions)
+    }
+}
@@ -1,10 +1,13 @@
 use crate::persistence::postgres::constraint_mapper;
 use crate::persistence::postgres::models::TableRequestModel;
 use crate::persistence::postgres::models::table_request::ETableRequestStatus;
+use crate::persistence::postgres::repositories::{insert_audit_log, insert_user_event};
 use domain::entities::*;
-use domain::repositories::{Repository, TableRequestRepository};
+use domain::repositories::{PaginationParams, Repository, TableRequestRepository};
 use shared::Result;
-use sqlx::PgPool;
+use sqlx::types::Json;
+use sqlx::{PgExecutor, PgPool};
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -18,6 +21,40 @@ impl PostgresTableRequestRepository {
     }
 }
 
+async fn update_table_request<'e, E: PgExecutor<'e>>(
+    executor: E,
+    command: UpdateTableRequestCommand,
+) -> Result<TableRequest> {
+    let response = sqlx::query_as!(
+        TableRequestModel,
+        r#"
+            UPDATE table_requests
+            SET
+                status = $2::request_status,
+                message = COALESCE($3, message),
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING
+                id,
+                user_id,
+                table_id,
+                message,
+                answers as "answers: Json<Vec<ApplicationAnswer>>",
+                status as "status: ETableRequestStatus",
+                created_at,
+                updated_at
+        "#,
+        command.id,
+        command.status.map(ETableRequestStatus::from) as Option<ETableRequestStatus>,
+        command.message
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(response.into())
+}
+
 #[async_trait::async_trait]
 impl
     Repository<
@@ -28,19 +65,21 @@ impl
         DeleteTableRequestCommand,
     > for PostgresTableRequestRepository
 {
+    #[instrument(name = "table_request.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, command: CreateTableRequestCommand) -> Result<TableRequest> {
         let result = sqlx::query_as!(
             TableRequestModel,
             r#"
                 INSERT INTO table_requests
-                    (id, user_id, table_id, message, status)
+                    (id, user_id, table_id, message, answers, status)
                 VALUES
-                    ($1, $2, $3, $4, $5)
+                    ($1, $2, $3, $4, $5, $6)
                 RETURNING
                     id,
                     user_id,
                     table_id,
                     message,
+                    answers as "answers: Json<Vec<ApplicationAnswer>>",
                     status as "status: ETableRequestStatus",
                     created_at,
                     updated_at
@@ -49,6 +88,7 @@ impl
             command.user_id,
             command.table_id,
             command.message,
+            Json(&command.answers) as _,
             ETableRequestStatus::from(command.status) as ETableRequestStatus,
         )
         .fetch_one(&self.pool)
@@ -58,36 +98,12 @@ impl
         Ok(result.into())
     }
 
+    #[instrument(name = "table_request.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, command: UpdateTableRequestCommand) -> Result<TableRequest> {
-        let response = sqlx::query_as!(
-            TableRequestModel,
-            r#"
-                UPDATE table_requests
-                SET
-                    status = $2::request_status,
-                    message = COALESCE($3, message),
-                    updated_at = NOW()
-                WHERE id = $1
-                RETURNING
-                    id,
-                    user_id,
-                    table_id,
-                    message,
-                    status as "status: ETableRequestStatus",
-                    created_at,
-                    updated_at
-            "#,
-            command.id,
-            command.status.map(ETableRequestStatus::from) as Option<ETableRequestStatus>,
-            command.message
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(constraint_mapper::map_database_error)?;
-
-        Ok(response.into())
+        update_table_request(&self.pool, command).await
     }
 
+    #[instrument(name = "table_request.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteTableRequestCommand) -> Result<TableRequest> {
         let table = sqlx::query_as!(
             TableRequestModel,
@@ -99,6 +115,7 @@ impl
                     user_id,
                     table_id,
                     message,
+                    answers as "answers: Json<Vec<ApplicationAnswer>>",
                     status as "status: ETableRequestStatus",
                     created_at,
                     updated_at
@@ -112,28 +129,47 @@ impl
         Ok(table.into())
     }
 
+    #[instrument(name = "table_request.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetTableRequestCommand) -> Result<Vec<TableRequest>> {
         let requests = sqlx::query_as!(
             TableRequestModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    table_id,
-                    message,
-                    status as "status: ETableRequestStatus",
-                    created_at,
-                    updated_at
+                    table_requests.id,
+                    table_requests.user_id,
+                    table_requests.table_id,
+                    table_requests.message,
+                    table_requests.answers as "answers: Json<Vec<ApplicationAnswer>>",
+                    table_requests.status as "status: ETableRequestStatus",
+                    table_requests.created_at,
+                    table_requests.updated_at
                 FROM table_requests
-                WHERE ($1::uuid IS NULL OR id = $1)
-                    AND ($2::uuid IS NULL OR user_id = $2)
-                    AND ($3::uuid IS NULL OR table_id = $3)
-                    AND ($4::request_status IS NULL OR status = $4)
+                INNER JOIN tables ON tables.id = table_requests.table_id
+                INNER JOIN users ON users.id = table_requests.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND ($1::uuid IS NULL OR table_requests.id = $1)
+                    AND ($2::uuid IS NULL OR table_requests.user_id = $2)
+                    AND ($3::uuid IS NULL OR table_requests.table_id = $3)
+                    AND ($4::request_status IS NULL OR table_requests.status = $4)
+                    AND ($7::uuid IS NULL OR CASE WHEN $6 = 'asc' THEN table_requests.id > $7 ELSE table_requests.id < $7 END)
+                ORDER BY
+                    CASE WHEN $5 = 'updated_at' AND $6 = 'asc' THEN table_requests.updated_at END ASC,
+                    CASE WHEN $5 = 'updated_at' AND $6 = 'desc' THEN table_requests.updated_at END DESC,
+                    CASE WHEN $6 = 'asc' THEN table_requests.id END ASC,
+                    table_requests.id DESC
+                LIMIT $8
+                OFFSET $9
             "#,
             command.id,
             command.user_id,
             command.table_id,
-            command.status.map(ETableRequestStatus::from) as Option<ETableRequestStatus>
+            command.status.map(ETableRequestStatus::from) as Option<ETableRequestStatus>,
+            command.sort.field.as_str(),
+            command.sort.order.as_str(),
+            command.page.as_ref().and_then(|page| page.cursor),
+            command.page.as_ref().map(PaginationParams::limit),
+            command.page.as_ref().map_or(0, PaginationParams::offset),
         )
         .fetch_all(&self.pool)
         .await
@@ -142,20 +178,26 @@ impl
         Ok(requests.into_iter().map(|m| m.into()).collect())
     }
 
+    #[instrument(name = "table_request.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<TableRequest>> {
         let request = sqlx::query_as!(
             TableRequestModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    table_id,
-                    message,
-                    status as "status: ETableRequestStatus",
-                    created_at,
-                    updated_at
+                    table_requests.id,
+                    table_requests.user_id,
+                    table_requests.table_id,
+                    table_requests.message,
+                    table_requests.answers as "answers: Json<Vec<ApplicationAnswer>>",
+                    table_requests.status as "status: ETableRequestStatus",
+                    table_requests.created_at,
+                    table_requests.updated_at
                 FROM table_requests
-                WHERE id = $1
+                INNER JOIN tables ON tables.id = table_requests.table_id
+                INNER JOIN users ON users.id = table_requests.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_requests.id = $1
             "#,
             id
         )
@@ -169,20 +211,124 @@ impl
 
 #[async_trait::async_trait]
 impl TableRequestRepository for PostgresTableRequestRepository {
+    #[instrument(name = "table_request.approve", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn approve(
+        &self,
+        request_id: Uuid,
+        member: CreateTableMemberCommand,
+        audit: CreateAuditLogCommand,
+    ) -> Result<TableRequest> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                INSERT INTO table_members
+                    (id, table_id, user_id)
+                VALUES ($1, $2, $3)
+            "#,
+            member.id,
+            member.table_id,
+            member.user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let command = UpdateTableRequestCommand {
+            id: request_id,
+            status: Some(TableRequestStatus::Approved),
+            message: None,
+        };
+        let request = update_table_request(&mut *tx, command).await?;
+        insert_audit_log(&mut *tx, audit.with_after(&request)).await?;
+
+        let event = CreateUserEventCommand::for_user(
+            request.user_id,
+            UserEventKind::RequestAccepted,
+            &request,
+        );
+        insert_user_event(&mut tx, event).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(request)
+    }
+
+    #[instrument(name = "table_request.update_with_audit", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn update_with_audit(
+        &self,
+        command: UpdateTableRequestCommand,
+        audit: CreateAuditLogCommand,
+    ) -> Result<TableRequest> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let request = update_table_request(&mut *tx, command).await?;
+        insert_audit_log(&mut *tx, audit.with_after(&request)).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(request)
+    }
+
+    #[instrument(name = "table_request.count", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn count(&self, command: GetTableRequestCommand) -> Result<i64> {
+        let total = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!"
+                FROM table_requests
+                INNER JOIN tables ON tables.id = table_requests.table_id
+                INNER JOIN users ON users.id = table_requests.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND ($1::uuid IS NULL OR table_requests.id = $1)
+                    AND ($2::uuid IS NULL OR table_requests.user_id = $2)
+                    AND ($3::uuid IS NULL OR table_requests.table_id = $3)
+                    AND ($4::request_status IS NULL OR table_requests.status = $4)
+            "#,
+            command.id,
+            command.user_id,
+            command.table_id,
+            command.status.map(ETableRequestStatus::from) as Option<ETableRequestStatus>
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(total)
+    }
+
+    #[instrument(name = "table_request.find_by_user_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<TableRequest>> {
         let requests = sqlx::query_as!(
             TableRequestModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    table_id,
-                    message,
-                    status as "status: ETableRequestStatus",
-                    created_at,
-                    updated_at
+                    table_requests.id,
+                    table_requests.user_id,
+                    table_requests.table_id,
+                    table_requests.message,
+                    table_requests.answers as "answers: Json<Vec<ApplicationAnswer>>",
+                    table_requests.status as "status: ETableRequestStatus",
+                    table_requests.created_at,
+                    table_requests.updated_at
                 FROM table_requests
-                WHERE user_id = $1
+                INNER JOIN tables ON tables.id = table_requests.table_id
+                INNER JOIN users ON users.id = table_requests.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_requests.user_id = $1
             "#,
             user_id
         )
@@ -193,20 +339,26 @@ impl TableRequestRepository for PostgresTableRequestRepository {
         Ok(requests.into_iter().map(|model| model.into()).collect())
     }
 
+    #[instrument(name = "table_request.find_by_table_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_table_id(&self, table_id: Uuid) -> Result<Vec<TableRequest>> {
         let requests = sqlx::query_as!(
             TableRequestModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    table_id,
-                    message,
-                    status as "status: ETableRequestStatus",
-                    created_at,
-                    updated_at
+                    table_requests.id,
+                    table_requests.user_id,
+                    table_requests.table_id,
+                    table_requests.message,
+                    table_requests.answers as "answers: Json<Vec<ApplicationAnswer>>",
+                    table_requests.status as "status: ETableRequestStatus",
+                    table_requests.created_at,
+                    table_requests.updated_at
                 FROM table_requests
-                WHERE table_id = $1
+                INNER JOIN tables ON tables.id = table_requests.table_id
+                INNER JOIN users ON users.id = table_requests.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_requests.table_id = $1
             "#,
             &table_id
         )
@@ -217,20 +369,26 @@ impl TableRequestRepository for PostgresTableRequestRepository {
         Ok(requests.into_iter().map(|model| model.into()).collect())
     }
 
+    #[instrument(name = "table_request.find_by_status", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_status(&self, status: TableRequestStatus) -> Result<Vec<TableRequest>> {
         let requests = sqlx::query_as!(
             TableRequestModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    table_id,
-                    message,
-                    status as "status: ETableRequestStatus",
-                    created_at,
-                    updated_at
+                    table_requests.id,
+                    table_requests.user_id,
+                    table_requests.table_id,
+                    table_requests.message,
+                    table_requests.answers as "answers: Json<Vec<ApplicationAnswer>>",
+                    table_requests.status as "status: ETableRequestStatus",
+                    table_requests.created_at,
+                    table_requests.updated_at
                 FROM table_requests
-                WHERE status = $1
+                INNER JOIN tables ON tables.id = table_requests.table_id
+                INNER JOIN users ON users.id = table_requests.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_requests.status = $1
             "#,
             ETableRequestStatus::from(status) as ETableRequestStatus
         )
@@ -241,6 +399,7 @@ impl TableRequestRepository for PostgresTableRequestRepository {
         Ok(requests.into_iter().map(|model| model.into()).collect())
     }
 
+    #[instrument(name = "table_request.find_by_user_and_table", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_user_and_table(
         &self,
         user_id: Uuid,
@@ -250,15 +409,21 @@ impl TableRequestRepository for PostgresTableRequestRepository {
             TableRequestModel,
             r#"
                 SELECT
-                    id,
-                    user_id,
-                    table_id,
-                    message,
-                    status as "status: ETableRequestStatus",
-                    created_at,
-                    updated_at
+                    table_requests.id,
+                    table_requests.user_id,
+                    table_requests.table_id,
+                    table_requests.message,
+                    table_requests.answers as "answers: Json<Vec<ApplicationAnswer>>",
+                    table_requests.status as "status: ETableRequestStatus",
+                    table_requests.created_at,
+                    table_requests.updated_at
                 FROM table_requests
-                WHERE user_id = $1 AND table_id = $2
+                INNER JOIN tables ON tables.id = table_requests.table_id
+                INNER JOIN users ON users.id = table_requests.user_id
+                WHERE tables.deleted_at IS NULL
+                    AND users.deleted_at IS NULL
+                    AND table_requests.user_id = $1
+                    AND table_requests.table_id = $2
             "#,
             user_id,
             table_id
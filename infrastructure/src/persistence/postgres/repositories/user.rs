@@ -1,9 +1,12 @@
 use crate::persistence::postgres::constraint_mapper;
-use crate::persistence::postgres::models::UserModel;
+use crate::persistence::postgres::models::{EUserRole, UserModel};
+use crate::persistence::postgres::repositories::insert_audit_log;
+use chrono::{DateTime, Utc};
 use domain::entities::*;
-use domain::repositories::{Repository, UserRepository};
+use domain::repositories::{PaginationParams, Repository, UserRepository};
 use shared::Result;
 use sqlx::PgPool;
+use tracing::instrument;
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -21,6 +24,7 @@ impl PostgresUserRepository {
 impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, DeleteUserCommand>
     for PostgresUserRepository
 {
+    #[instrument(name = "user.create", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn create(&self, user: CreateUserCommand) -> Result<User> {
         let created_user = sqlx::query_as!(
             UserModel,
@@ -30,7 +34,14 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
                 VALUES
                     ($1, $2, $3, $4)
                 RETURNING
-                    *
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
             "#,
             user.id,
             user.username,
@@ -44,6 +55,7 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
         Ok(created_user.into())
     }
 
+    #[instrument(name = "user.update", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn update(&self, data: UpdateUserCommand) -> Result<User> {
         let updated_user = sqlx::query_as!(
             UserModel,
@@ -53,14 +65,24 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
                     username = COALESCE($2, username),
                     email = COALESCE($3, email),
                     password = COALESCE($4, password),
+                    role = COALESCE($5, role),
                     updated_at = NOW()
-                WHERE id = $1
-                RETURNING *
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
             "#,
             data.user_id,
             data.username,
             data.email,
-            data.password
+            data.password,
+            data.role.map(EUserRole::from) as Option<EUserRole>,
         )
         .fetch_one(&self.pool)
         .await
@@ -69,20 +91,40 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
         Ok(updated_user.into())
     }
 
+    #[instrument(name = "user.read", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn read(&self, command: GetUserCommand) -> Result<Vec<User>> {
         let users = sqlx::query_as!(
             UserModel,
             r#"
                 SELECT
-                    *
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
                 FROM users
                 WHERE ($1::uuid IS NULL OR id = $1)
                     AND ($2::text IS NULL OR username = $2)
                     AND ($3::text IS NULL OR email = $3)
+                    AND ($4::user_role IS NULL OR role = $4)
+                    AND ($5::boolean IS NULL OR (suspended_at IS NOT NULL) = $5)
+                    AND ($6::uuid IS NULL OR id < $6)
+                    AND deleted_at IS NULL
+                ORDER BY id DESC
+                LIMIT $7
+                OFFSET $8
             "#,
             command.id,
             command.username,
-            command.email
+            command.email,
+            command.role.map(EUserRole::from) as Option<EUserRole>,
+            command.suspended,
+            command.page.as_ref().and_then(|page| page.cursor),
+            command.page.as_ref().map(PaginationParams::limit),
+            command.page.as_ref().map_or(0, PaginationParams::offset),
         )
         .fetch_all(&self.pool)
         .await
@@ -91,14 +133,22 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
         Ok(users.into_iter().map(|model| model.into()).collect())
     }
 
+    #[instrument(name = "user.find_by_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>> {
         let user = sqlx::query_as!(
             UserModel,
             r#"
                 SELECT
-                    *
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
                 FROM users
-                WHERE id = $1
+                WHERE id = $1 AND deleted_at IS NULL
             "#,
             &id
         )
@@ -109,6 +159,7 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
         Ok(user.map(|model| model.into()))
     }
 
+    #[instrument(name = "user.delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn delete(&self, command: DeleteUserCommand) -> Result<User> {
         let user = sqlx::query_as!(
             UserModel,
@@ -116,7 +167,14 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
                 DELETE FROM users
                 WHERE id = $1
                 RETURNING
-                    *
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
             "#,
             &command.id
         )
@@ -130,14 +188,22 @@ impl Repository<User, CreateUserCommand, UpdateUserCommand, GetUserCommand, Dele
 
 #[async_trait::async_trait]
 impl UserRepository for PostgresUserRepository {
+    #[instrument(name = "user.find_by_email", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
     async fn find_by_email(&self, email: &str) -> Result<Option<User>> {
         let user = sqlx::query_as!(
             UserModel,
             r#"
                 SELECT
-                    *
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
                 FROM users
-                WHERE email = $1
+                WHERE email = $1 AND deleted_at IS NULL
             "#,
             email
         )
@@ -147,4 +213,361 @@ impl UserRepository for PostgresUserRepository {
 
         Ok(user.map(|model| model.into()))
     }
+
+    #[instrument(name = "user.count", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn count(&self, command: GetUserCommand) -> Result<i64> {
+        let total = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!"
+                FROM users
+                WHERE ($1::uuid IS NULL OR id = $1)
+                    AND ($2::text IS NULL OR username = $2)
+                    AND ($3::text IS NULL OR email = $3)
+                    AND ($4::user_role IS NULL OR role = $4)
+                    AND ($5::boolean IS NULL OR (suspended_at IS NOT NULL) = $5)
+                    AND deleted_at IS NULL
+            "#,
+            command.id,
+            command.username,
+            command.email,
+            command.role.map(EUserRole::from) as Option<EUserRole>,
+            command.suspended,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(total)
+    }
+
+    #[instrument(name = "user.set_suspended_at", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn set_suspended_at(
+        &self,
+        user_id: Uuid,
+        suspended_at: Option<DateTime<Utc>>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<User> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let user = sqlx::query_as!(
+            UserModel,
+            r#"
+                UPDATE users
+                SET
+                    suspended_at = $2,
+                    updated_at = NOW()
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
+            "#,
+            user_id,
+            suspended_at
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        if suspended_at.is_some() {
+            sqlx::query!(
+                r#"
+                    DELETE FROM refresh_tokens
+                    WHERE user_id = $1
+                "#,
+                user_id
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+        }
+
+        insert_audit_log(&mut *tx, audit).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(user.into())
+    }
+
+    #[instrument(name = "user.set_role", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn set_role(
+        &self,
+        user_id: Uuid,
+        role: UserRole,
+        audit: CreateAuditLogCommand,
+    ) -> Result<User> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let user = sqlx::query_as!(
+            UserModel,
+            r#"
+                UPDATE users
+                SET
+                    role = $2,
+                    updated_at = NOW()
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
+            "#,
+            user_id,
+            EUserRole::from(role) as EUserRole
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        insert_audit_log(&mut *tx, audit).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(user.into())
+    }
+
+    #[instrument(name = "user.soft_delete", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn soft_delete(&self, user_id: Uuid, audit: CreateAuditLogCommand) -> Result<User> {
+        let deleted_at = Utc::now();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let user = sqlx::query_as!(
+            UserModel,
+            r#"
+                UPDATE users
+                SET
+                    deleted_at = $2,
+                    updated_at = NOW()
+                WHERE id = $1 AND deleted_at IS NULL
+                RETURNING
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
+            "#,
+            user_id,
+            deleted_at
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                UPDATE sessions
+                SET deleted_at = $2
+                WHERE deleted_at IS NULL
+                    AND table_id IN (
+                        SELECT id FROM tables WHERE gm_id = $1 AND deleted_at IS NULL
+                    )
+            "#,
+            user_id,
+            deleted_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                UPDATE tables
+                SET deleted_at = $2
+                WHERE gm_id = $1 AND deleted_at IS NULL
+            "#,
+            user_id,
+            deleted_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM refresh_tokens
+                WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        insert_audit_log(&mut *tx, audit).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(user.into())
+    }
+
+    #[instrument(name = "user.find_deleted_by_email", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_deleted_by_email(
+        &self,
+        email: &str,
+        deleted_since: DateTime<Utc>,
+    ) -> Result<Option<User>> {
+        let user = sqlx::query_as!(
+            UserModel,
+            r#"
+                SELECT
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
+                FROM users
+                WHERE email = $1 AND deleted_at >= $2
+            "#,
+            email,
+            deleted_since
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(user.map(|model| model.into()))
+    }
+
+    #[instrument(name = "user.restore", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn restore(
+        &self,
+        user_id: Uuid,
+        deleted_since: DateTime<Utc>,
+        audit: CreateAuditLogCommand,
+    ) -> Result<Option<User>> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        let deleted_at = sqlx::query_scalar!(
+            r#"
+                SELECT deleted_at as "deleted_at!"
+                FROM users
+                WHERE id = $1 AND deleted_at >= $2
+                FOR UPDATE
+            "#,
+            user_id,
+            deleted_since
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let Some(deleted_at) = deleted_at else {
+            return Ok(None);
+        };
+
+        sqlx::query!(
+            r#"
+                UPDATE sessions
+                SET deleted_at = NULL
+                WHERE deleted_at = $2
+                    AND table_id IN (
+                        SELECT id FROM tables WHERE gm_id = $1 AND deleted_at = $2
+                    )
+            "#,
+            user_id,
+            deleted_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        sqlx::query!(
+            r#"
+                UPDATE tables
+                SET deleted_at = NULL
+                WHERE gm_id = $1 AND deleted_at = $2
+            "#,
+            user_id,
+            deleted_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        let user = sqlx::query_as!(
+            UserModel,
+            r#"
+                UPDATE users
+                SET
+                    deleted_at = NULL,
+                    updated_at = NOW()
+                WHERE id = $1
+                RETURNING
+                    id,
+                    username,
+                    email,
+                    password,
+                    role as "role: EUserRole",
+                    suspended_at,
+                    created_at,
+                    updated_at
+            "#,
+            user_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        insert_audit_log(&mut *tx, audit).await?;
+
+        tx.commit()
+            .await
+            .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(Some(user.into()))
+    }
+
+    #[instrument(name = "user.purge_deleted", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn purge_deleted(&self, deleted_before: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM users
+                WHERE deleted_at < $1
+            "#,
+            deleted_before
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(result.rows_affected())
+    }
 }
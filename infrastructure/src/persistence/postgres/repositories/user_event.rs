@@ -0,0 +1,135 @@
+use crate::persistence::postgres::constraint_mapper;
+use crate::persistence::postgres::models::{EUserEventKind, UserEventModel};
+use chrono::{DateTime, Utc};
+use domain::entities::*;
+use domain::repositories::UserEventRepository;
+use shared::Result;
+use sqlx::{PgConnection, PgPool};
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct PostgresUserEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Records an event inside the caller's transaction, so it is only seen if
+/// the change it describes commits.
+///
+/// Streams resume after the last id a user saw, so each user's ids must
+/// become visible in order. Writers hold a per-recipient transaction lock
+/// from allocating ids until commit; otherwise a later id could commit first
+/// and the earlier one be skipped.
+pub(crate) async fn insert_user_event(
+    conn: &mut PgConnection,
+    command: CreateUserEventCommand,
+) -> Result<()> {
+    let recipients = match command.recipients {
+        UserEventRecipients::User(user_id) => vec![user_id],
+        UserEventRecipients::Table(table_id) => sqlx::query_scalar!(
+            r#"
+                SELECT gm_id AS "user_id!" FROM tables WHERE id = $1
+                UNION
+                SELECT user_id FROM table_members WHERE table_id = $1
+            "#,
+            table_id,
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(constraint_mapper::map_database_error)?,
+    };
+
+    // Taken in a fixed order so writers sharing recipients cannot deadlock.
+    sqlx::query_scalar!(
+        r#"
+            SELECT COUNT(*) AS "locked!"
+            FROM (
+                SELECT pg_advisory_xact_lock(hashtext(recipient::text))
+                FROM UNNEST($1::uuid[]) AS recipient
+                ORDER BY hashtext(recipient::text)
+            ) locks
+        "#,
+        &recipients,
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    sqlx::query!(
+        r#"
+            INSERT INTO user_events (user_id, kind, payload)
+            SELECT recipient, $2, $3
+            FROM UNNEST($1::uuid[]) AS recipient
+        "#,
+        &recipients,
+        EUserEventKind::from(command.kind) as EUserEventKind,
+        command.payload,
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(constraint_mapper::map_database_error)?;
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl UserEventRepository for PostgresUserEventRepository {
+    #[instrument(name = "user_event.find_after", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_after(&self, user_id: Uuid, after: i64, limit: u32) -> Result<Vec<UserEvent>> {
+        let events = sqlx::query_as!(
+            UserEventModel,
+            r#"
+                SELECT
+                    id,
+                    user_id,
+                    kind as "kind: EUserEventKind",
+                    payload,
+                    created_at
+                FROM user_events
+                WHERE user_id = $1 AND id > $2
+                ORDER BY id
+                LIMIT $3
+            "#,
+            user_id,
+            after,
+            limit as i64,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(events.into_iter().map(UserEvent::from).collect())
+    }
+
+    #[instrument(name = "user_event.find_latest_id", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn find_latest_id(&self, user_id: Uuid) -> Result<Option<i64>> {
+        let latest = sqlx::query_scalar!(
+            "SELECT MAX(id) FROM user_events WHERE user_id = $1",
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(latest)
+    }
+
+    #[instrument(name = "user_event.purge_older_than", level = "debug", skip_all, fields(db.system.name = "postgresql"))]
+    async fn purge_older_than(&self, created_before: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query!(
+            "DELETE FROM user_events WHERE created_at < $1",
+            created_before
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(constraint_mapper::map_database_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,20 +1,26 @@
+use crate::config::DatabasePoolConfig;
 use crate::persistence::Db;
 use crate::persistence::postgres::create_postgres_pool;
 use shared::Result;
 use shared::error::Error;
 use shared::error::SetupError;
+use sqlx::migrate::Migrator;
 use tracing::*;
 
-pub async fn setup_database(database_url: &str) -> Result<Db> {
-    let database = connect_to_database(database_url).await?;
+/// Migrations embedded at build time, also used by the readiness probe to
+/// detect a database that is behind the binary.
+pub(crate) static MIGRATOR: Migrator = sqlx::migrate!("../migrations");
+
+pub async fn setup_database(database_url: &str, pool: &DatabasePoolConfig) -> Result<Db> {
+    let database = connect_to_database(database_url, pool).await?;
     run_postgres_migrations(&database).await?;
     health_check_database(&database).await?;
     Ok(database)
 }
 
-async fn connect_to_database(database_url: &str) -> Result<Db> {
+async fn connect_to_database(database_url: &str, pool: &DatabasePoolConfig) -> Result<Db> {
     info!("🔌 Establishing database connection...");
-    let pool = create_postgres_pool(database_url).await?;
+    let pool = create_postgres_pool(database_url, pool).await?;
     info!("✅ Database connection established");
 
     Ok(pool)
@@ -37,7 +43,7 @@ async fn health_check_database(database: &Db) -> Result<()> {
 async fn run_postgres_migrations(database: &Db) -> Result<()> {
     info!("🔄 Running database migrations...");
 
-    sqlx::migrate!("../migrations")
+    MIGRATOR
         .run(database)
         .await
         .map_err(|err| Error::Setup(SetupError::FailedToRunDBMigrations(err.to_string())))?;
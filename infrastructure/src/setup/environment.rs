@@ -1,6 +1,22 @@
+use std::str::FromStr;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Environment {
     #[default]
     Development,
     Production,
 }
+
+impl FromStr for Environment {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case("development") {
+            Ok(Environment::Development)
+        } else if value.eq_ignore_ascii_case("production") {
+            Ok(Environment::Production)
+        } else {
+            Err(())
+        }
+    }
+}
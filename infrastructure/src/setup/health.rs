@@ -0,0 +1,224 @@
+use crate::mail::SmtpMailer;
+use crate::persistence::Db;
+use crate::setup::database::MIGRATOR;
+use crate::setup::shutdown::Shutdown;
+use domain::storage::ObjectStorage;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// Key read from object storage to prove it is reachable. It never exists,
+/// so a healthy backend answers "not found".
+const STORAGE_PROBE_KEY: &str = "health/readiness-probe";
+
+/// Opening an SMTP session per probe would hammer the relay, so its result
+/// is reused for this long.
+const MAIL_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Failure details are only logged; the readiness endpoint is public and
+/// they can name hosts, users or buckets.
+type ProbeResult = std::result::Result<(), String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    pub status: ComponentStatus,
+    pub duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessStatus {
+    Ready,
+    NotReady,
+    /// Set as soon as shutdown begins so load balancers stop routing here
+    /// while in-flight requests drain.
+    ShuttingDown,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessReport {
+    pub status: ReadinessStatus,
+    pub checks: BTreeMap<&'static str, ComponentHealth>,
+}
+
+impl ReadinessReport {
+    pub fn is_ready(&self) -> bool {
+        self.status == ReadinessStatus::Ready
+    }
+}
+
+/// Checks whether the process can serve traffic: the database answers, its
+/// schema is up to date and the configured storage and mail backends are
+/// reachable. Every check runs concurrently under the same timeout.
+#[derive(Clone)]
+pub struct HealthChecker {
+    database: Db,
+    storage: Arc<dyn ObjectStorage>,
+    /// Only set when mail goes out over SMTP rather than to the log.
+    smtp: Option<SmtpMailer>,
+    /// The last mail check and when it ran, see [`MAIL_CHECK_INTERVAL`].
+    last_mail_check: Arc<Mutex<Option<(Instant, ComponentHealth)>>>,
+    shutdown: Shutdown,
+    timeout: Duration,
+}
+
+impl HealthChecker {
+    pub fn new(
+        database: Db,
+        storage: Arc<dyn ObjectStorage>,
+        smtp: Option<SmtpMailer>,
+        shutdown: Shutdown,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            database,
+            storage,
+            smtp,
+            last_mail_check: Arc::new(Mutex::new(None)),
+            shutdown,
+            timeout,
+        }
+    }
+
+    pub async fn readiness(&self) -> ReadinessReport {
+        if self.shutdown.is_triggered() {
+            return ReadinessReport {
+                status: ReadinessStatus::ShuttingDown,
+                checks: BTreeMap::new(),
+            };
+        }
+
+        let (database, migrations, storage, mail) = tokio::join!(
+            self.check("database", self.check_database()),
+            self.check("migrations", self.check_migrations()),
+            self.check("storage", self.check_storage()),
+            self.check_mail(),
+        );
+
+        let mut checks = BTreeMap::from([
+            ("database", database),
+            ("migrations", migrations),
+            ("storage", storage),
+        ]);
+        if let Some(mail) = mail {
+            checks.insert("mail", mail);
+        }
+
+        let status = if checks
+            .values()
+            .all(|check| check.status == ComponentStatus::Up)
+        {
+            ReadinessStatus::Ready
+        } else {
+            ReadinessStatus::NotReady
+        };
+
+        ReadinessReport { status, checks }
+    }
+
+    async fn check(
+        &self,
+        component: &'static str,
+        probe: impl Future<Output = ProbeResult>,
+    ) -> ComponentHealth {
+        let started = Instant::now();
+        let error = match tokio::time::timeout(self.timeout, probe).await {
+            Ok(Ok(())) => None,
+            Ok(Err(err)) => {
+                warn!("⚠ Readiness check of {} failed: {}", component, err);
+                Some("unavailable")
+            }
+            Err(_) => {
+                warn!(
+                    "⚠ Readiness check of {} timed out after {}ms",
+                    component,
+                    self.timeout.as_millis()
+                );
+                Some("timed out")
+            }
+        };
+
+        ComponentHealth {
+            status: if error.is_none() {
+                ComponentStatus::Up
+            } else {
+                ComponentStatus::Down
+            },
+            duration_ms: started.elapsed().as_millis() as u64,
+            error,
+        }
+    }
+
+    async fn check_database(&self) -> ProbeResult {
+        sqlx::query("SELECT 1")
+            .execute(&self.database)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(())
+    }
+
+    async fn check_migrations(&self) -> ProbeResult {
+        let applied: HashSet<i64> =
+            sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success")
+                .fetch_all(&self.database)
+                .await
+                .map_err(|err| err.to_string())?
+                .into_iter()
+                .collect();
+
+        let pending = MIGRATOR
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .filter(|migration| !applied.contains(&migration.version))
+            .count();
+
+        if pending > 0 {
+            return Err(format!("{pending} pending migration(s)"));
+        }
+
+        Ok(())
+    }
+
+    async fn check_storage(&self) -> ProbeResult {
+        self.storage
+            .get(STORAGE_PROBE_KEY)
+            .await
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    async fn check_mail(&self) -> Option<ComponentHealth> {
+        let smtp = self.smtp.as_ref()?;
+
+        // Held across the probe so concurrent requests wait for its result.
+        let mut last_check = self.last_mail_check.lock().await;
+        if let Some((checked_at, health)) = last_check.as_ref()
+            && checked_at.elapsed() < MAIL_CHECK_INTERVAL
+        {
+            return Some(health.clone());
+        }
+
+        let health = self
+            .check("mail", async {
+                smtp.test_connection().await.map_err(|err| err.to_string())
+            })
+            .await;
+        *last_check = Some((Instant::now(), health.clone()));
+
+        Some(health)
+    }
+}
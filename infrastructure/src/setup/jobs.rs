@@ -0,0 +1,94 @@
+use crate::setup::shutdown::Shutdown;
+use crate::state::AppState;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{error, info};
+
+/// Exports queued on another instance are noticed within this interval.
+const EXPORT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Periodically removes rows whose restore window has expired together with
+/// their uploaded files, as well as expired sign-in links and old events. On
+/// shutdown a purge in progress runs until the drain timeout; whatever it did
+/// not reach is left for the next run.
+pub fn spawn_purge_job(app_state: &AppState, shutdown: &Shutdown) -> JoinHandle<()> {
+    let purge_service = app_state.purge_service.clone();
+    let period: Duration = app_state.config.purge_interval;
+
+    info!(
+        "🧹 Purge job scheduled every {} minutes",
+        period.as_secs() / 60
+    );
+
+    let stopped = shutdown.clone();
+
+    shutdown.spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = stopped.triggered() => break,
+                _ = ticker.tick() => {}
+            }
+
+            match purge_service.purge_expired().await {
+                Ok(report) if report.total() > 0 => info!(
+                    "🧹 Purged {} images, {} attachments, {} sessions, {} tables and {} users past their grace period, {} expired sign-in links, {} old events",
+                    report.images,
+                    report.attachments,
+                    report.sessions,
+                    report.tables,
+                    report.users,
+                    report.magic_links,
+                    report.user_events
+                ),
+                Ok(_) => {}
+                Err(err) => error!("❌ Purge job failed: {}", err),
+            }
+        }
+
+        info!("🧹 Purge job stopped");
+    })
+}
+
+/// Builds queued account exports one at a time. The queue lives in the
+/// database, so exports left pending by a restart are picked up again. On
+/// shutdown an export in progress is abandoned and its claim released, so
+/// the next start resumes it.
+pub fn spawn_export_worker(app_state: &AppState, shutdown: &Shutdown) -> JoinHandle<()> {
+    let export_service = app_state.account_export_service.clone();
+    let stopped = shutdown.clone();
+
+    shutdown.spawn(async move {
+        let mut ticker = interval(EXPORT_POLL_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = stopped.triggered() => break,
+                _ = ticker.tick() => {}
+                _ = export_service.work_queued() => {}
+            }
+
+            while !stopped.is_triggered() {
+                // An interrupted export is released for the next start
+                // rather than holding up the shutdown drain.
+                match export_service.run_next(stopped.triggered()).await {
+                    Ok(Some(export)) => info!(
+                        "📦 Account export {} finished as {:?}",
+                        export.id, export.status
+                    ),
+                    Ok(None) => break,
+                    Err(err) => {
+                        error!("❌ Export worker failed: {}", err);
+                        break;
+                    }
+                }
+            }
+        }
+
+        info!("📦 Export worker stopped");
+    })
+}
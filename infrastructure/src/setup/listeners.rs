@@ -0,0 +1,109 @@
+use crate::chat::CHAT_CHANNEL;
+use crate::persistence::Db;
+use crate::setup::shutdown::Shutdown;
+use application::chat_service::ChatService;
+use application::user_event_service::UserEventService;
+use domain::chat::ChatEvent;
+use sqlx::postgres::PgListener;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Channel notified by the `user_events` insert trigger with the recipient's id.
+pub const USER_EVENT_CHANNEL: &str = "user_events";
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Hands chat events from all instances to this instance's connections.
+pub fn spawn_chat_listener(
+    database: &Db,
+    chat_service: &ChatService,
+    shutdown: &Shutdown,
+) -> JoinHandle<()> {
+    let chat_service = chat_service.clone();
+
+    spawn_listener(
+        database,
+        shutdown,
+        CHAT_CHANNEL,
+        move |payload| match serde_json::from_str::<ChatEvent>(payload) {
+            Ok(event) => chat_service.deliver(event),
+            Err(err) => warn!("Ignoring malformed chat event: {}", err),
+        },
+    )
+}
+
+/// Wakes up this instance's event streams of users with new events.
+pub fn spawn_user_event_listener(
+    database: &Db,
+    user_event_service: &UserEventService,
+    shutdown: &Shutdown,
+) -> JoinHandle<()> {
+    let user_event_service = user_event_service.clone();
+
+    spawn_listener(
+        database,
+        shutdown,
+        USER_EVENT_CHANNEL,
+        move |payload| match payload.parse::<Uuid>() {
+            Ok(user_id) => user_event_service.deliver(user_id),
+            Err(err) => warn!("Ignoring malformed user event notification: {}", err),
+        },
+    )
+}
+
+/// Runs `handler` for every notification on `channel`, reconnecting when
+/// the connection drops. Notifications sent while reconnecting are lost, so
+/// consumers must be able to catch up from the database. Stops on shutdown,
+/// releasing its connection before the pool is closed.
+fn spawn_listener<F>(
+    database: &Db,
+    shutdown: &Shutdown,
+    channel: &'static str,
+    handler: F,
+) -> JoinHandle<()>
+where
+    F: Fn(&str) + Send + 'static,
+{
+    let database = database.clone();
+    let stopped = shutdown.clone();
+
+    shutdown.spawn(async move {
+        'connect: while !stopped.is_triggered() {
+            let mut listener = match PgListener::connect_with(&database).await {
+                Ok(listener) => listener,
+                Err(err) => {
+                    error!("❌ Listener for '{}' failed to connect: {}", channel, err);
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                    continue;
+                }
+            };
+
+            if let Err(err) = listener.listen(channel).await {
+                error!("❌ Listener failed to subscribe to '{}': {}", channel, err);
+                tokio::time::sleep(RECONNECT_DELAY).await;
+                continue;
+            }
+
+            info!("📡 Listening for notifications on '{}'", channel);
+
+            loop {
+                let received = tokio::select! {
+                    _ = stopped.triggered() => break 'connect,
+                    received = listener.recv() => received,
+                };
+
+                match received {
+                    Ok(notification) => handler(notification.payload()),
+                    Err(err) => {
+                        error!("❌ Listener for '{}' lost its connection: {}", channel, err);
+                        tokio::time::sleep(RECONNECT_DELAY).await;
+                        break;
+                    }
+                }
+            }
+        }
+
+        info!("📡 Stopped listening on '{}'", channel);
+    })
+}
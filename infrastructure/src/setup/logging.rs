@@ -1,12 +1,97 @@
-use tracing::Level;
-use tracing_subscriber::FmtSubscriber;
-use tracing_subscriber::fmt::format::FmtSpan;
-
-pub fn init_logging() {
-    FmtSubscriber::builder()
-        .with_max_level(Level::INFO)
-        .with_span_events(FmtSpan::NEW | FmtSpan::CLOSE)
-        .with_target(true)
-        .with_test_writer()
-        .init();
+use crate::setup::environment::Environment;
+use crate::setup::telemetry::{TelemetryConfig, TelemetryGuard, init_tracer};
+use std::str::FromStr;
+use tracing::level_filters::LevelFilter;
+use tracing::{info, warn};
+use tracing_log::{AsLog, LogTracer};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry, fmt};
+
+const DEFAULT_LOG_FILTER: &str = "info";
+
+/// How log lines are written to stdout. JSON is meant for log collectors in
+/// production; pretty text for local development.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    #[default]
+    Pretty,
+    Json,
+}
+
+impl LogFormat {
+    /// JSON in production, pretty text otherwise.
+    pub fn for_environment(environment: Environment) -> Self {
+        match environment {
+            Environment::Production => LogFormat::Json,
+            Environment::Development => LogFormat::Pretty,
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.eq_ignore_ascii_case("json") {
+            Ok(LogFormat::Json)
+        } else if value.eq_ignore_ascii_case("pretty") {
+            Ok(LogFormat::Pretty)
+        } else {
+            Err(())
+        }
+    }
+}
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+/// Installs the global subscriber. Log lines honour `RUST_LOG` directives and
+/// fall back to `info`; when telemetry is enabled, spans selected by its own
+/// filter are also exported over OTLP.
+pub fn init_logging(format: LogFormat, telemetry: &TelemetryConfig) -> TelemetryGuard {
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_LOG_FILTER));
+    let log_level = filter.max_level_hint().unwrap_or(LevelFilter::TRACE);
+
+    let fmt_layer: BoxedLayer = match format {
+        LogFormat::Json => fmt::layer()
+            .with_target(true)
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .with_filter(filter)
+            .boxed(),
+        LogFormat::Pretty => fmt::layer().with_target(true).with_filter(filter).boxed(),
+    };
+
+    let (provider, otel_layer, setup_error) =
+        match telemetry.enabled.then(|| init_tracer(telemetry)) {
+            Some(Ok((provider, tracer))) => {
+                let layer = tracing_opentelemetry::layer()
+                    .with_tracer(tracer)
+                    .with_filter(telemetry.traces_filter());
+                (Some(provider), Some(layer), None)
+            }
+            Some(Err(err)) => (None, None, Some(err)),
+            None => (None, None, None),
+        };
+
+    let subscriber = tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(otel_layer);
+    tracing::subscriber::set_global_default(subscriber).expect("failed to install subscriber");
+
+    // Records from `log` users (the application layer, sqlx) are only wanted
+    // in the logs. Capping the bridge at the log level also keeps their
+    // `log_enabled!` checks from reaching the per-layer filters, which
+    // otherwise drop the next events on that thread.
+    LogTracer::init_with_filter(log_level.as_log()).expect("failed to install log bridge");
+
+    if let Some(err) = setup_error {
+        warn!("⚠ Trace export disabled: {err}");
+    } else if telemetry.enabled {
+        info!("📡 Exporting traces to {}", telemetry.otlp_endpoint);
+    }
+
+    TelemetryGuard::new(provider)
 }
@@ -0,0 +1,89 @@
+use crate::persistence::Db;
+use metrics::{Unit, describe_counter, describe_gauge, describe_histogram, gauge};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use shared::Result;
+use shared::error::{Error, SetupError};
+use shared::metrics::*;
+
+/// Latency buckets in seconds, from a cached lookup up to a slow upload.
+const HTTP_DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Installs the global Prometheus recorder. Must run once, before anything
+/// records a metric.
+pub fn init_metrics() -> Result<PrometheusHandle> {
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full(HTTP_REQUEST_DURATION_SECONDS.to_string()),
+            HTTP_DURATION_BUCKETS,
+        )
+        .and_then(PrometheusBuilder::install_recorder)
+        .map_err(|err| Error::Setup(SetupError::FailedToInstallMetricsRecorder(err.to_string())))?;
+
+    describe_metrics();
+
+    Ok(handle)
+}
+
+fn describe_metrics() {
+    describe_counter!(
+        HTTP_REQUESTS_TOTAL,
+        "HTTP requests by method, route and status"
+    );
+    describe_histogram!(
+        HTTP_REQUEST_DURATION_SECONDS,
+        Unit::Seconds,
+        "HTTP request latency by method, route and status"
+    );
+    describe_gauge!(
+        HTTP_REQUESTS_IN_FLIGHT,
+        "HTTP requests currently being served"
+    );
+
+    describe_gauge!(DB_POOL_CONNECTIONS, "Open database connections");
+    describe_gauge!(DB_POOL_IDLE_CONNECTIONS, "Idle database connections");
+    describe_gauge!(DB_POOL_MAX_CONNECTIONS, "Maximum database pool size");
+
+    describe_counter!(
+        AUTH_FAILURES_TOTAL,
+        "Rejected sign-ins and tokens by reason"
+    );
+
+    describe_counter!(USERS_REGISTERED_TOTAL, "Accounts registered");
+    describe_counter!(SESSIONS_SCHEDULED_TOTAL, "Sessions scheduled");
+    describe_counter!(TABLE_REQUESTS_CREATED_TOTAL, "Table join requests sent");
+    describe_counter!(
+        TABLE_REQUESTS_ACCEPTED_TOTAL,
+        "Table join requests accepted"
+    );
+    describe_counter!(
+        TABLE_REQUESTS_REJECTED_TOTAL,
+        "Table join requests rejected"
+    );
+}
+
+/// Renders the Prometheus scrape payload. Pool statistics are sampled at
+/// scrape time rather than tracked continuously.
+#[derive(Clone)]
+pub struct MetricsExporter {
+    handle: PrometheusHandle,
+    database: Db,
+}
+
+impl MetricsExporter {
+    pub fn new(handle: PrometheusHandle, database: Db) -> Self {
+        Self { handle, database }
+    }
+
+    pub fn render(&self) -> String {
+        gauge!(DB_POOL_CONNECTIONS).set(self.database.size());
+        gauge!(DB_POOL_IDLE_CONNECTIONS).set(self.database.num_idle() as f64);
+        gauge!(DB_POOL_MAX_CONNECTIONS).set(self.database.options().get_max_connections());
+
+        // Without the exporter's own HTTP listener nothing else runs upkeep,
+        // so do it on every scrape.
+        self.handle.run_upkeep();
+        self.handle.render()
+    }
+}
@@ -1,6 +1,12 @@
 pub mod database;
 pub mod environment;
+pub mod health;
+pub mod jobs;
+pub mod listeners;
 pub mod logging;
+pub mod metrics;
 pub mod server;
+pub mod shutdown;
+pub mod telemetry;
 
 pub use server::launch_server;
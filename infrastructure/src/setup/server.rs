@@ -1,6 +1,7 @@
 use axum::Router;
+use std::future::IntoFuture;
 use tokio::net::TcpListener;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::state::AppState;
 use shared::Result;
@@ -21,13 +22,53 @@ pub async fn launch_server(router: Router, app_state: &AppState) -> Result<()> {
     let local_addr = listener.local_addr().expect("failed to get server addr");
 
     info!("✅ Server bound to: {}", local_addr);
+    if app_state.config.features.api_docs {
+        info!(
+            "🌐 API documentation available at: http://{}/docs",
+            local_addr
+        );
+    }
     info!(
-        "🌐 API documentation available at: http://{}/docs",
+        "🔍 Health checks available at: http://{}/health/live and /health/ready",
         local_addr
     );
-    info!("🔍 Health check available at: http://{}/health", local_addr);
 
-    axum::serve(listener, router)
-        .await
-        .map_err(|err| Error::Setup(SetupError::FailedToLaunchServer(err.to_string())))
+    let shutdown = app_state.shutdown.clone();
+    shutdown.listen_for_signals();
+
+    // Readiness fails as soon as shutdown begins, but connections are still
+    // accepted for the grace period so load balancers can notice.
+    let grace_period = app_state.config.shutdown_grace_period;
+    let stop_accepting = {
+        let shutdown = shutdown.clone();
+        async move {
+            shutdown.triggered().await;
+            tokio::time::sleep(grace_period).await;
+        }
+    };
+
+    let server = axum::serve(listener, router)
+        .with_graceful_shutdown(stop_accepting)
+        .into_future();
+
+    let drain_timeout = app_state.config.shutdown_drain_timeout;
+    let drain_deadline = async {
+        shutdown.triggered().await;
+        tokio::time::sleep(grace_period).await;
+        info!(
+            "⏳ Draining in-flight requests for up to {}s",
+            drain_timeout.as_secs()
+        );
+        tokio::time::sleep(drain_timeout).await;
+    };
+
+    tokio::select! {
+        result = server => {
+            result.map_err(|err| Error::Setup(SetupError::FailedToLaunchServer(err.to_string())))?;
+            info!("✅ HTTP server stopped");
+        }
+        _ = drain_deadline => warn!("⚠ Drain timeout elapsed, closing remaining connections"),
+    }
+
+    Ok(())
 }
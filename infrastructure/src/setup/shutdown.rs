@@ -0,0 +1,106 @@
+use crate::persistence::Db;
+use std::future::Future;
+use std::time::Duration;
+use tokio::signal;
+use tokio_util::sync::{CancellationToken, WaitForCancellationFutureOwned};
+use tokio_util::task::TaskTracker;
+use tracing::{info, warn};
+
+/// Coordinates a graceful shutdown. Once triggered, readiness reports the
+/// instance as going away, the server stops accepting connections and
+/// long-lived streams and background tasks wind down.
+#[derive(Clone, Default)]
+pub struct Shutdown {
+    token: CancellationToken,
+    tasks: TaskTracker,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn trigger(&self) {
+        self.token.cancel();
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Resolves once shutdown has been triggered.
+    pub fn triggered(&self) -> WaitForCancellationFutureOwned {
+        self.token.clone().cancelled_owned()
+    }
+
+    /// Spawns a background task that is awaited by [`Shutdown::complete`].
+    /// The task is expected to return soon after [`Shutdown::triggered`] resolves.
+    pub fn spawn<F>(&self, task: F) -> tokio::task::JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.tasks.spawn(task)
+    }
+
+    /// Triggers shutdown on the first SIGINT or SIGTERM.
+    pub fn listen_for_signals(&self) {
+        let shutdown = self.clone();
+
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = ctrl_c() => info!("🛑 Received SIGINT, shutting down"),
+                _ = terminate() => info!("🛑 Received SIGTERM, shutting down"),
+                _ = shutdown.triggered() => return,
+            }
+
+            shutdown.trigger();
+        });
+    }
+
+    /// Waits up to `timeout` for the background tasks to finish, then closes
+    /// the database pool. Call once the server has stopped.
+    pub async fn complete(&self, database: &Db, timeout: Duration) {
+        // The server may also have stopped on its own.
+        self.trigger();
+        self.tasks.close();
+
+        if tokio::time::timeout(timeout, self.tasks.wait())
+            .await
+            .is_err()
+        {
+            warn!(
+                "⚠ {} background task(s) still running after {}s, abandoning them",
+                self.tasks.len(),
+                timeout.as_secs()
+            );
+        }
+
+        database.close().await;
+        info!("👋 Shutdown complete");
+    }
+}
+
+async fn ctrl_c() {
+    if let Err(err) = signal::ctrl_c().await {
+        warn!("⚠ Failed to listen for SIGINT: {}", err);
+        std::future::pending::<()>().await;
+    }
+}
+
+#[cfg(unix)]
+async fn terminate() {
+    match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+        Ok(mut signal) => {
+            signal.recv().await;
+        }
+        Err(err) => {
+            warn!("⚠ Failed to listen for SIGTERM: {}", err);
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn terminate() {
+    std::future::pending::<()>().await;
+}
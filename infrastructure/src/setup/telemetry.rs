@@ -0,0 +1,122 @@
+use opentelemetry::global;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use shared::Result;
+use shared::error::{Error, SetupError};
+use tracing_subscriber::filter::Targets;
+
+pub const DEFAULT_OTLP_ENDPOINT: &str = "http://localhost:4318";
+pub const DEFAULT_SERVICE_NAME: &str = "jos-api";
+/// Service and repository spans are recorded at `debug`, so they are
+/// exported without becoming the current span of every log line.
+pub const DEFAULT_TRACES_FILTER: &str = "info,application=debug,infrastructure=debug";
+
+/// Export of traces to an OpenTelemetry collector over OTLP/HTTP.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    pub enabled: bool,
+    /// Collector base URL; `/v1/traces` is appended.
+    pub otlp_endpoint: String,
+    pub service_name: String,
+    /// `target=level` directives selecting which spans are exported.
+    pub traces_filter: String,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            otlp_endpoint: DEFAULT_OTLP_ENDPOINT.to_string(),
+            service_name: DEFAULT_SERVICE_NAME.to_string(),
+            traces_filter: DEFAULT_TRACES_FILTER.to_string(),
+        }
+    }
+}
+
+impl TelemetryConfig {
+    /// Lists every problem with the settings, like [`CorsConfig::validate`].
+    ///
+    /// [`CorsConfig::validate`]: crate::config::CorsConfig::validate
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.traces_filter.parse::<Targets>().is_err() {
+            problems.push(format!(
+                "telemetry.traces_filter: '{}' is not a valid filter",
+                self.traces_filter
+            ));
+        }
+        if self.enabled && !self.otlp_endpoint.contains("://") {
+            problems.push(format!(
+                "telemetry.otlp_endpoint: '{}' is not a URL",
+                self.otlp_endpoint
+            ));
+        }
+
+        problems
+    }
+
+    /// Falls back to the default directives when `traces_filter` does not
+    /// parse, which [`validate`](Self::validate) rejects at startup.
+    pub(crate) fn traces_filter(&self) -> Targets {
+        self.traces_filter
+            .parse()
+            .unwrap_or_else(|_| DEFAULT_TRACES_FILTER.parse().unwrap_or_default())
+    }
+
+    fn traces_endpoint(&self) -> String {
+        format!("{}/v1/traces", self.otlp_endpoint.trim_end_matches('/'))
+    }
+}
+
+/// Builds the OTLP tracer and registers the W3C `traceparent` propagator
+/// used to continue traces started by callers.
+pub(crate) fn init_tracer(config: &TelemetryConfig) -> Result<(SdkTracerProvider, SdkTracer)> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(config.traces_endpoint())
+        .build()
+        .map_err(|err| Error::Setup(SetupError::FailedToSetupTelemetry(err.to_string())))?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build();
+
+    let tracer = provider.tracer(config.service_name.clone());
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    global::set_tracer_provider(provider.clone());
+
+    Ok((provider, tracer))
+}
+
+/// Flushes buffered spans when dropped. Keep it alive for the lifetime of
+/// the process.
+#[must_use]
+pub struct TelemetryGuard {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl TelemetryGuard {
+    pub(crate) fn new(provider: Option<SdkTracerProvider>) -> Self {
+        Self { provider }
+    }
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take()
+            && let Err(err) = provider.shutdown()
+        {
+            eprintln!("Failed to flush traces: {err}");
+        }
+    }
+}
@@ -1,21 +1,40 @@
-use crate::config::AppConfig;
+use crate::chat::PostgresChatPublisher;
+use crate::config::{AppConfig, StorageBackend};
+use crate::mail::{LogMailer, SmtpMailer};
 use crate::persistence::Db;
 use crate::persistence::postgres::repositories::*;
 use crate::persistence::repositories::{
     PostgresSessionCheckinRepository, PostgresSessionIntentRepository,
 };
 use crate::security::{BcryptPasswordProvider, JwtTokenProvider};
+use crate::setup::health::HealthChecker;
+use crate::setup::metrics::MetricsExporter;
+use crate::setup::shutdown::Shutdown;
+use crate::storage::{LocalStorage, S3Storage};
+use application::account_export_service::AccountExportService;
+use application::admin_service::AdminService;
+use application::announcement_service::AnnouncementService;
+use application::attachment_service::AttachmentService;
 use application::auth_service::AuthService;
+use application::chat_service::ChatService;
 use application::game_system_service::GameSystemService;
+use application::image_service::ImageService;
+use application::journal_service::JournalService;
 use application::password_service::PasswordService;
+use application::purge_service::PurgeService;
+use application::search_service::SearchService;
 use application::session_checkin_service::SessionCheckinService;
 use application::session_intent_service::SessionIntentService;
 use application::session_service::SessionService;
 use application::table_member_service::TableMemberService;
 use application::table_request_service::TableRequestService;
 use application::table_service::TableService;
+use application::user_event_service::UserEventService;
 use application::user_service::UserService;
 use axum::extract::FromRef;
+use domain::mail::Mailer;
+use domain::storage::ObjectStorage;
+use metrics_exporter_prometheus::PrometheusHandle;
 use shared::Result;
 use std::sync::Arc;
 use tracing::info;
@@ -23,6 +42,9 @@ use tracing::info;
 #[derive(Clone)]
 pub struct AppState {
     pub config: AppConfig,
+    pub metrics: MetricsExporter,
+    pub health: HealthChecker,
+    pub shutdown: Shutdown,
     pub user_service: UserService,
     pub table_service: TableService,
     pub table_request_service: TableRequestService,
@@ -33,6 +55,16 @@ pub struct AppState {
     pub password_service: PasswordService,
     pub game_system_service: GameSystemService,
     pub table_member_service: TableMemberService,
+    pub admin_service: AdminService,
+    pub account_export_service: AccountExportService,
+    pub purge_service: PurgeService,
+    pub search_service: SearchService,
+    pub image_service: ImageService,
+    pub attachment_service: AttachmentService,
+    pub journal_service: JournalService,
+    pub announcement_service: AnnouncementService,
+    pub chat_service: ChatService,
+    pub user_event_service: UserEventService,
 }
 
 impl FromRef<AppState> for AppConfig {
@@ -89,27 +121,94 @@ impl FromRef<AppState> for SessionCheckinService {
     }
 }
 
+impl FromRef<AppState> for AdminService {
+    fn from_ref(input: &AppState) -> Self {
+        input.admin_service.clone()
+    }
+}
+
+impl FromRef<AppState> for AccountExportService {
+    fn from_ref(input: &AppState) -> Self {
+        input.account_export_service.clone()
+    }
+}
+
+impl FromRef<AppState> for ImageService {
+    fn from_ref(input: &AppState) -> Self {
+        input.image_service.clone()
+    }
+}
+
+impl FromRef<AppState> for AttachmentService {
+    fn from_ref(input: &AppState) -> Self {
+        input.attachment_service.clone()
+    }
+}
+
+impl FromRef<AppState> for JournalService {
+    fn from_ref(input: &AppState) -> Self {
+        input.journal_service.clone()
+    }
+}
+
+impl FromRef<AppState> for AnnouncementService {
+    fn from_ref(input: &AppState) -> Self {
+        input.announcement_service.clone()
+    }
+}
+
+impl FromRef<AppState> for ChatService {
+    fn from_ref(input: &AppState) -> Self {
+        input.chat_service.clone()
+    }
+}
+
+impl FromRef<AppState> for UserEventService {
+    fn from_ref(input: &AppState) -> Self {
+        input.user_event_service.clone()
+    }
+}
+
 impl FromRef<Arc<AppState>> for AppState {
     fn from_ref(input: &Arc<AppState>) -> Self {
         input.as_ref().clone()
     }
 }
 
-pub async fn setup_app_state(database: &Db, config: &AppConfig) -> Result<AppState> {
-    let app_state = setup_services(database, config).await?;
+pub async fn setup_app_state(
+    database: &Db,
+    config: &AppConfig,
+    metrics: PrometheusHandle,
+) -> Result<AppState> {
+    let app_state = setup_services(database, config, metrics).await?;
 
     Ok(app_state)
 }
 
-pub async fn setup_services(database: &Db, config: &AppConfig) -> Result<AppState> {
+pub async fn setup_services(
+    database: &Db,
+    config: &AppConfig,
+    metrics: PrometheusHandle,
+) -> Result<AppState> {
     info!("🔧 Initializing application setup...");
     info!("📝 Logging system initialized");
     info!("🏗️  Initializing services...");
 
+    // Audit log
+    let audit_log_repo = Arc::new(PostgresAuditLogRepository::new(database.clone()));
+
+    // Live-update events
+    let user_event_repo = Arc::new(PostgresUserEventRepository::new(database.clone()));
+
     // User service
     let user_repo = Arc::new(PostgresUserRepository::new(database.clone()));
     let password_repo = Arc::new(BcryptPasswordProvider);
-    let user_service = UserService::new(user_repo.clone(), password_repo.clone());
+    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(database.clone()));
+    let user_service = UserService::new(
+        user_repo.clone(),
+        password_repo.clone(),
+        config.deletion_grace_period,
+    );
     info!("✅ User service initialized");
 
     // Password service
@@ -119,7 +218,15 @@ pub async fn setup_services(database: &Db, config: &AppConfig) -> Result<AppStat
     // Table service
     let table_repo = Arc::new(PostgresTableRepository::new(database.clone()));
     let table_request_repo = Arc::new(PostgresTableRequestRepository::new(database.clone()));
-    let table_service = TableService::new(table_repo.clone(), table_request_repo.clone());
+    let table_question_repo = Arc::new(PostgresTableQuestionRepository::new(database.clone()));
+    let table_service = TableService::new(
+        table_repo.clone(),
+        table_request_repo.clone(),
+        user_repo.clone(),
+        audit_log_repo.clone(),
+        table_question_repo.clone(),
+        config.deletion_grace_period,
+    );
     info!("✅ Table service initialized");
 
     // Session service
@@ -132,13 +239,18 @@ pub async fn setup_services(database: &Db, config: &AppConfig) -> Result<AppStat
         table_repo.clone(),
         table_member_repo_for_req.clone(),
         session_repo.clone(),
+        table_question_repo.clone(),
     );
     info!("✅ Table request service initialized");
     let session_intent_repository =
         Arc::new(PostgresSessionIntentRepository::new(database.clone()));
     let session_checkin_repository =
         Arc::new(PostgresSessionCheckinRepository::new(database.clone()));
-    let session_service = SessionService::new(session_repo.clone(), table_repo.clone());
+    let session_service = SessionService::new(
+        session_repo.clone(),
+        table_repo.clone(),
+        config.deletion_grace_period,
+    );
     info!("✅ Session service initialized");
 
     // Session Intent service
@@ -151,7 +263,7 @@ pub async fn setup_services(database: &Db, config: &AppConfig) -> Result<AppStat
     info!("✅ Session Intent service initialized");
 
     // Session Checkin service
-    let session_checkin_service = SessionCheckinService::new(session_checkin_repository);
+    let session_checkin_service = SessionCheckinService::new(session_checkin_repository.clone());
     info!("✅ Session Checkin service initialized");
 
     // Auth service
@@ -159,13 +271,26 @@ pub async fn setup_services(database: &Db, config: &AppConfig) -> Result<AppStat
         config.jwt_secret.clone(),
         config.jwt_expiration_duration,
     ));
-    let refresh_token_repo = Arc::new(PostgresRefreshTokenRepository::new(database.clone()));
+    let magic_link_repo = Arc::new(PostgresMagicLinkRepository::new(database.clone()));
+    let smtp_mailer = match &config.smtp_url {
+        Some(smtp_url) => Some(SmtpMailer::new(smtp_url, &config.mail_from)?),
+        None => None,
+    };
+    let mailer: Arc<dyn Mailer> = match &smtp_mailer {
+        Some(smtp_mailer) => Arc::new(smtp_mailer.clone()),
+        None => Arc::new(LogMailer),
+    };
     let auth_service = AuthService::new(
         user_repo.clone(),
         password_repo.clone(),
         jwt_provider.clone(),
         refresh_token_repo.clone(),
+        audit_log_repo.clone(),
+        magic_link_repo.clone(),
+        mailer.clone(),
+        config.magic_link_url.clone(),
         config.jwt_expiration_duration,
+        config.refresh_token_expiration_duration,
     );
     info!("✅ Auth service initialized");
 
@@ -176,12 +301,140 @@ pub async fn setup_services(database: &Db, config: &AppConfig) -> Result<AppStat
 
     // Table member service
     let table_member_repo = Arc::new(PostgresTableMemberRepository::new(database.clone()));
-    let table_member_service = TableMemberService::new(table_member_repo.clone());
+    let table_member_service =
+        TableMemberService::new(table_member_repo.clone(), table_repo.clone());
     info!("✅ Table member service initialized");
 
+    // Admin service
+    let admin_service = AdminService::new(
+        user_repo.clone(),
+        table_repo.clone(),
+        game_system_repo.clone(),
+        audit_log_repo.clone(),
+    );
+    info!("✅ Admin service initialized");
+
+    // Account export service
+    let account_export_repo = Arc::new(PostgresAccountExportRepository::new(database.clone()));
+    let account_export_service = AccountExportService::new(
+        user_repo.clone(),
+        table_repo.clone(),
+        table_member_repo.clone(),
+        table_request_repo.clone(),
+        session_intent_repository.clone(),
+        session_checkin_repository.clone(),
+        account_export_repo,
+    );
+    info!("✅ Account export service initialized");
+
+    // Search service
+    let search_repo = Arc::new(PostgresSearchRepository::new(database.clone()));
+    let search_service = SearchService::new(search_repo);
+    info!("✅ Search service initialized");
+
+    // Image service
+    let storage: Arc<dyn ObjectStorage> = match &config.storage {
+        StorageBackend::Local { path } => Arc::new(LocalStorage::new(path)),
+        StorageBackend::S3 {
+            bucket,
+            endpoint,
+            region,
+            access_key_id,
+            secret_access_key,
+        } => Arc::new(S3Storage::new(
+            bucket,
+            endpoint.as_deref(),
+            region,
+            access_key_id,
+            secret_access_key,
+        )?),
+    };
+    let image_repo = Arc::new(PostgresImageRepository::new(database.clone()));
+    let image_service = ImageService::new(
+        image_repo.clone(),
+        table_repo.clone(),
+        storage.clone(),
+        config.max_upload_bytes,
+    );
+    info!("✅ Image service initialized");
+
+    // Attachment service
+    let attachment_repo = Arc::new(PostgresAttachmentRepository::new(database.clone()));
+    let attachment_service = AttachmentService::new(
+        attachment_repo.clone(),
+        table_repo.clone(),
+        session_repo.clone(),
+        table_member_repo.clone(),
+        storage.clone(),
+        config.max_attachment_bytes,
+        config.table_attachment_quota_bytes,
+    );
+    info!("✅ Attachment service initialized");
+
+    // Purge service
+    let purge_service = PurgeService::new(
+        user_repo.clone(),
+        table_repo.clone(),
+        session_repo.clone(),
+        magic_link_repo.clone(),
+        user_event_repo.clone(),
+        image_repo.clone(),
+        attachment_repo.clone(),
+        storage.clone(),
+        config.deletion_grace_period,
+        config.user_event_retention,
+    );
+    info!("✅ Purge service initialized");
+
+    // Journal service
+    let journal_repo = Arc::new(PostgresJournalRepository::new(database.clone()));
+    let journal_service = JournalService::new(
+        journal_repo,
+        table_repo.clone(),
+        session_repo.clone(),
+        table_member_repo.clone(),
+    );
+    info!("✅ Journal service initialized");
+
+    // Announcement service
+    let announcement_repo = Arc::new(PostgresAnnouncementRepository::new(database.clone()));
+    let announcement_service = AnnouncementService::new(
+        announcement_repo,
+        table_repo.clone(),
+        table_member_repo.clone(),
+    );
+    info!("✅ Announcement service initialized");
+
+    // Chat service
+    let chat_message_repo = Arc::new(PostgresChatMessageRepository::new(database.clone()));
+    let chat_publisher = Arc::new(PostgresChatPublisher::new(database.clone()));
+    let chat_service = ChatService::new(
+        chat_message_repo,
+        table_repo.clone(),
+        table_member_repo.clone(),
+        user_repo.clone(),
+        chat_publisher,
+    );
+    info!("✅ Chat service initialized");
+
+    // User event service
+    let user_event_service = UserEventService::new(user_event_repo);
+    info!("✅ User event service initialized");
+
+    let shutdown = Shutdown::new();
+
     // Create AppState
     let app_state = AppState {
         config: config.clone(),
+        metrics: MetricsExporter::new(metrics, database.clone()),
+        health: HealthChecker::new(
+            database.clone(),
+            storage,
+            smtp_mailer,
+            shutdown.clone(),
+            config.readiness_timeout,
+        ),
+        shutdown,
         user_service,
         table_service,
         table_request_service,
@@ -192,6 +445,16 @@ pub async fn setup_services(database: &Db, config: &AppConfig) -> Result<AppStat
         password_service,
         game_system_service,
         table_member_service,
+        admin_service,
+        account_export_service,
+        purge_service,
+        search_service,
+        image_service,
+        attachment_service,
+        journal_service,
+        announcement_service,
+        chat_service,
+        user_event_service,
     };
 
     info!("🎉 Application setup completed successfully!");
@@ -0,0 +1,78 @@
+use domain::storage::ObjectStorage;
+use shared::Result;
+use shared::error::{ApplicationError, Error};
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use tracing::error;
+
+/// Stores objects as plain files below a root directory.
+#[derive(Clone)]
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Keys are generated by the services, but are still checked so a bad
+    /// key can never point outside the root.
+    fn path_for(&self, key: &str) -> Result<PathBuf> {
+        let relative = Path::new(key);
+        let is_safe = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+
+        if !is_safe {
+            return Err(Error::Application(ApplicationError::InvalidInput {
+                message: "Invalid storage key".to_string(),
+            }));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+fn storage_error(err: std::io::Error) -> Error {
+    error!("Local storage failure: {}", err);
+    Error::Application(ApplicationError::ServiceUnavailable {
+        service: "storage".to_string(),
+    })
+}
+
+#[async_trait::async_trait]
+impl ObjectStorage for LocalStorage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, _content_type: &str) -> Result<()> {
+        let path = self.path_for(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(storage_error)?;
+        }
+
+        tokio::fs::write(&path, bytes).await.map_err(storage_error)
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.path_for(key)?;
+
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(storage_error(err)),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let path = self.path_for(key)?;
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(storage_error(err)),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod local;
+pub mod s3;
+
+pub use local::*;
+pub use s3::*;
@@ -0,0 +1,89 @@
+use domain::storage::ObjectStorage;
+use object_store::aws::{AmazonS3, AmazonS3Builder};
+use object_store::path::Path;
+use object_store::{Attribute, Attributes, ObjectStore, PutOptions, PutPayload};
+use shared::Result;
+use shared::error::{ApplicationError, Error, SetupError};
+use tracing::error;
+
+/// Stores objects in an S3 bucket. Any S3-compatible service works, which is
+/// how MinIO stands in for S3 during local development.
+#[derive(Clone)]
+pub struct S3Storage {
+    store: AmazonS3,
+}
+
+impl S3Storage {
+    pub fn new(
+        bucket: &str,
+        endpoint: Option<&str>,
+        region: &str,
+        access_key_id: &str,
+        secret_access_key: &str,
+    ) -> Result<Self> {
+        let mut builder = AmazonS3Builder::new()
+            .with_bucket_name(bucket)
+            .with_region(region)
+            .with_access_key_id(access_key_id)
+            .with_secret_access_key(secret_access_key);
+
+        if let Some(endpoint) = endpoint {
+            builder = builder
+                .with_endpoint(endpoint)
+                .with_virtual_hosted_style_request(false)
+                .with_allow_http(endpoint.starts_with("http://"));
+        }
+
+        let store = builder
+            .build()
+            .map_err(|err| Error::Setup(SetupError::InvalidConfiguration(err.to_string())))?;
+
+        Ok(Self { store })
+    }
+}
+
+fn storage_error(err: object_store::Error) -> Error {
+    error!("S3 storage failure: {}", err);
+    Error::Application(ApplicationError::ServiceUnavailable {
+        service: "storage".to_string(),
+    })
+}
+
+#[async_trait::async_trait]
+impl ObjectStorage for S3Storage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<()> {
+        let mut attributes = Attributes::new();
+        attributes.insert(Attribute::ContentType, content_type.to_string().into());
+
+        let options = PutOptions {
+            attributes,
+            ..Default::default()
+        };
+
+        self.store
+            .put_opts(&Path::from(key), PutPayload::from(bytes), options)
+            .await
+            .map_err(storage_error)?;
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        let result = match self.store.get(&Path::from(key)).await {
+            Ok(result) => result,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(err) => return Err(storage_error(err)),
+        };
+
+        let bytes = result.bytes().await.map_err(storage_error)?;
+
+        Ok(Some(bytes.to_vec()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        match self.store.delete(&Path::from(key)).await {
+            Ok(()) | Err(object_store::Error::NotFound { .. }) => Ok(()),
+            Err(err) => Err(storage_error(err)),
+        }
+    }
+}
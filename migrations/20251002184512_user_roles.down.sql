@@ -0,0 +1,8 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_users_role;
+
+ALTER TABLE users
+    DROP COLUMN IF EXISTS "suspended_at",
+    DROP COLUMN IF EXISTS "role";
+
+DROP TYPE IF EXISTS user_role;
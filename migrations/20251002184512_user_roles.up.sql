@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TYPE user_role AS ENUM ('user', 'admin');
+
+ALTER TABLE users
+    ADD COLUMN "role"         user_role   NOT NULL DEFAULT 'user',
+    ADD COLUMN "suspended_at" TIMESTAMPTZ;
+
+CREATE INDEX idx_users_role ON users ("role");
@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS audit_logs_no_update_or_delete ON audit_logs;
+DROP FUNCTION IF EXISTS audit_logs_append_only();
+DROP TABLE IF EXISTS audit_logs;
+DROP TYPE IF EXISTS audit_entity;
+DROP TYPE IF EXISTS audit_action;
@@ -0,0 +1,51 @@
+-- Add up migration script here
+CREATE TYPE audit_action AS ENUM (
+    'user_registered',
+    'login_succeeded',
+    'login_failed',
+    'logout',
+    'password_changed',
+    'user_suspended',
+    'user_unsuspended',
+    'user_role_changed',
+    'table_updated',
+    'table_deleted',
+    'session_updated',
+    'session_deleted',
+    'request_approved',
+    'request_rejected'
+);
+
+CREATE TYPE audit_entity AS ENUM ('user', 'table', 'session', 'table_request');
+
+-- actor_id and table_id intentionally carry no foreign keys: audit entries
+-- must outlive the users and tables they describe.
+CREATE TABLE audit_logs
+(
+    id          UUID PRIMARY KEY NOT NULL DEFAULT uuidv7(),
+    actor_id    UUID,
+    action      audit_action     NOT NULL,
+    entity_type audit_entity     NOT NULL,
+    entity_id   UUID             NOT NULL,
+    table_id    UUID,
+    before      JSONB,
+    after       JSONB,
+    created_at  TIMESTAMPTZ      NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_audit_logs_actor ON audit_logs (actor_id, created_at DESC);
+CREATE INDEX idx_audit_logs_entity ON audit_logs (entity_type, entity_id, created_at DESC);
+CREATE INDEX idx_audit_logs_table ON audit_logs (table_id, created_at DESC);
+
+CREATE FUNCTION audit_logs_append_only() RETURNS trigger AS
+$$
+BEGIN
+    RAISE EXCEPTION 'audit_logs is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_logs_no_update_or_delete
+    BEFORE UPDATE OR DELETE
+    ON audit_logs
+    FOR EACH ROW
+EXECUTE FUNCTION audit_logs_append_only();
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS account_exports;
+DROP TYPE IF EXISTS export_status;
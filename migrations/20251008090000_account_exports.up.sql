@@ -0,0 +1,15 @@
+-- Add up migration script here
+CREATE TYPE export_status AS ENUM ('pending', 'ready', 'failed');
+
+CREATE TABLE account_exports
+(
+    id           UUID PRIMARY KEY NOT NULL DEFAULT uuidv7(),
+    user_id      UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    status       export_status    NOT NULL DEFAULT 'pending',
+    payload      JSONB,
+    error        TEXT,
+    created_at   TIMESTAMPTZ      NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_account_exports_user ON account_exports (user_id, created_at DESC);
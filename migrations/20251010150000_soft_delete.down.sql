@@ -0,0 +1,9 @@
+-- Add down migration script here
+-- Postgres cannot drop enum values, so the audit_action additions are kept.
+DROP INDEX IF EXISTS idx_sessions_deleted_at;
+DROP INDEX IF EXISTS idx_tables_deleted_at;
+DROP INDEX IF EXISTS idx_users_deleted_at;
+
+ALTER TABLE sessions DROP COLUMN IF EXISTS "deleted_at";
+ALTER TABLE tables DROP COLUMN IF EXISTS "deleted_at";
+ALTER TABLE users DROP COLUMN IF EXISTS "deleted_at";
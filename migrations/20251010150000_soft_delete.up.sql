@@ -0,0 +1,13 @@
+-- Add up migration script here
+ALTER TABLE users ADD COLUMN "deleted_at" TIMESTAMPTZ;
+ALTER TABLE tables ADD COLUMN "deleted_at" TIMESTAMPTZ;
+ALTER TABLE sessions ADD COLUMN "deleted_at" TIMESTAMPTZ;
+
+CREATE INDEX idx_users_deleted_at ON users ("deleted_at") WHERE "deleted_at" IS NOT NULL;
+CREATE INDEX idx_tables_deleted_at ON tables ("deleted_at") WHERE "deleted_at" IS NOT NULL;
+CREATE INDEX idx_sessions_deleted_at ON sessions ("deleted_at") WHERE "deleted_at" IS NOT NULL;
+
+ALTER TYPE audit_action ADD VALUE 'account_deleted';
+ALTER TYPE audit_action ADD VALUE 'account_restored';
+ALTER TYPE audit_action ADD VALUE 'table_restored';
+ALTER TYPE audit_action ADD VALUE 'session_restored';
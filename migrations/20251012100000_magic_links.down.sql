@@ -0,0 +1,3 @@
+-- Add down migration script here
+-- Postgres cannot drop enum values; 'magic_link_requested' stays on audit_action.
+DROP TABLE IF EXISTS magic_link_tokens;
@@ -0,0 +1,14 @@
+-- Add up migration script here
+CREATE TABLE magic_link_tokens
+(
+    id          UUID PRIMARY KEY NOT NULL DEFAULT uuidv7(),
+    user_id     UUID             NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    token_hash  TEXT             NOT NULL UNIQUE,
+    expires_at  TIMESTAMPTZ      NOT NULL,
+    consumed_at TIMESTAMPTZ,
+    created_at  TIMESTAMPTZ      NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_magic_link_tokens_user ON magic_link_tokens (user_id, created_at DESC);
+
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'magic_link_requested';
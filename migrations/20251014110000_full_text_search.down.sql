@@ -0,0 +1,10 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_game_systems_search;
+DROP INDEX IF EXISTS idx_users_search;
+DROP INDEX IF EXISTS idx_sessions_search;
+DROP INDEX IF EXISTS idx_tables_search;
+
+ALTER TABLE game_systems DROP COLUMN IF EXISTS search_vector;
+ALTER TABLE users DROP COLUMN IF EXISTS search_vector;
+ALTER TABLE sessions DROP COLUMN IF EXISTS search_vector;
+ALTER TABLE tables DROP COLUMN IF EXISTS search_vector;
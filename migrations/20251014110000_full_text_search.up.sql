@@ -0,0 +1,29 @@
+-- Add up migration script here
+-- The 'simple' configuration avoids language-specific stemming, since table
+-- and session texts are written in whatever language the group plays in.
+ALTER TABLE tables
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('simple', coalesce(description, '')), 'B')
+    ) STORED;
+
+ALTER TABLE sessions
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('simple', coalesce(description, '')), 'C')
+    ) STORED;
+
+ALTER TABLE users
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', coalesce(username, '')), 'A')
+    ) STORED;
+
+ALTER TABLE game_systems
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', coalesce(name, '')), 'A')
+    ) STORED;
+
+CREATE INDEX idx_tables_search ON tables USING GIN (search_vector);
+CREATE INDEX idx_sessions_search ON sessions USING GIN (search_vector);
+CREATE INDEX idx_users_search ON users USING GIN (search_vector);
+CREATE INDEX idx_game_systems_search ON game_systems USING GIN (search_vector);
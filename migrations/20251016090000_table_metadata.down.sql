@@ -0,0 +1,13 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_tables_discovery;
+DROP INDEX IF EXISTS idx_tables_tags;
+
+ALTER TABLE tables
+    DROP COLUMN IF EXISTS age_rating,
+    DROP COLUMN IF EXISTS beginner_friendly,
+    DROP COLUMN IF EXISTS format,
+    DROP COLUMN IF EXISTS language,
+    DROP COLUMN IF EXISTS tags;
+
+DROP TYPE IF EXISTS age_rating;
+DROP TYPE IF EXISTS table_format;
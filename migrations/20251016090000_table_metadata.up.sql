@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TYPE table_format AS ENUM ('online', 'in_person', 'hybrid');
+CREATE TYPE age_rating AS ENUM ('all_ages', 'teen', 'mature');
+
+ALTER TABLE tables
+    ADD COLUMN tags              TEXT[]       NOT NULL DEFAULT '{}',
+    ADD COLUMN language          TEXT,
+    ADD COLUMN format            table_format NOT NULL DEFAULT 'online',
+    ADD COLUMN beginner_friendly BOOLEAN      NOT NULL DEFAULT FALSE,
+    ADD COLUMN age_rating        age_rating   NOT NULL DEFAULT 'all_ages';
+
+CREATE INDEX idx_tables_tags ON tables USING GIN (tags);
+CREATE INDEX idx_tables_discovery ON tables (format, language, age_rating) WHERE deleted_at IS NULL;
@@ -0,0 +1,10 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_tables_latitude;
+
+ALTER TABLE tables
+    DROP CONSTRAINT IF EXISTS tables_longitude_range,
+    DROP CONSTRAINT IF EXISTS tables_latitude_range,
+    DROP CONSTRAINT IF EXISTS tables_location_complete,
+    DROP COLUMN IF EXISTS longitude,
+    DROP COLUMN IF EXISTS latitude,
+    DROP COLUMN IF EXISTS venue_address;
@@ -0,0 +1,15 @@
+-- Add up migration script here
+ALTER TABLE tables
+    ADD COLUMN venue_address TEXT,
+    ADD COLUMN latitude      DOUBLE PRECISION,
+    ADD COLUMN longitude     DOUBLE PRECISION,
+    ADD CONSTRAINT tables_location_complete CHECK (
+        (venue_address IS NULL) = (latitude IS NULL)
+        AND (latitude IS NULL) = (longitude IS NULL)
+    ),
+    ADD CONSTRAINT tables_latitude_range CHECK (latitude BETWEEN -90 AND 90),
+    ADD CONSTRAINT tables_longitude_range CHECK (longitude BETWEEN -180 AND 180);
+
+-- Radius searches pre-filter on a latitude band before computing distances.
+CREATE INDEX idx_tables_latitude ON tables (latitude)
+    WHERE latitude IS NOT NULL AND deleted_at IS NULL;
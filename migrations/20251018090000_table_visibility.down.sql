@@ -0,0 +1,7 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_tables_visibility;
+
+ALTER TABLE tables
+    DROP COLUMN IF EXISTS visibility;
+
+DROP TYPE IF EXISTS table_visibility;
@@ -0,0 +1,7 @@
+-- Add up migration script here
+CREATE TYPE table_visibility AS ENUM ('public', 'unlisted', 'private');
+
+ALTER TABLE tables
+    ADD COLUMN visibility table_visibility NOT NULL DEFAULT 'public';
+
+CREATE INDEX idx_tables_visibility ON tables (visibility) WHERE deleted_at IS NULL;
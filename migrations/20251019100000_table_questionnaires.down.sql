@@ -0,0 +1,6 @@
+-- Add down migration script here
+ALTER TABLE table_requests
+    DROP COLUMN IF EXISTS answers;
+
+DROP TABLE IF EXISTS table_questions;
+DROP TYPE IF EXISTS question_kind;
@@ -0,0 +1,21 @@
+-- Add up migration script here
+CREATE TYPE question_kind AS ENUM ('text', 'single_choice', 'multi_choice');
+
+CREATE TABLE table_questions (
+    "id" UUID NOT NULL DEFAULT uuidv7(),
+    "table_id" UUID NOT NULL,
+    "position" INTEGER NOT NULL,
+    "prompt" TEXT NOT NULL,
+    "kind" question_kind NOT NULL,
+    "options" TEXT[] NOT NULL DEFAULT '{}',
+    "required" BOOLEAN NOT NULL DEFAULT FALSE,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("id"),
+    FOREIGN KEY("table_id") REFERENCES tables("id") ON DELETE CASCADE,
+    UNIQUE("table_id", "position")
+);
+
+-- Answers keep a copy of each prompt so they stay readable after the GM
+-- edits the questionnaire.
+ALTER TABLE table_requests
+    ADD COLUMN answers JSONB NOT NULL DEFAULT '[]';
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS images;
+DROP TYPE IF EXISTS image_kind;
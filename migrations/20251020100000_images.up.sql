@@ -0,0 +1,20 @@
+-- Add up migration script here
+CREATE TYPE image_kind AS ENUM ('table_cover', 'avatar');
+
+-- subject_id points at a table or a user depending on kind.
+CREATE TABLE images (
+    "id" UUID NOT NULL DEFAULT uuidv7(),
+    "kind" image_kind NOT NULL,
+    "subject_id" UUID NOT NULL,
+    "uploaded_by" UUID NOT NULL,
+    "content_type" TEXT NOT NULL,
+    "width" INTEGER NOT NULL,
+    "height" INTEGER NOT NULL,
+    "size_bytes" BIGINT NOT NULL,
+    "storage_key" TEXT NOT NULL,
+    "thumbnail_key" TEXT NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("id"),
+    FOREIGN KEY("uploaded_by") REFERENCES users("id") ON DELETE CASCADE,
+    UNIQUE("kind", "subject_id")
+);
@@ -0,0 +1,20 @@
+-- Add up migration script here
+-- session_id is NULL for handouts shared with the whole table.
+CREATE TABLE attachments (
+    "id" UUID NOT NULL DEFAULT uuidv7(),
+    "table_id" UUID NOT NULL,
+    "session_id" UUID,
+    "uploaded_by" UUID NOT NULL,
+    "file_name" TEXT NOT NULL,
+    "content_type" TEXT NOT NULL,
+    "size_bytes" BIGINT NOT NULL CHECK ("size_bytes" >= 0),
+    "storage_key" TEXT NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("id"),
+    FOREIGN KEY("table_id") REFERENCES tables("id") ON DELETE CASCADE,
+    FOREIGN KEY("session_id") REFERENCES sessions("id") ON DELETE CASCADE,
+    FOREIGN KEY("uploaded_by") REFERENCES users("id") ON DELETE CASCADE
+);
+
+CREATE INDEX idx_attachments_table_id ON attachments("table_id");
+CREATE INDEX idx_attachments_session_id ON attachments("session_id");
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS journal_entry_revisions;
+DROP TABLE IF EXISTS journal_entries;
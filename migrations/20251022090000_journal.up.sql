@@ -0,0 +1,34 @@
+-- Add up migration script here
+-- body_html is rendered from body when the entry is written.
+CREATE TABLE journal_entries (
+    "id" UUID NOT NULL DEFAULT uuidv7(),
+    "table_id" UUID NOT NULL,
+    "session_id" UUID,
+    "author_id" UUID NOT NULL,
+    "title" TEXT NOT NULL,
+    "body" TEXT NOT NULL,
+    "body_html" TEXT NOT NULL,
+    "revision" INTEGER NOT NULL DEFAULT 1,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    "updated_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("id"),
+    FOREIGN KEY("table_id") REFERENCES tables("id") ON DELETE CASCADE,
+    FOREIGN KEY("session_id") REFERENCES sessions("id") ON DELETE SET NULL,
+    FOREIGN KEY("author_id") REFERENCES users("id") ON DELETE CASCADE
+);
+
+CREATE INDEX idx_journal_entries_table_id ON journal_entries("table_id");
+CREATE INDEX idx_journal_entries_session_id ON journal_entries("session_id");
+
+-- Every version of an entry, including the current one.
+CREATE TABLE journal_entry_revisions (
+    "entry_id" UUID NOT NULL,
+    "revision" INTEGER NOT NULL,
+    "title" TEXT NOT NULL,
+    "body" TEXT NOT NULL,
+    "edited_by" UUID NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("entry_id", "revision"),
+    FOREIGN KEY("entry_id") REFERENCES journal_entries("id") ON DELETE CASCADE,
+    FOREIGN KEY("edited_by") REFERENCES users("id") ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS announcement_comments;
+DROP TABLE IF EXISTS announcement_reads;
+DROP TABLE IF EXISTS announcements;
@@ -0,0 +1,39 @@
+-- Add up migration script here
+CREATE TABLE announcements (
+    "id" UUID NOT NULL DEFAULT uuidv7(),
+    "table_id" UUID NOT NULL,
+    "author_id" UUID NOT NULL,
+    "title" TEXT NOT NULL,
+    "body" TEXT NOT NULL,
+    "pinned" BOOLEAN NOT NULL DEFAULT FALSE,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    "updated_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("id"),
+    FOREIGN KEY("table_id") REFERENCES tables("id") ON DELETE CASCADE,
+    FOREIGN KEY("author_id") REFERENCES users("id") ON DELETE CASCADE
+);
+
+CREATE INDEX idx_announcements_table_id ON announcements("table_id", "pinned", "created_at");
+
+CREATE TABLE announcement_reads (
+    "announcement_id" UUID NOT NULL,
+    "user_id" UUID NOT NULL,
+    "read_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("announcement_id", "user_id"),
+    FOREIGN KEY("announcement_id") REFERENCES announcements("id") ON DELETE CASCADE,
+    FOREIGN KEY("user_id") REFERENCES users("id") ON DELETE CASCADE
+);
+
+CREATE TABLE announcement_comments (
+    "id" UUID NOT NULL DEFAULT uuidv7(),
+    "announcement_id" UUID NOT NULL,
+    "author_id" UUID NOT NULL,
+    "body" TEXT NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("id"),
+    FOREIGN KEY("announcement_id") REFERENCES announcements("id") ON DELETE CASCADE,
+    FOREIGN KEY("author_id") REFERENCES users("id") ON DELETE CASCADE
+);
+
+CREATE INDEX idx_announcement_comments_announcement_id
+    ON announcement_comments("announcement_id", "created_at");
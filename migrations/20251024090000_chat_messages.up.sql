@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE chat_messages (
+    "id" UUID NOT NULL DEFAULT uuidv7(),
+    "table_id" UUID NOT NULL,
+    "author_id" UUID NOT NULL,
+    "body" TEXT NOT NULL,
+    "created_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY("id"),
+    FOREIGN KEY("table_id") REFERENCES tables("id") ON DELETE CASCADE,
+    FOREIGN KEY("author_id") REFERENCES users("id") ON DELETE CASCADE
+);
+
+CREATE INDEX idx_chat_messages_table_id ON chat_messages("table_id", "id");
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS user_events;
+DROP FUNCTION IF EXISTS user_events_notify;
+DROP TYPE IF EXISTS user_event_kind;
@@ -0,0 +1,38 @@
+-- Add up migration script here
+CREATE TYPE user_event_kind AS ENUM (
+    'session_scheduled',
+    'session_started',
+    'session_completed',
+    'intent_changed',
+    'request_accepted'
+);
+
+-- Ids are sequential so clients can resume a stream with Last-Event-ID.
+CREATE TABLE user_events
+(
+    id         BIGSERIAL PRIMARY KEY,
+    user_id    UUID            NOT NULL,
+    kind       user_event_kind NOT NULL,
+    payload    JSONB           NOT NULL,
+    created_at TIMESTAMPTZ     NOT NULL DEFAULT NOW(),
+    FOREIGN KEY ("user_id") REFERENCES users ("id") ON DELETE CASCADE
+);
+
+CREATE INDEX idx_user_events_user_id ON user_events (user_id, id);
+CREATE INDEX idx_user_events_created_at ON user_events (created_at);
+
+-- Wakes up the streams of the recipient on every instance once the event
+-- is committed.
+CREATE FUNCTION user_events_notify() RETURNS trigger AS
+$$
+BEGIN
+    PERFORM pg_notify('user_events', NEW.user_id::text);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER user_events_notify
+    AFTER INSERT
+    ON user_events
+    FOR EACH ROW
+EXECUTE FUNCTION user_events_notify();
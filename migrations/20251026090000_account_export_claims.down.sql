@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_account_exports_pending_user;
+
+ALTER TABLE account_exports
+    DROP COLUMN IF EXISTS claimed_at;
@@ -0,0 +1,23 @@
+-- Add up migration script here
+-- The export worker claims a pending job before building it. Claims expire,
+-- so a job abandoned by a crashed instance is picked up again.
+ALTER TABLE account_exports
+    ADD COLUMN claimed_at TIMESTAMPTZ;
+
+-- Keep only the latest pending export per user before enforcing uniqueness.
+UPDATE account_exports
+SET
+    status = 'failed',
+    error = 'Superseded by a newer export request',
+    completed_at = NOW()
+WHERE status = 'pending'
+    AND id NOT IN (
+        SELECT DISTINCT ON (user_id) id
+        FROM account_exports
+        WHERE status = 'pending'
+        ORDER BY user_id, created_at DESC
+    );
+
+CREATE UNIQUE INDEX idx_account_exports_pending_user
+    ON account_exports (user_id)
+    WHERE status = 'pending';
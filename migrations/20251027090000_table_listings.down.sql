@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP FUNCTION IF EXISTS table_listings(
+    UUID, UUID, table_status, UUID, TEXT[], TEXT, table_format, BOOLEAN, age_rating,
+    INT, FLOAT8, FLOAT8, FLOAT8, UUID
+);
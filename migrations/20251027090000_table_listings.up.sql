@@ -0,0 +1,111 @@
+-- Add up migration script here
+-- Tables matching the directory filters, with their open slots and distance
+-- from the search point. Listing and counting both read from here so they
+-- always agree; being a single STABLE query, the planner inlines it.
+CREATE FUNCTION table_listings(
+    p_id UUID,
+    p_gm_id UUID,
+    p_status table_status,
+    p_game_system_id UUID,
+    p_tags TEXT[],
+    p_language TEXT,
+    p_format table_format,
+    p_beginner_friendly BOOLEAN,
+    p_age_rating age_rating,
+    p_min_open_slots INT,
+    p_latitude FLOAT8,
+    p_longitude FLOAT8,
+    p_radius_km FLOAT8,
+    p_visible_to UUID
+)
+RETURNS TABLE (
+    id UUID,
+    gm_id UUID,
+    title TEXT,
+    description TEXT,
+    slots INT,
+    status table_status,
+    game_system_id UUID,
+    tags TEXT[],
+    language TEXT,
+    format table_format,
+    beginner_friendly BOOLEAN,
+    age_rating age_rating,
+    venue_address TEXT,
+    latitude FLOAT8,
+    longitude FLOAT8,
+    visibility table_visibility,
+    created_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ,
+    open_slots INT,
+    distance_km FLOAT8
+)
+LANGUAGE sql
+STABLE
+AS $$
+    SELECT *
+    FROM (
+        SELECT
+            tables.id,
+            tables.gm_id,
+            tables.title,
+            tables.description,
+            tables.slots,
+            tables.status,
+            tables.game_system_id,
+            tables.tags,
+            tables.language,
+            tables.format,
+            tables.beginner_friendly,
+            tables.age_rating,
+            tables.venue_address,
+            tables.latitude,
+            tables.longitude,
+            tables.visibility,
+            tables.created_at,
+            tables.updated_at,
+            GREATEST(
+                tables.slots - (
+                    SELECT COUNT(*)
+                    FROM table_members
+                    INNER JOIN users ON users.id = table_members.user_id
+                    WHERE table_members.table_id = tables.id
+                        AND users.deleted_at IS NULL
+                ),
+                0
+            )::int AS open_slots,
+            CASE WHEN p_latitude IS NOT NULL AND tables.latitude IS NOT NULL THEN
+                -- Haversine distance in kilometres.
+                6371.0088 * 2 * asin(LEAST(1, sqrt(
+                    power(sin(radians(tables.latitude - p_latitude) / 2), 2)
+                    + cos(radians(p_latitude)) * cos(radians(tables.latitude))
+                    * power(sin(radians(tables.longitude - p_longitude) / 2), 2)
+                )))
+            END AS distance_km
+        FROM tables
+        WHERE tables.deleted_at IS NULL
+            AND (p_id IS NULL OR tables.id = p_id)
+            AND (p_gm_id IS NULL OR tables.gm_id = p_gm_id)
+            AND (p_status IS NULL OR tables.status = p_status)
+            AND (p_game_system_id IS NULL OR tables.game_system_id = p_game_system_id)
+            AND (p_tags IS NULL OR tables.tags @> p_tags)
+            AND (p_language IS NULL OR lower(tables.language) = lower(p_language))
+            AND (p_format IS NULL OR tables.format = p_format)
+            AND (p_beginner_friendly IS NULL OR tables.beginner_friendly = p_beginner_friendly)
+            AND (p_age_rating IS NULL OR tables.age_rating = p_age_rating)
+            AND (
+                p_visible_to IS NULL
+                OR tables.visibility = 'public'
+                OR tables.gm_id = p_visible_to
+                OR EXISTS (
+                    SELECT 1 FROM table_members
+                    WHERE table_members.table_id = tables.id
+                        AND table_members.user_id = p_visible_to
+                )
+            )
+            AND (p_latitude IS NULL OR tables.latitude
+                BETWEEN p_latitude - p_radius_km / 111.045 AND p_latitude + p_radius_km / 111.045)
+    ) listing
+    WHERE (p_min_open_slots IS NULL OR listing.open_slots >= p_min_open_slots)
+        AND (p_latitude IS NULL OR listing.distance_km <= p_radius_km)
+$$;
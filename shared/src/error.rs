@@ -1,11 +1,89 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Response},
 };
-use log::error;
-use serde_json::json;
-use validator::ValidationErrors;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// RFC 7807 body of every error response. `code` is stable and meant for
+/// clients to branch on; `detail` is for humans and may change.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Path of the request that failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Per-field details of validation failures.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    /// Dotted path of the field, with list indexes in brackets.
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, code: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            problem_type: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or("Error").to_string(),
+            status: status.as_u16(),
+            code: code.into(),
+            detail: Some(detail.into()).filter(|detail: &String| !detail.is_empty()),
+            instance: None,
+            request_id: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Describes an error response that did not come from [`Error`], such as
+    /// a framework rejection, deriving the code from the status.
+    pub fn from_status(status: StatusCode, detail: impl Into<String>) -> Self {
+        let code = status
+            .canonical_reason()
+            .unwrap_or("error")
+            .to_ascii_lowercase()
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+        Self::new(status, code, detail)
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+/// The problem is also stored in the response extensions so middleware can
+/// enrich it with request details before it is sent.
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let mut response = (
+            status,
+            [(header::CONTENT_TYPE, PROBLEM_JSON_CONTENT_TYPE)],
+            Json(&self),
+        )
+            .into_response();
+        response.extensions_mut().insert(self);
+        response
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -30,13 +108,59 @@ impl IntoResponse for Error {
             Error::Application(error) => error.into_response(),
             Error::Domain(error) => error.into_response(),
             Error::Setup(error) => error.into_response(),
-            Error::InternalServerError => (
+            Error::InternalServerError => ProblemDetails::new(
                 StatusCode::INTERNAL_SERVER_ERROR,
-                "Internal server error".to_string(),
+                "internal_server_error",
+                "Internal server error",
             )
-                .into_response(),
-            Error::Validation(error) => {
-                (StatusCode::BAD_REQUEST, error.to_string()).into_response()
+            .into_response(),
+            Error::Validation(error) => ProblemDetails {
+                errors: field_errors(&error),
+                ..ProblemDetails::new(
+                    StatusCode::BAD_REQUEST,
+                    "validation_failed",
+                    "The request contains invalid fields",
+                )
+            }
+            .into_response(),
+        }
+    }
+}
+
+fn field_errors(errors: &ValidationErrors) -> Vec<FieldError> {
+    let mut collected = Vec::new();
+    collect_field_errors(errors, "", &mut collected);
+    collected.sort_by(|a, b| a.field.cmp(&b.field));
+    collected
+}
+
+fn collect_field_errors(errors: &ValidationErrors, prefix: &str, collected: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(field_errors) => {
+                collected.extend(field_errors.iter().map(|error| {
+                    FieldError {
+                        field: path.clone(),
+                        code: error.code.to_string(),
+                        message: error
+                            .message
+                            .as_ref()
+                            .map(|message| message.to_string())
+                            .unwrap_or_else(|| format!("Failed the '{}' check", error.code)),
+                    }
+                }));
+            }
+            ValidationErrorsKind::Struct(nested) => collect_field_errors(nested, &path, collected),
+            ValidationErrorsKind::List(items) => {
+                for (index, nested) in items {
+                    collect_field_errors(nested, &format!("{}[{}]", path, index), collected);
+                }
             }
         }
     }
@@ -58,37 +182,40 @@ pub enum PersistenceError {
 
 impl IntoResponse for PersistenceError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
+        let (status, code, error_message) = match self {
             PersistenceError::NotFound { entity, id } => (
                 StatusCode::NOT_FOUND,
+                "not_found",
                 format!("Entity '{}' with id {} not found", entity, id),
             ),
             PersistenceError::ConstraintViolation { constraint } => (
                 StatusCode::CONFLICT,
+                "conflict",
                 format!("Conflict due to constraint: {}", constraint),
             ),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
+                "internal_server_error",
                 "An internal persistence error occurred".to_string(),
             ),
         };
 
-        let body = Json(json!({
-            "error": error_message,
-        }));
-
-        (status, body).into_response()
+        ProblemDetails::new(status, code, error_message).into_response()
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum ApplicationError {
+    #[error("Unauthenticated")]
+    Unauthenticated,
     #[error("Invalid credentials")]
     InvalidCredentials,
     #[error("Incorrect password")]
     IncorrectPassword,
     #[error("Forbidden")]
     Forbidden,
+    #[error("Account suspended")]
+    AccountSuspended,
     #[error("Invalid input: {message}")]
     InvalidInput { message: String },
     #[error("Service unavailable: {service}")]
@@ -97,31 +224,47 @@ pub enum ApplicationError {
 
 impl IntoResponse for ApplicationError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            ApplicationError::InvalidCredentials => {
-                (StatusCode::UNAUTHORIZED, "Invalid credentials".to_string())
-            }
-
-            ApplicationError::IncorrectPassword => {
-                (StatusCode::FORBIDDEN, "Incorrect password".to_string())
-            }
+        let (status, code, error_message) = match self {
+            ApplicationError::Unauthenticated => (
+                StatusCode::UNAUTHORIZED,
+                "unauthenticated",
+                "A valid access token is required".to_string(),
+            ),
 
-            ApplicationError::Forbidden => (StatusCode::FORBIDDEN, "Forbidden".to_string()),
+            ApplicationError::InvalidCredentials => (
+                StatusCode::UNAUTHORIZED,
+                "invalid_credentials",
+                "Invalid credentials".to_string(),
+            ),
 
-            ApplicationError::InvalidInput { message } => (StatusCode::BAD_REQUEST, message),
+            ApplicationError::IncorrectPassword => (
+                StatusCode::FORBIDDEN,
+                "incorrect_password",
+                "Incorrect password".to_string(),
+            ),
 
-            ApplicationError::ServiceUnavailable { service } => {
-                (StatusCode::SERVICE_UNAVAILABLE, service)
+            ApplicationError::Forbidden => {
+                (StatusCode::FORBIDDEN, "forbidden", "Forbidden".to_string())
             }
-        };
 
-        let body = Json(json!({
+            ApplicationError::AccountSuspended => (
+                StatusCode::FORBIDDEN,
+                "account_suspended",
+                "Account suspended".to_string(),
+            ),
 
-            "error": error_message,
+            ApplicationError::InvalidInput { message } => {
+                (StatusCode::BAD_REQUEST, "invalid_input", message)
+            }
 
-        }));
+            ApplicationError::ServiceUnavailable { service } => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "service_unavailable",
+                service,
+            ),
+        };
 
-        (status, body).into_response()
+        ProblemDetails::new(status, code, error_message).into_response()
     }
 }
 
@@ -140,26 +283,26 @@ pub enum DomainError {
 
 impl IntoResponse for DomainError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            DomainError::BusinessRuleViolation { message } => (StatusCode::BAD_REQUEST, message),
+        let (status, code, error_message) = match self {
+            DomainError::BusinessRuleViolation { message } => {
+                (StatusCode::BAD_REQUEST, "business_rule_violation", message)
+            }
             DomainError::EntityNotFound {
                 entity_type,
                 entity_id,
             } => (
                 StatusCode::NOT_FOUND,
+                "not_found",
                 format!("Entity '{}' with id {} not found", entity_type, entity_id),
             ),
             DomainError::InvalidStateTransition { from, to } => (
                 StatusCode::UNPROCESSABLE_ENTITY,
+                "invalid_state_transition",
                 format!("Invalid state transition from '{}' to '{}'", from, to),
             ),
         };
 
-        let body = Json(json!({
-            "error": error_message,
-        }));
-
-        (status, body).into_response()
+        ProblemDetails::new(status, code, error_message).into_response()
     }
 }
 
@@ -185,6 +328,10 @@ pub enum SetupError {
     InvalidConfiguration(String),
     #[error("Environment validation failed: {0}")]
     EnvironmentValidationFailed(String),
+    #[error("Failed to install metrics recorder: {0}")]
+    FailedToInstallMetricsRecorder(String),
+    #[error("Failed to setup telemetry: {0}")]
+    FailedToSetupTelemetry(String),
 }
 
 impl IntoResponse for SetupError {
@@ -212,12 +359,12 @@ impl IntoResponse for SetupError {
             SetupError::EnvironmentValidationFailed(error) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, error)
             }
+            SetupError::FailedToInstallMetricsRecorder(error) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, error)
+            }
+            SetupError::FailedToSetupTelemetry(error) => (StatusCode::INTERNAL_SERVER_ERROR, error),
         };
 
-        let body = Json(json!({
-            "error": error_message,
-        }));
-
-        (status, body).into_response()
+        ProblemDetails::new(status, "setup_error", error_message).into_response()
     }
 }
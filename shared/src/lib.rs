@@ -1,4 +1,5 @@
 pub mod error;
+pub mod metrics;
 pub mod prelude;
 
 // Re-export commonly used types
@@ -0,0 +1,23 @@
+//! Names of the metrics exported at `/metrics`. They live here so the layers
+//! that record them and the exporter that describes them agree on spelling.
+
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+pub const HTTP_REQUESTS_IN_FLIGHT: &str = "http_requests_in_flight";
+
+pub const DB_POOL_CONNECTIONS: &str = "db_pool_connections";
+pub const DB_POOL_IDLE_CONNECTIONS: &str = "db_pool_idle_connections";
+pub const DB_POOL_MAX_CONNECTIONS: &str = "db_pool_max_connections";
+
+/// Labelled with a `reason` such as `missing_token` or `invalid_credentials`.
+pub const AUTH_FAILURES_TOTAL: &str = "auth_failures_total";
+
+pub const USERS_REGISTERED_TOTAL: &str = "users_registered_total";
+pub const SESSIONS_SCHEDULED_TOTAL: &str = "sessions_scheduled_total";
+pub const TABLE_REQUESTS_CREATED_TOTAL: &str = "table_requests_created_total";
+pub const TABLE_REQUESTS_ACCEPTED_TOTAL: &str = "table_requests_accepted_total";
+pub const TABLE_REQUESTS_REJECTED_TOTAL: &str = "table_requests_rejected_total";
+
+/// Route label used for requests that did not match any route, so scanners
+/// cannot blow up label cardinality.
+pub const UNMATCHED_ROUTE: &str = "unmatched";
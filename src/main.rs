@@ -1,27 +1,55 @@
 use api::http::handlers::create_router;
+use clap::Parser;
 use infrastructure::{
-    config::AppConfig,
-    setup::{database::setup_database, launch_server, logging::init_logging},
+    config::{AppConfig, Cli},
+    setup::{
+        database::setup_database,
+        jobs::{spawn_export_worker, spawn_purge_job},
+        launch_server,
+        listeners::{spawn_chat_listener, spawn_user_event_listener},
+        logging::init_logging,
+        metrics::init_metrics,
+    },
     state::setup_app_state,
 };
 use std::sync::Arc;
 
 #[tokio::main]
 async fn main() {
-    init_logging();
-    let config = AppConfig::from_env().expect("failed to load configuration");
-    let database = setup_database(&config.database_url)
+    // Loaded before logging, which it configures, so problems go to stderr.
+    let config = AppConfig::load(&Cli::parse()).unwrap_or_else(|err| {
+        eprintln!("❌ {err}");
+        std::process::exit(1);
+    });
+
+    let _telemetry = init_logging(config.log_format, &config.telemetry);
+    config.log_notices();
+
+    let metrics = init_metrics().expect("failed to install metrics recorder");
+    let database = setup_database(&config.database_url, &config.database_pool)
         .await
         .expect("failed to setup database");
 
-    let app_state = setup_app_state(&database, &config)
+    let app_state = setup_app_state(&database, &config, metrics)
         .await
         .expect("failed to setup app state");
 
+    let shutdown = app_state.shutdown.clone();
+    if config.features.purge_job {
+        spawn_purge_job(&app_state, &shutdown);
+    }
+    spawn_export_worker(&app_state, &shutdown);
+    spawn_chat_listener(&database, &app_state.chat_service, &shutdown);
+    spawn_user_event_listener(&database, &app_state.user_event_service, &shutdown);
+
     let app_state_arc = Arc::new(app_state);
     let server = create_router(app_state_arc.clone());
 
     launch_server(server, &app_state_arc)
         .await
         .expect("failed to launch server");
+
+    shutdown
+        .complete(&database, config.shutdown_drain_timeout)
+        .await;
 }